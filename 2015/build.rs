@@ -1,9 +1,17 @@
 use std::{
+    env,
     fs::{copy, read_dir},
     path::PathBuf,
 };
 
 fn main() -> std::io::Result<()> {
+    advent::example::generate_tests()?;
+
+    // Inputs are read at run time unless they're explicitly baked into the binary.
+    if env::var_os("CARGO_FEATURE_EMBED_INPUT").is_none() {
+        return Ok(());
+    }
+
     println!("cargo::rerun-if-changed=../AdventOfCodeInput/2015");
     for dir in read_dir("../AdventOfCodeInput/2015")
         .expect("Could not load input files from ../AdventOfCodeInput/2015")
@@ -1,50 +1,19 @@
 use advent::*;
 
-advent_day!(Day01, parse, str, part1, part2);
+advent_day!(Day01, 1, parse, str, part1, part2);
 
-pub fn parse(input: &str) -> &str {
-    input
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    let input = input.trim_end();
+    match input.char_indices().find(|&(_, c)| c != '(' && c != ')') {
+        Some((index, c)) => Err(ParseError::at(
+            input,
+            &input[index..index + c.len_utf8()],
+            "`(` or `)`",
+        )),
+        None => Ok(input),
+    }
 }
 
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(0, part1(&(parse("(())"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(0, part1(&parse("()()")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(3, part1(&parse("(((")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(3, part1(&parse("(()(()(")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(3, part1(&parse("))(((((")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(-1, part1(&parse("())")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(-1, part1(&parse("))(")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(-3, part1(&parse(")))")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(-3, part1(&parse(")())())")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// ```
 pub fn part1(input: &str) -> i32 {
     input.bytes().fold(0, |floor, c| match c {
         b'(' => floor + 1,
@@ -53,14 +22,6 @@ pub fn part1(input: &str) -> i32 {
     })
 }
 
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(1, part2(&(parse(")"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day01::*;
-/// assert_eq!(5, part2(&parse("()())")));
-/// ```
 pub fn part2(input: &str) -> u32 {
     input
         .bytes()
@@ -1,29 +1,22 @@
 use advent::*;
 
-advent_day!(Day02, parse, Vec<(u32, u32, u32)>, part1, part2);
+advent_day!(Day02, 2, parse, Vec<(u32, u32, u32)>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<(u32, u32, u32)> {
+pub fn parse(input: &str) -> Result<Vec<(u32, u32, u32)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let dimensions: Vec<&str> = line.split('x').collect();
-            (
-                dimensions[0].parse().unwrap(),
-                dimensions[1].parse().unwrap(),
-                dimensions[2].parse().unwrap(),
-            )
+            let (length, rest) = parse::split_once(input, line, "x")?;
+            let (width, height) = parse::split_once(input, rest, "x")?;
+            Ok((
+                parse::token(input, length)?,
+                parse::token(input, width)?,
+                parse::token(input, height)?,
+            ))
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2015::day02::*;
-/// assert_eq!(58, part1(&(parse("2x3x4"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day02::*;
-/// assert_eq!(43, part1(&parse("1x1x10")));
-/// ```
 pub fn part1(input: &Vec<(u32, u32, u32)>) -> u32 {
     input
         .iter()
@@ -36,14 +29,6 @@ pub fn part1(input: &Vec<(u32, u32, u32)>) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2015::day02::*;
-/// assert_eq!(34, part2(&(parse("2x3x4"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day02::*;
-/// assert_eq!(14, part2(&parse("1x1x10")));
-/// ```
 pub fn part2(input: &Vec<(u32, u32, u32)>) -> u32 {
     input
         .iter()
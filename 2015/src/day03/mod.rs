@@ -2,33 +2,26 @@ use std::collections::HashSet;
 
 use advent::*;
 
-advent_day!(Day03, parse, Vec<Direction>, part1, part2);
+advent_day!(Day03, 3, parse, Vec<Direction>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<Direction> {
+pub fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
     input
-        .chars()
-        .map(|c| match c {
-            '^' => Direction::North,
-            'v' => Direction::South,
-            '>' => Direction::East,
-            '<' => Direction::West,
-            _ => unreachable!(),
+        .trim_end()
+        .char_indices()
+        .map(|(index, c)| match c {
+            '^' => Ok(Direction::North),
+            'v' => Ok(Direction::South),
+            '>' => Ok(Direction::East),
+            '<' => Ok(Direction::West),
+            _ => Err(ParseError::at(
+                input,
+                &input[index..index + c.len_utf8()],
+                "one of `^v<>`",
+            )),
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(2, part1(&(parse(">"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(4, part1(&parse("^>v<")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(2, part1(&parse("^v^v^v^v^v")));
-/// ```
 pub fn part1(input: &Vec<Direction>) -> u32 {
     let mut visited = HashSet::<IPoint>::new();
     let mut location = IPoint::origin();
@@ -47,18 +40,6 @@ pub fn part1(input: &Vec<Direction>) -> u32 {
     visited.len() as u32
 }
 
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(3, part2(&(parse("^v"))));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(3, part2(&parse("^>v<")));
-/// ```
-/// ```rust
-/// use advent_of_code_2015::day03::*;
-/// assert_eq!(11, part2(&parse("^v^v^v^v^v")));
-/// ```
 pub fn part2(input: &Vec<Direction>) -> u32 {
     let mut visited = HashSet::<IPoint>::new();
     let mut location = IPoint::origin();
@@ -4,10 +4,13 @@ use md5::{
     Digest, Md5,
 };
 
-advent_day!(Day04, parse, str, part1, part2);
+advent_day!(Day04, 4, parse, str, part1, part2);
 
-pub fn parse(input: &str) -> &str {
-    input
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    match input.trim() {
+        "" => Err(ParseError::end_of_input(input, "a secret key")),
+        key => Ok(key),
+    }
 }
 
 fn md5_find_digits<F: Fn(GenericArray<u8, U16>) -> bool>(
@@ -52,10 +55,6 @@ fn md5_find<F: Fn(GenericArray<u8, U16>) -> bool>(input: &str, predicate: F) ->
         .or_else(|| md5_find_digits(&prefixed_hash, 6, &predicate))
 }
 
-/// ```rust
-/// use advent_of_code_2015::day04::*;
-/// assert_eq!(609043, part1(&(parse("abcdef"))));
-/// ```
 pub fn part1(input: &str) -> u32 {
     md5_find(input, |result| {
         result[0] == 0 && result[1] == 0 && result[2] & 0xf0 == 0
@@ -63,10 +62,6 @@ pub fn part1(input: &str) -> u32 {
     .unwrap()
 }
 
-/// ```rust
-/// use advent_of_code_2015::day04::*;
-/// assert_eq!(6742839, part2(&(parse("abcdef"))));
-/// ```
 pub fn part2(input: &str) -> u32 {
     md5_find(input, |result| {
         result[0] == 0 && result[1] == 0 && result[2] == 0
@@ -1,6 +1,20 @@
-#![feature(test)]
+pub const YEAR: u16 = 2015;
+
+use advent::{DayError, LoadedDay, Solver};
 
 pub mod day01;
 pub mod day02;
 pub mod day03;
 pub mod day04;
+
+pub fn new_day(day_id: u8) -> Option<LoadedDay> {
+    try_new_day(day_id).map(|day| day.unwrap_or_else(|error| panic!("Day {day_id}: {error}")))
+}
+
+pub fn try_new_day(day_id: u8) -> Option<Result<LoadedDay, DayError>> {
+    solver(day_id).map(|solver| solver.load())
+}
+
+pub fn solver(day_id: u8) -> Option<Solver> {
+    advent::registry::solver(YEAR, day_id)
+}
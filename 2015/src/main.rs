@@ -1,13 +1,10 @@
-#![feature(test)]
-
-use advent::*;
-use advent_of_code_2015::*;
+use advent::registry;
+use advent_of_code_2015::YEAR;
 
 fn main() {
     println!("AdventOfCode 2015");
 
-    run_day!(1, day01::Day01);
-    run_day!(2, day02::Day02);
-    run_day!(3, day03::Day03);
-    run_day!(4, day04::Day04);
+    for solver in registry::year(YEAR) {
+        println!("{}", solver.summary());
+    }
 }
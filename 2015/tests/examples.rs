@@ -0,0 +1,4 @@
+//! The puzzles' own examples, from `examples/dayNN.toml`, solved by the registered days. The
+//! build script writes a test for every day with examples.
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -1,24 +1,15 @@
 use std::{cmp::Ordering, sync::OnceLock};
 
 use advent::*;
-use log::debug;
+use tracing::debug;
 
-advent_day!(Day01, parse, Vec<&'static str>, part1, part2);
+advent_day!(Day01, 1, parse, Vec<&'static str>, part1, part2);
 advent_bench!(parse, cursed_regex, part2_regex);
 
-pub fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
 }
 
-/// ```rust
-/// use advent_of_code_2023::day01::*;
-/// let input = parse(
-/// r"1abc2
-/// pqr3stu8vwx
-/// a1b2c3d4e5f
-/// treb7uchet");
-/// assert_eq!(142, part1(&input));
-/// ```
 pub fn part1(input: &Vec<&str>) -> u32 {
     let mut total = 0;
     for line in input {
@@ -26,7 +17,7 @@ pub fn part1(input: &Vec<&str>) -> u32 {
         let first = digits.next().unwrap();
         let last = digits.last().unwrap_or(first);
 
-        debug!("{} => {}{} | {}", line, first, last, total);
+        debug!(line, first, last, total, "calibration value");
         total += first * 10 + last;
     }
 
@@ -42,7 +33,7 @@ pub fn part1(input: &Vec<&str>) -> u32 {
 /// xtwone3four
 /// 4nineeightseven2
 /// zoneight234
-/// 7pqrstsixteen");
+/// 7pqrstsixteen").unwrap();
 /// assert_eq!(281, part2_regex(&input));
 /// ```
 pub fn part2_regex(input: &Vec<&str>) -> u32 {
@@ -69,32 +60,19 @@ pub fn part2_regex(input: &Vec<&str>) -> u32 {
     }
 
     for line in input {
-        debug!("{}", line);
         let mut captures = number_parser.captures_iter(line);
         let first = parse_digit(captures.next().unwrap().get(0).unwrap().as_str());
         let last = captures
             .last()
             .map_or(first, |cap| parse_digit(cap.get(0).unwrap().as_str()));
 
-        debug!("{}{}", first, last);
+        debug!(line, first, last, "calibration value");
         total += (first * 10) + last;
     }
 
     total
 }
 
-/// ```rust
-/// use advent_of_code_2023::day01::*;
-/// let input = parse(
-/// r"two1nine
-/// eightwothree
-/// abcone2threexyz
-/// xtwone3four
-/// 4nineeightseven2
-/// zoneight234
-/// 7pqrstsixteen");
-/// assert_eq!(281, part2(&input));
-/// ```
 pub fn part2(input: &Vec<&str>) -> u32 {
     const DIGITS: [(&str, u32); 18] = [
         ("1", 1),
@@ -160,6 +138,6 @@ mod unittests {
             zoneight234
             7pqrstsixteen
             eighthree";
-        assert_eq!(281 + 83, super::part2(&super::parse(input)));
+        assert_eq!(281 + 83, super::part2(&super::parse(input).unwrap()));
     }
 }
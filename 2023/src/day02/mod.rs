@@ -2,22 +2,12 @@ use std::sync::OnceLock;
 
 use advent::*;
 
-advent_day!(Day02, parse, Vec<&'static str>, part1, part2);
+advent_day!(Day02, 2, parse, Vec<&'static str>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
 }
 
-/// ```rust
-/// use advent_of_code_2023::day02::*;
-/// let input = parse(
-/// r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-/// Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-/// Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-/// Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-/// Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green");
-/// assert_eq!(8, part1(&input));
-/// ```
 pub fn part1(input: &Vec<&str>) -> u32 {
     static LINE_PARSER: OnceLock<regex::Regex> = OnceLock::new();
     static COLOUR_PARSER: OnceLock<regex::Regex> = OnceLock::new();
@@ -49,16 +39,6 @@ pub fn part1(input: &Vec<&str>) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day02::*;
-/// let input = parse(
-/// r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-/// Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-/// Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-/// Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-/// Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green");
-/// assert_eq!(2286, part2(&input));
-/// ```
 pub fn part2(input: &Vec<&str>) -> u32 {
     static COLOUR_PARSER: OnceLock<regex::Regex> = OnceLock::new();
 
@@ -1,22 +1,22 @@
 use advent::*;
 
-advent_day!(Day03, parse, Grid<'a>, part1, part2);
+advent_day!(Day03, 3, parse, Grid<'a>, part1, part2);
 
-pub fn parse<'a>(input: &'a str) -> Grid<'a> {
-    let width = input.find(['\r', '\n']).unwrap();
-    let terminator_length = input
-        .get(width..)
-        .unwrap()
+pub fn parse<'a>(input: &'a str) -> Result<Grid<'a>, ParseError> {
+    let width = input
+        .find(['\r', '\n'])
+        .ok_or_else(|| ParseError::end_of_input(input, "a line break"))?;
+    let terminator_length = input[width..]
         .find(|c: char| !c.is_whitespace())
-        .unwrap();
+        .ok_or_else(|| ParseError::end_of_input(input, "a second row"))?;
     let stride = width + terminator_length;
     let height = input.len().div_ceil(stride);
 
-    Grid {
+    Ok(Grid {
         cells: input.as_bytes(),
         bounds: UPoint::new(width as u32, height as u32),
         stride: stride as u32,
-    }
+    })
 }
 
 pub struct Grid<'a> {
@@ -96,21 +96,6 @@ fn part_number(grid: &Grid, position: UPoint) -> Option<u32> {
     }
 }
 
-/// ```rust
-/// use advent_of_code_2023::day03::*;
-/// let input = parse(
-/// r"467..114..
-/// ...*......
-/// ..35..633.
-/// ......#...
-/// 617*......
-/// .....+.58.
-/// ..592.....
-/// ......755.
-/// ...$.*....
-/// .664.598..");
-/// assert_eq!(4361, part1(&input));
-/// ```
 pub fn part1(grid: &Grid<'_>) -> u32 {
     let mut sum = 0;
 
@@ -146,21 +131,6 @@ pub fn part1(grid: &Grid<'_>) -> u32 {
     sum
 }
 
-/// ```rust
-/// use advent_of_code_2023::day03::*;
-/// let input = parse(
-/// r"467..114..
-/// ...*......
-/// ..35..633.
-/// ......#...
-/// 617*......
-/// .....+.58.
-/// ..592.....
-/// ......755.
-/// ...$.*....
-/// .664.598..");
-/// assert_eq!(467835, part2(&input));
-/// ```
 pub fn part2(grid: &Grid<'_>) -> u32 {
     let mut sum = 0;
 
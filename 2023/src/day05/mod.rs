@@ -1,142 +1,75 @@
-use std::{cmp::Ordering, ops::RangeInclusive};
+use advent::{interval::*, *};
 
-use advent::*;
+advent_day!(Day05, 5, parse, SeedMaps, part1, part2);
 
-advent_day!(Day05, parse, SeedMaps, part1, part2);
-
-pub fn parse(input: &str) -> SeedMaps {
-    fn map_regex(map_name: &str) -> String {
-        let group_name = map_name.replace("-", "_");
-        format!(
-            r"{} map:(?P<{}>(?:\s+(?:\d+ \d+ \d+))*)",
-            map_name, group_name
-        )
-    }
-
-    let data_parser = regex::Regex::new(
-        format!(
-            r"seeds:(?P<seeds>(?: \d+)+)\s+{}\s+{}\s+{}\s+{}\s+{}\s+{}\s+{}",
-            map_regex("seed-to-soil"),
-            map_regex("soil-to-fertilizer"),
-            map_regex("fertilizer-to-water"),
-            map_regex("water-to-light"),
-            map_regex("light-to-temperature"),
-            map_regex("temperature-to-humidity"),
-            map_regex("humidity-to-location")
-        )
-        .as_str(),
-    )
-    .unwrap();
-
-    let data = data_parser.captures(input).unwrap();
-    let seeds: Vec<u32> = data
-        .name("seeds")
-        .map(|seeds| {
-            seeds
-                .as_str()
-                .split_whitespace()
-                .map(|id| id.parse::<u32>().expect("Seed is not u32"))
-        })
-        .expect("Failed to parse seeds")
-        .collect();
+/// Seeds come in pairs, and a map can't send the same source anywhere twice.
+///
+/// ```rust
+/// use advent_of_code_2023::day05::parse;
+/// assert!(parse("seeds:\n\nseed-to-soil map:\n50 98 2\n").is_err());
+/// assert!(parse("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n").is_err());
+/// assert!(parse("seeds: 79 0\n\nseed-to-soil map:\n50 98 2\n").is_err());
+/// let error = parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 49\n").err().unwrap();
+/// assert_eq!((5, 1), (error.line, error.column));
+/// ```
+pub fn parse(input: &str) -> Result<SeedMaps, ParseError> {
+    let sections = parse::sections(input, input)?;
+    let [(_, seeds), maps @ ..] = sections.as_slice() else {
+        return Err(ParseError::end_of_input(input, "seeds"));
+    };
+    let seeds = match parse::unsigned_integers(input, seeds)? {
+        numbers if numbers.is_empty() || numbers.len() % 2 != 0 => {
+            return Err(ParseError::at(input, seeds, "seeds in pairs of start and length"));
+        }
+        numbers if numbers.chunks_exact(2).any(|pair| pair[1] == 0) => {
+            return Err(ParseError::at(input, seeds, "at least one seed in each range"));
+        }
+        numbers => numbers,
+    };
 
-    let parse_map = |name: &str| -> Vec<RangeMap> {
-        let mut map: Vec<RangeMap> = data
-            .name(name)
-            .map(|section| {
-                section.as_str().trim().lines().map(|line| {
-                    let tokens: Vec<u32> = line
-                        .split_whitespace()
-                        .map(|token| token.parse::<u32>().expect("Failed to parse as u32"))
-                        .collect();
-                    let range = tokens[1]..=(tokens[1] + (tokens[2] - 1));
-                    let offset = tokens[0].wrapping_sub(tokens[1]);
-                    RangeMap { range, offset }
-                    // tokens.
+    let parse_map = |name: &'static str| -> Result<OffsetMap<u64>, ParseError> {
+        let (_, body) = maps
+            .iter()
+            .find(|&&(heading, _)| heading.strip_suffix(" map") == Some(name))
+            .ok_or_else(|| ParseError::end_of_input(input, format!("a {name} map")))?;
+        let mut map = OffsetMap::new();
+        parse::lines(body, |line| {
+            let [destination, source, length] = parse::integer_array::<u64, 3>(input, line)?;
+            map.try_insert(source..source + length, destination)
+                .map_err(|overlapped| {
+                    let expected = format!(
+                        "a source range clear of {}..{}",
+                        overlapped.start, overlapped.end
+                    );
+                    ParseError::at(input, line, expected)
                 })
-            })
-            .unwrap()
-            .collect();
-        map.sort_by_key(|item| *item.range.start());
-        map
+        })?;
+        Ok(map)
     };
-    SeedMaps {
+    Ok(SeedMaps {
         seeds,
-        seed_to_soil: parse_map("seed_to_soil"),
-        soil_to_fertilizer: parse_map("soil_to_fertilizer"),
-        fertilizer_to_water: parse_map("fertilizer_to_water"),
-        water_to_light: parse_map("water_to_light"),
-        light_to_temperature: parse_map("light_to_temperature"),
-        temperature_to_humidity: parse_map("temperature_to_humidity"),
-        humidity_to_location: parse_map("humidity_to_location"),
-    }
-}
-
-struct RangeMap {
-    range: RangeInclusive<u32>,
-    offset: u32,
-}
-
-impl RangeMap {
-    fn contains(&self, location: &u32) -> bool {
-        self.range.contains(location)
-    }
-
-    fn offset(&self, location: u32) -> u32 {
-        location.wrapping_add(self.offset)
-    }
+        seed_to_soil: parse_map("seed-to-soil")?,
+        soil_to_fertilizer: parse_map("soil-to-fertilizer")?,
+        fertilizer_to_water: parse_map("fertilizer-to-water")?,
+        water_to_light: parse_map("water-to-light")?,
+        light_to_temperature: parse_map("light-to-temperature")?,
+        temperature_to_humidity: parse_map("temperature-to-humidity")?,
+        humidity_to_location: parse_map("humidity-to-location")?,
+    })
 }
 
 pub struct SeedMaps {
-    seeds: Vec<u32>,
-    seed_to_soil: Vec<RangeMap>,
-    soil_to_fertilizer: Vec<RangeMap>,
-    fertilizer_to_water: Vec<RangeMap>,
-    water_to_light: Vec<RangeMap>,
-    light_to_temperature: Vec<RangeMap>,
-    temperature_to_humidity: Vec<RangeMap>,
-    humidity_to_location: Vec<RangeMap>,
+    seeds: Vec<u64>,
+    seed_to_soil: OffsetMap<u64>,
+    soil_to_fertilizer: OffsetMap<u64>,
+    fertilizer_to_water: OffsetMap<u64>,
+    water_to_light: OffsetMap<u64>,
+    light_to_temperature: OffsetMap<u64>,
+    temperature_to_humidity: OffsetMap<u64>,
+    humidity_to_location: OffsetMap<u64>,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day05::*;
-/// let input = parse(
-/// r"seeds: 79 14 55 13
-///
-/// seed-to-soil map:
-/// 50 98 2
-/// 52 50 48
-///
-/// soil-to-fertilizer map:
-/// 0 15 37
-/// 37 52 2
-/// 39 0 15
-///
-/// fertilizer-to-water map:
-/// 49 53 8
-/// 0 11 42
-/// 42 0 7
-/// 57 7 4
-///
-/// water-to-light map:
-/// 88 18 7
-/// 18 25 70
-///
-/// light-to-temperature map:
-/// 45 77 23
-/// 81 45 19
-/// 68 64 13
-///
-/// temperature-to-humidity map:
-/// 0 69 1
-/// 1 0 69
-///
-/// humidity-to-location map:
-/// 60 56 37
-/// 56 93 4");
-/// assert_eq!(35, part1(&input));
-/// ```
-pub fn part1(input: &SeedMaps) -> u32 {
+pub fn part1(input: &SeedMaps) -> u64 {
     let maps = [
         &input.seed_to_soil,
         &input.soil_to_fertilizer,
@@ -150,155 +83,33 @@ pub fn part1(input: &SeedMaps) -> u32 {
     input
         .seeds
         .iter()
-        .map(|seed| {
-            maps.iter().fold(*seed, |loc, map| {
-                map.binary_search_by(|probe| {
-                    if probe.range.contains(&loc) {
-                        Ordering::Equal
-                    } else {
-                        probe.range.start().cmp(&loc)
-                    }
-                })
-                .map_or(loc, |index| map[index].offset(loc))
-            })
-        })
+        .map(|&seed| maps.iter().fold(seed, |location, map| map.get(location)))
         .min()
-        .unwrap()
+        .expect("parse rejects an empty seed list")
 }
 
-/// ```rust
-/// use advent_of_code_2023::day05::*;
-/// let input = parse(
-/// r"seeds: 79 14 55 13
-///
-/// seed-to-soil map:
-/// 50 98 2
-/// 52 50 48
-///
-/// soil-to-fertilizer map:
-/// 0 15 37
-/// 37 52 2
-/// 39 0 15
-///
-/// fertilizer-to-water map:
-/// 49 53 8
-/// 0 11 42
-/// 42 0 7
-/// 57 7 4
-///
-/// water-to-light map:
-/// 88 18 7
-/// 18 25 70
-///
-/// light-to-temperature map:
-/// 45 77 23
-/// 81 45 19
-/// 68 64 13
-///
-/// temperature-to-humidity map:
-/// 0 69 1
-/// 1 0 69
-///
-/// humidity-to-location map:
-/// 60 56 37
-/// 56 93 4");
-/// assert_eq!(46, part2(&input));
-/// ```
-pub fn part2(input: &SeedMaps) -> u32 {
-    let seed_ranges: Vec<RangeInclusive<u32>> = input
+pub fn part2(input: &SeedMaps) -> u64 {
+    let maps = [
+        &input.seed_to_soil,
+        &input.soil_to_fertilizer,
+        &input.fertilizer_to_water,
+        &input.water_to_light,
+        &input.light_to_temperature,
+        &input.temperature_to_humidity,
+        &input.humidity_to_location,
+    ];
+
+    let seeds = input
         .seeds
         .chunks_exact(2)
-        .map(|chunk| chunk[0]..=(chunk[0] + (chunk[1] - 1)))
-        .collect();
-
-    let location_map = |locations: Vec<RangeInclusive<u32>>,
-                        map: &Vec<RangeMap>|
-     -> Vec<RangeInclusive<u32>> {
-        locations
-            .iter()
-            .flat_map(|location_range| {
-                let start_index = map
-                    .binary_search_by(|probe| {
-                        if probe.contains(location_range.start()) {
-                            Ordering::Equal
-                        } else {
-                            probe.range.start().cmp(location_range.start())
-                        }
-                    })
-                    .map_or_else(|index| index, |index| index);
-
-                let mut new_ranges = Vec::new();
-                let mut remaining_range = location_range.clone();
-                let mut maps = map.get(start_index..).unwrap().iter();
-
-                while !remaining_range.is_empty() {
-                    match maps.next() {
-                        Some(map_range) => {
-                            if map_range.range.contains(remaining_range.start())
-                                && map_range.range.contains(remaining_range.end())
-                            {
-                                // Fully contained, push all+offset
-                                new_ranges.push(
-                                    map_range.offset(*remaining_range.start())
-                                        ..=map_range.offset(*remaining_range.end()),
-                                );
-                                break;
-                                // remaining_range = *seed_range.end()..=*seed_range.end();
-                            } else if map_range.range.contains(remaining_range.start()) {
-                                // Seed head overlaps map tail, push head+offset
-                                new_ranges.push(
-                                    map_range.offset(*remaining_range.start())
-                                        ..=map_range.offset(*map_range.range.end()),
-                                );
-                                // Remainder tail
-                                remaining_range =
-                                    (map_range.range.end() + 1)..=*remaining_range.end();
-                            } else if map_range.range.contains(remaining_range.end()) {
-                                // Seed tail overlaps map head, push head and tail+offset
-                                new_ranges
-                                    .push(*remaining_range.start()..=(map_range.range.start() - 1));
-                                new_ranges.push(
-                                    map_range.offset(*map_range.range.start())
-                                        ..=map_range.offset(*remaining_range.end()),
-                                );
-                                break;
-                            } else if remaining_range.contains(map_range.range.start())
-                                && remaining_range.contains(map_range.range.end())
-                            {
-                                // Seed overlaps map, push head, body+offset
-                                new_ranges
-                                    .push(*remaining_range.start()..=(map_range.range.start() - 1));
-                                new_ranges.push(
-                                    map_range.offset(*map_range.range.start())
-                                        ..=map_range.offset(*map_range.range.end()),
-                                );
-                                // Remainder tail
-                                remaining_range =
-                                    (map_range.range.end() + 1)..=*remaining_range.end();
-                            }
-                        }
-                        None => {
-                            new_ranges.push(remaining_range);
-                            break;
-                        }
-                    }
-                }
-
-                new_ranges
-            })
-            .collect()
-    };
-
-    let soil_ranges = location_map(seed_ranges, &input.seed_to_soil);
-    let fertilizer_ranges = location_map(soil_ranges, &input.soil_to_fertilizer);
-    let water_ranges = location_map(fertilizer_ranges, &input.fertilizer_to_water);
-    let light_ranges = location_map(water_ranges, &input.water_to_light);
-    let temperature_ranges = location_map(light_ranges, &input.light_to_temperature);
-    let humidity_ranges = location_map(temperature_ranges, &input.temperature_to_humidity);
-    let location_ranges = location_map(humidity_ranges, &input.humidity_to_location);
-    location_ranges
+        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+        .collect::<IntervalSet<_>>();
+    let locations = maps
         .iter()
-        .map(|location| *location.start())
-        .min()
-        .unwrap()
+        .fold(seeds, |locations, map| map.map(&locations));
+    locations
+        .ranges()
+        .first()
+        .expect("parse rejects empty seed ranges")
+        .start
 }
@@ -1,6 +1,6 @@
 use advent::*;
 
-advent_day!(Day06, parse, Vec<BoatRace>, part1, part2);
+advent_day!(Day06, 6, parse, Vec<BoatRace>, part1, part2);
 
 pub struct BoatRace {
     time: u32,
@@ -9,32 +9,32 @@ pub struct BoatRace {
     meta_distance_length: u32,
 }
 
-pub fn parse(input: &str) -> Vec<BoatRace> {
+pub fn parse(input: &str) -> Result<Vec<BoatRace>, ParseError> {
     let mut rows = input.lines().map(|line| {
         line.split_whitespace()
             .skip(1)
-            .map(|value| (value.parse::<u32>().unwrap(), value.len() as u32))
+            .map(|value| Ok((parse::token::<u32>(input, value)?, value.len() as u32)))
+            .collect::<Result<Vec<_>, ParseError>>()
     });
-    let time = rows.next().unwrap();
-    let distance = rows.next().unwrap();
+    let time = rows
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a `Time:` line"))??;
+    let distance = rows
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a `Distance:` line"))??;
 
-    time.zip(distance)
+    Ok(time
+        .into_iter()
+        .zip(distance)
         .map(|(time, distance)| BoatRace {
             time: time.0,
             meta_time_length: time.1,
             distance: distance.0,
             meta_distance_length: distance.1,
         })
-        .collect()
+        .collect())
 }
 
-/// ```rust
-/// use advent_of_code_2023::day06::*;
-/// let input = parse(
-/// r"Time:      7  15   30
-/// Distance:  9  40  200");
-/// assert_eq!(288, part1(&input));
-/// ```
 pub fn part1(input: &Vec<BoatRace>) -> u32 {
     input
         .iter()
@@ -47,13 +47,6 @@ pub fn part1(input: &Vec<BoatRace>) -> u32 {
         .product()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day06::*;
-/// let input = parse(
-/// r"Time:      7  15   30
-/// Distance:  9  40  200");
-/// assert_eq!(71503, part2(&input));
-/// ```
 pub fn part2(input: &Vec<BoatRace>) -> u32 {
     let (time, distance) = input.iter().fold((0, 0), |acc, race| {
         (
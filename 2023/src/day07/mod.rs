@@ -2,36 +2,26 @@ use std::collections::BTreeMap;
 
 use advent::*;
 
-advent_day!(Day07, parse, Vec<CamelHand<'a>>, part1, part2);
+advent_day!(Day07, 7, parse, Vec<CamelHand<'a>>, part1, part2);
 
 pub struct CamelHand<'a> {
     cards: &'a str,
     bid: u32,
 }
 
-pub fn parse(input: &str) -> Vec<CamelHand> {
+pub fn parse(input: &str) -> Result<Vec<CamelHand>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (cards, bid) = line.split_once(' ').unwrap();
-            CamelHand {
+            let (cards, bid) = parse::split_once(input, line, " ")?;
+            Ok(CamelHand {
                 cards,
-                bid: bid.parse::<u32>().unwrap(),
-            }
+                bid: parse::token(input, bid)?,
+            })
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day07::*;
-/// let input = parse(
-/// r"32T3K 765
-/// T55J5 684
-/// KK677 28
-/// KTJJT 220
-/// QQQJA 483");
-/// assert_eq!(6440, part1(&input));
-/// ```
 pub fn part1(input: &Vec<CamelHand>) -> u32 {
     const fn card_value(card: u8) -> u8 {
         match card {
@@ -94,16 +84,6 @@ pub fn part1(input: &Vec<CamelHand>) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day07::*;
-/// let input = parse(
-/// r"32T3K 765
-/// T55J5 684
-/// KK677 28
-/// KTJJT 220
-/// QQQJA 483");
-/// assert_eq!(5905, part2(&input));
-/// ```
 pub fn part2(input: &Vec<CamelHand>) -> u32 {
     const fn card_value(card: u8) -> u8 {
         match card {
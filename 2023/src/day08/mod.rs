@@ -1,133 +1,90 @@
 use std::collections::HashMap;
 
-use advent::*;
-use num::Integer;
+use advent::{intern::Alphanumeric, *};
 
-advent_day!(Day08, parse, WastelandMap<'a>, part1, part2);
+type Node = Alphanumeric<3>;
+
+advent_day!(Day08, 8, parse, WastelandMap<'a>, part1, part2);
 advent_bench!(parse, cursed_hashmap, part1_hashmap, part2_hashmap);
 
-pub fn parse(input: &str) -> WastelandMap {
+pub fn parse(input: &str) -> Result<WastelandMap, ParseError> {
     let mut lines = input.lines();
     let instructions = lines
         .next()
-        .unwrap()
+        .ok_or_else(|| ParseError::end_of_input(input, "a line of instructions"))?
         .bytes()
-        .map(|instruction| match instruction {
-            b'L' => 0,
-            b'R' => 1,
-            _ => unreachable!(),
+        .enumerate()
+        .map(|(x, instruction)| match instruction {
+            b'L' => Ok(0),
+            b'R' => Ok(1),
+            _ => Err(ParseError::new(
+                1,
+                x + 1,
+                (instruction as char).to_string(),
+                "`L` or `R`",
+            )),
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let _blank_line = lines.next();
 
     let map: HashMap<&str, [&str; 2]> = lines
         .map(|line| {
-            let (key, children) = line.split_once('=').unwrap();
-            let (left, right) = children.split_once(',').unwrap();
-            (
+            let (key, children) = parse::split_once(input, line, "=")?;
+            let (left, right) = parse::split_once(input, children, ",")?;
+            Ok((
                 key.trim_end(),
                 [
                     left.trim().trim_start_matches('('),
                     right.trim().trim_end_matches(')'),
                 ],
-            )
+            ))
         })
-        .collect();
-
-    let mut base26_lookup = [None; 17576];
-
-    for entry in map.iter() {
-        fn decode_node(node: &str) -> u16 {
-            node.bytes()
-                .fold(0u16, |acc, digit| (acc * 26) + (digit - b'A') as u16)
-        }
-
-        let node_index = decode_node(entry.0);
-        base26_lookup[node_index as usize] =
-            Some([decode_node(entry.1[0]), decode_node(entry.1[1])]);
+        .collect::<Result<_, ParseError>>()?;
+
+    let mut packed = vec![None; Node::CAPACITY];
+    for (&node, children) in map.iter() {
+        let pack = |label| {
+            Node::pack(label)
+                .map(|id| id as u16)
+                .ok_or_else(|| ParseError::at(input, label, "a three character label"))
+        };
+        packed[pack(node)? as usize] = Some([pack(children[0])?, pack(children[1])?]);
     }
 
-    WastelandMap {
+    Ok(WastelandMap {
         instructions,
         map,
-        base26_lookup,
-    }
+        packed,
+    })
 }
 
 pub struct WastelandMap<'a> {
     instructions: Vec<u8>,
     map: HashMap<&'a str, [&'a str; 2]>,
-    base26_lookup: [Option<[u16; 2]>; 17576],
+    /// The children of every node, indexed by its packed label.
+    packed: Vec<Option<[u16; 2]>>,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"RL
-///
-/// AAA = (BBB, CCC)
-/// BBB = (DDD, EEE)
-/// CCC = (ZZZ, GGG)
-/// DDD = (DDD, DDD)
-/// EEE = (EEE, EEE)
-/// GGG = (GGG, GGG)
-/// ZZZ = (ZZZ, ZZZ)");
-/// assert_eq!(2, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"LLR
-///
-/// AAA = (BBB, BBB)
-/// BBB = (AAA, ZZZ)
-/// ZZZ = (ZZZ, ZZZ)");
-/// assert_eq!(6, part1(&input));
-/// ```
 pub fn part1(input: &WastelandMap) -> u32 {
     input
         .instructions
         .iter()
         .cycle()
-        .scan(0u16, |node, instruction| {
-            let next = input.base26_lookup[*node as usize].unwrap()[(*instruction) as usize];
+        .scan(Node::pack("AAA").unwrap() as u16, |node, instruction| {
+            let next = input.packed[*node as usize].unwrap()[(*instruction) as usize];
 
-            if next == 17575 {
+            if next as usize == Node::pack("ZZZ").unwrap() {
                 None
             } else {
                 *node = next;
-                Some(input.base26_lookup[*node as usize].unwrap()[(*instruction) as usize])
+                Some(input.packed[*node as usize].unwrap()[(*instruction) as usize])
             }
         })
         .count() as u32
         + 1
 }
 
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"RL
-///
-/// AAA = (BBB, CCC)
-/// BBB = (DDD, EEE)
-/// CCC = (ZZZ, GGG)
-/// DDD = (DDD, DDD)
-/// EEE = (EEE, EEE)
-/// GGG = (GGG, GGG)
-/// ZZZ = (ZZZ, ZZZ)");
-/// assert_eq!(2, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"LLR
-///
-/// AAA = (BBB, BBB)
-/// BBB = (AAA, ZZZ)
-/// ZZZ = (ZZZ, ZZZ)");
-/// assert_eq!(6, part1(&input));
-/// ```
 pub fn part1_hashmap(input: &WastelandMap) -> u32 {
     input
         .instructions
@@ -147,25 +104,10 @@ pub fn part1_hashmap(input: &WastelandMap) -> u32 {
         + 1
 }
 
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"LR
-///
-/// AAA = (AAB, XXX)
-/// AAB = (XXX, AAZ)
-/// AAZ = (AAB, XXX)
-/// BBA = (BBB, XXX)
-/// BBB = (BBC, BBC)
-/// BBC = (BBZ, BBZ)
-/// BBZ = (BBB, BBB)
-/// XXX = (XXX, XXX)");
-/// assert_eq!(6, part2(&input));
-/// ```
 pub fn part2(input: &WastelandMap) -> u64 {
-    (0..input.base26_lookup.len())
-        .step_by(26)
-        .filter_map(|entry| input.base26_lookup[entry].map(|_| entry as u16))
+    let (start, end) = (intern::digit(b'A'), intern::digit(b'Z'));
+    let cycles = (0..input.packed.len())
+        .filter(|&node| Some(node % 36) == start && input.packed[node].is_some())
         .map(|start_node| {
             input
                 .instructions
@@ -173,32 +115,17 @@ pub fn part2(input: &WastelandMap) -> u64 {
                 .cycle()
                 .scan(start_node, |node, instruction| {
                     let current = *node;
-                    *node = input.base26_lookup[(*node) as usize].unwrap()[*instruction as usize];
+                    *node = input.packed[*node].unwrap()[*instruction as usize] as usize;
                     Some(current)
                 })
-                .take_while(|step| step % 26 != 25)
+                .take_while(|step| Some(step % 36) != end)
                 .count() as u64
-        })
-        .fold(1u64, |lcm: u64, cycle| lcm.lcm(&cycle))
+        });
+    math::lcm_all(cycles)
 }
 
-/// ```rust
-/// use advent_of_code_2023::day08::*;
-/// let input = parse(
-/// r"LR
-///
-/// AAA = (AAB, XXX)
-/// AAB = (XXX, AAZ)
-/// AAZ = (AAB, XXX)
-/// BBA = (BBB, XXX)
-/// BBB = (BBC, BBC)
-/// BBC = (BBZ, BBZ)
-/// BBZ = (BBB, BBB)
-/// XXX = (XXX, XXX)");
-/// assert_eq!(6, part2(&input));
-/// ```
 pub fn part2_hashmap(input: &WastelandMap) -> u64 {
-    input
+    let cycles = input
         .map
         .keys()
         .filter(|node| node.ends_with('A'))
@@ -214,6 +141,6 @@ pub fn part2_hashmap(input: &WastelandMap) -> u64 {
                 })
                 .take_while(|step| !step.ends_with("Z"))
                 .count() as u64
-        })
-        .fold(1u64, |lcm: u64, cycle| lcm.lcm(&cycle))
+        });
+    math::lcm_all(cycles)
 }
@@ -1,44 +1,18 @@
 use advent::*;
 
-advent_day!(Day09, parse, Vec<Vec<i32>>, part1, part2);
+advent_day!(Day09, 9, parse, Vec<Vec<i32>>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<Vec<i32>> {
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
     input
         .lines()
         .map(|line| {
             line.split_whitespace()
-                .map(|item| item.parse::<i32>().unwrap())
+                .map(|item| parse::token(input, item))
                 .collect()
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"0 3 6 9 12 15");
-/// assert_eq!(18, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"1 3 6 10 15 21");
-/// assert_eq!(28, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"10 13 16 21 30 45");
-/// assert_eq!(68, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"0 3 6 9 12 15
-/// 1 3 6 10 15 21
-/// 10 13 16 21 30 45");
-/// assert_eq!(114, part1(&input));
-/// ```
 pub fn part1(input: &Vec<Vec<i32>>) -> i32 {
     // Pretty sure this can be solved with geometric series shit, but I'm not a fucking math major
     input
@@ -73,32 +47,6 @@ pub fn part1(input: &Vec<Vec<i32>>) -> i32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"0 3 6 9 12 15");
-/// assert_eq!(-3, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"1 3 6 10 15 21");
-/// assert_eq!(0, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"10 13 16 21 30 45");
-/// assert_eq!(5, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day09::*;
-/// let input = parse(
-/// r"0 3 6 9 12 15
-/// 1 3 6 10 15 21
-/// 10 13 16 21 30 45");
-/// assert_eq!(2, part2(&input));
-/// ```
 pub fn part2(input: &Vec<Vec<i32>>) -> i32 {
     input
         .iter()
@@ -1,34 +1,48 @@
 use bitflags::bitflags;
 
-use advent::*;
+use advent::{
+    visualize::{Cell, Frame, Palette, Rgb},
+    *,
+};
 
-advent_day!(Day10, parse, PipeMap, part1, part2);
+advent_day!(Day10, 10, parse, PipeMap, part1, part2);
+advent_visualize!(parse, loop_regions);
 
-pub fn parse(input: &str) -> PipeMap {
-    PipeMap {
-        rows: input
-            .lines()
-            .map(|line| {
-                line.bytes()
-                    .map(|cell| match cell {
-                        b'|' => PipeEnds::North.union(PipeEnds::South),
-                        b'-' => PipeEnds::East.union(PipeEnds::West),
-                        b'L' => PipeEnds::North.union(PipeEnds::East),
-                        b'J' => PipeEnds::North.union(PipeEnds::West),
-                        b'7' => PipeEnds::South.union(PipeEnds::West),
-                        b'F' => PipeEnds::South.union(PipeEnds::East),
-                        b'.' => PipeEnds::empty(),
-                        b'S' => PipeEnds::Start,
-                        _ => unreachable!(),
-                    })
-                    .collect()
-            })
-            .collect(),
-        bounds: UPoint::new(
-            input.lines().next().unwrap().len() as u32,
-            input.lines().count() as u32,
-        ),
-    }
+pub fn parse(input: &str) -> Result<PipeMap, ParseError> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.bytes()
+                .enumerate()
+                .map(|(x, cell)| match cell {
+                    b'|' => Ok(PipeEnds::North.union(PipeEnds::South)),
+                    b'-' => Ok(PipeEnds::East.union(PipeEnds::West)),
+                    b'L' => Ok(PipeEnds::North.union(PipeEnds::East)),
+                    b'J' => Ok(PipeEnds::North.union(PipeEnds::West)),
+                    b'7' => Ok(PipeEnds::South.union(PipeEnds::West)),
+                    b'F' => Ok(PipeEnds::South.union(PipeEnds::East)),
+                    b'.' => Ok(PipeEnds::empty()),
+                    b'S' => Ok(PipeEnds::Start),
+                    _ => Err(ParseError::new(
+                        y + 1,
+                        x + 1,
+                        (cell as char).to_string(),
+                        "one of `|-LJ7F.S`",
+                    )),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    let width = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a row of pipes"))?
+        .len();
+    Ok(PipeMap {
+        rows,
+        bounds: UPoint::new(width as u32, input.lines().count() as u32),
+    })
 }
 
 bitflags! {
@@ -61,26 +75,6 @@ impl PipeMap {
     }
 }
 
-/// ```rust
-/// use advent_of_code_2023::day10::*;
-/// let input = parse(
-/// r".....
-/// .S-7.
-/// .|.|.
-/// .L-J.
-/// .....");
-/// assert_eq!(4, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day10::*;
-/// let input = parse(
-/// r"7-F7-
-/// .FJ|7
-/// SJLL7
-/// |F--J
-/// LJ.LJ");
-/// assert_eq!(8, part1(&input));
-/// ```
 pub fn part1(map: &PipeMap) -> u32 {
     // Find start
     let start = map
@@ -149,52 +143,36 @@ pub fn part1(map: &PipeMap) -> u32 {
     distance / 2
 }
 
-/// ```rust
-/// use advent_of_code_2023::day10::*;
-/// let input = parse(
-/// r"...........
-/// .S-------7.
-/// .|F-----7|.
-/// .||.....||.
-/// .||.....||.
-/// .|L-7.F-J|.
-/// .|..|.|..|.
-/// .L--J.L--J.
-/// ...........");
-/// assert_eq!(4, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day10::*;
-/// let input = parse(
-/// r".F----7F7F7F7F-7....
-/// .|F--7||||||||FJ....
-/// .||.FJ||||||||L7....
-/// FJL7L7LJLJ||LJ.L-7..
-/// L--J.L7...LJS7F-7L7.
-/// ....F-J..F7FJ|L7L7L7
-/// ....L7.F7||L7|.L7L7|
-/// .....|FJLJ|FJ|F7|.LJ
-/// ....FJL-7.||.||||...
-/// ....L---J.LJ.LJLJ...");
-/// assert_eq!(8, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day10::*;
-/// let input = parse(
-/// r"FF7FSF7F7F7F7F7F---7
-/// L|LJ||||||||||||F--J
-/// FL-7LJLJ||||||LJL-77
-/// F--JF--7||LJLJ7F7FJ-
-/// L---JF-JLJ.||-FJLJJ7
-/// |F|F-JF---7F7-L7L|7|
-/// |FFJF7L7F-JF7|JL---7
-/// 7-L-JL7||F7|L7F-7F7|
-/// L.L7LFJ|||||FJL7||LJ
-/// L7JLJL-JLJLJL--JLJ.L");
-/// assert_eq!(10, part2(&input));
-/// ```
 pub fn part2(map: &PipeMap) -> u32 {
+    regions(map)
+        .iter::<UPoint>()
+        .filter(|&(_, &region)| region == Region::Inside)
+        .count() as u32
+}
+
+/// Where a tile is with respect to the loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Part of the loop, with its pipe.
+    Loop(PipeEnds),
+    Inside,
+    Outside,
+}
+
+impl Palette for Region {
+    fn colour(&self) -> Rgb {
+        match self {
+            Region::Loop(_) => Rgb::YELLOW,
+            Region::Inside => Rgb::GREEN,
+            Region::Outside => Rgb::BLACK,
+        }
+    }
+}
+
+/// Which tiles are the loop, and which of the rest it has inside it.
+pub fn regions(map: &PipeMap) -> Grid<Region> {
     let mut map = (*map).clone();
+    let (width, height) = (map.bounds.x as usize, map.bounds.y as usize);
 
     // Find start
     let start = map
@@ -263,13 +241,14 @@ pub fn part2(map: &PipeMap) -> u32 {
 
     map.rows[position.1.y as usize][position.1.x as usize] |= position.0;
 
-    let mut inside = 0;
+    let mut regions = Vec::with_capacity(width * height);
 
     for row in map.rows {
         let mut loop_orientation = PipeEnds::empty();
         let mut is_inside = false;
         for cell in row {
             if cell.contains(PipeEnds::Visited) {
+                regions.push(Region::Loop(cell));
                 const NORTH_SOUTH: PipeEnds = PipeEnds::North.union(PipeEnds::South);
                 let path_orientation = cell.intersection(NORTH_SOUTH);
 
@@ -282,13 +261,54 @@ pub fn part2(map: &PipeMap) -> u32 {
                         is_inside = false;
                     }
                 }
+            } else if is_inside {
+                regions.push(Region::Inside);
             } else {
-                if is_inside {
-                    inside += 1;
-                }
+                regions.push(Region::Outside);
             }
         }
     }
 
-    inside
+    Grid::new(width, height, regions)
+}
+
+/// The loop drawn over the tiles it has inside it, as a single frame.
+///
+/// ```rust
+/// use advent_of_code_2023::day10::*;
+/// let input = parse(
+/// r"..F7.
+/// .S-7.
+/// .|.|-
+/// .L-J.
+/// .....").unwrap();
+/// let frame = loop_regions(input).next().unwrap();
+/// assert_eq!(".....\n.S-7.\n.|I|.\n.L-J.\n.....\n1 tiles inside\n", frame.to_string());
+/// ```
+pub fn loop_regions(map: PipeMap) -> impl Iterator<Item = Frame> {
+    let regions = regions(&map);
+    let frame = Frame::from_grid(&regions, |&region| match region {
+        // The start's ends are whatever got it round the loop.
+        Region::Loop(ends) if ends.contains(PipeEnds::Start) => {
+            Cell::coloured('S', region.colour())
+        }
+        Region::Loop(ends) => {
+            let glyph = match ends.difference(PipeEnds::Visited) {
+                ends if ends == PipeEnds::North | PipeEnds::South => '|',
+                ends if ends == PipeEnds::East | PipeEnds::West => '-',
+                ends if ends == PipeEnds::North | PipeEnds::East => 'L',
+                ends if ends == PipeEnds::North | PipeEnds::West => 'J',
+                ends if ends == PipeEnds::South | PipeEnds::West => '7',
+                _ => 'F',
+            };
+            Cell::coloured(glyph, region.colour())
+        }
+        Region::Inside => Cell::coloured('I', region.colour()),
+        Region::Outside => Cell::new('.'),
+    });
+    let inside = regions
+        .iter::<UPoint>()
+        .filter(|&(_, &region)| region == Region::Inside)
+        .count();
+    std::iter::once(frame.with_caption(format!("{inside} tiles inside")))
 }
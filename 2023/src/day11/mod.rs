@@ -6,9 +6,21 @@ use std::{
 
 use advent::*;
 
-advent_day!(Day11, parse, GalaxyMap, part1, part2);
+advent_day!(
+    Day11,
+    11,
+    parse,
+    GalaxyMap,
+    part1,
+    part2,
+    params = [EXPANSION],
+    part_with
+);
 
-pub fn parse(input: &str) -> GalaxyMap {
+/// How many times bigger empty rows and columns grow in part 2.
+pub const EXPANSION: Param = Param::new("expansion", "1000000");
+
+pub fn parse(input: &str) -> Result<GalaxyMap, ParseError> {
     let galaxy = input
         .lines()
         .enumerate()
@@ -22,7 +34,7 @@ pub fn parse(input: &str) -> GalaxyMap {
             })
         })
         .collect();
-    GalaxyMap { galaxy }
+    Ok(GalaxyMap { galaxy })
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -30,55 +42,10 @@ pub struct GalaxyMap {
     galaxy: Vec<UPoint>,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day11::*;
-/// let input = parse(
-/// r"...#......
-/// .......#..
-/// #.........
-/// ..........
-/// ......#...
-/// .#........
-/// .........#
-/// ..........
-/// .......#..
-/// #...#.....");
-/// assert_eq!(374, part1(&input));
-/// ```
 pub fn part1(map: &GalaxyMap) -> u64 {
     expand_universe(map, 2)
 }
 
-/// ```rust
-/// use advent_of_code_2023::day11::*;
-/// let input = parse(
-/// r"...#......
-/// .......#..
-/// #.........
-/// ..........
-/// ......#...
-/// .#........
-/// .........#
-/// ..........
-/// .......#..
-/// #...#.....");
-/// assert_eq!(1030, expand_universe(&input, 10));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day11::*;
-/// let input = parse(
-/// r"...#......
-/// .......#..
-/// #.........
-/// ..........
-/// ......#...
-/// .#........
-/// .........#
-/// ..........
-/// .......#..
-/// #...#.....");
-/// assert_eq!(8410, expand_universe(&input, 100));
-/// ```
 pub fn expand_universe(map: &GalaxyMap, expansion_rate: u32) -> u64 {
     let expansion_rate = expansion_rate - 1;
     let galaxies: Vec<_> = map
@@ -155,6 +122,16 @@ pub fn expand_universe(map: &GalaxyMap, expansion_rate: u32) -> u64 {
         .sum()
 }
 
-pub fn part2(map: &GalaxyMap) -> u64 {
-    expand_universe(map, 1_000_000)
+pub fn part2(map: &GalaxyMap) -> Answer {
+    params::with_defaults(part_with, map, 2)
+}
+
+pub fn part_with(map: &GalaxyMap, part: u8, params: &Params) -> Result<Answer, ParamError> {
+    Ok(match part {
+        1 => part1(map).into(),
+        _ => match params.get(&EXPANSION)? {
+            0 => return Err(ParamError::unsuitable(&EXPANSION, 0, "empty space can't vanish")),
+            expansion => expand_universe(map, expansion).into(),
+        },
+    })
 }
@@ -1,14 +1,14 @@
 use advent::*;
 use rayon::prelude::*;
 
-advent_day!(Day12, parse, Vec<SpringRecord<'a>>, part1, part2);
+advent_day!(Day12, 12, parse, Vec<SpringRecord<'a>>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<SpringRecord> {
+pub fn parse(input: &str) -> Result<Vec<SpringRecord>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (row, pattern) = line.split_once(" ").unwrap();
-            SpringRecord {
+            let (row, pattern) = parse::split_once(input, line, " ")?;
+            Ok(SpringRecord {
                 blocks: row
                     .split_terminator('.')
                     .filter_map(|block| {
@@ -22,9 +22,9 @@ pub fn parse(input: &str) -> Vec<SpringRecord> {
                 row: row.as_bytes(),
                 pattern: pattern
                     .split(",")
-                    .map(|number| number.parse::<usize>().unwrap())
-                    .collect(),
-            }
+                    .map(|number| parse::token(input, number))
+                    .collect::<Result<_, _>>()?,
+            })
         })
         .collect()
 }
@@ -130,53 +130,6 @@ fn matches_count<'a>(
     matches
 }
 
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"???.### 1,1,3");
-/// assert_eq!(1, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r".??..??...?##. 1,1,3");
-/// assert_eq!(4, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"?#?#?#?#?#?#?#? 1,3,1,6");
-/// assert_eq!(1, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"????.#...#... 4,1,1");
-/// assert_eq!(1, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"????.######..#####. 1,6,5");
-/// assert_eq!(4, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"?###???????? 3,2,1");
-/// assert_eq!(10, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"???.### 1,1,3
-/// .??..??...?##. 1,1,3
-/// ?#?#?#?#?#?#?#? 1,3,1,6
-/// ????.#...#... 4,1,1
-/// ????.######..#####. 1,6,5
-/// ?###???????? 3,2,1");
-/// assert_eq!(21, part1(&input));
-/// ```
 pub fn part1(input: &Vec<SpringRecord>) -> u64 {
     input
         .par_iter()
@@ -195,53 +148,6 @@ pub fn part1(input: &Vec<SpringRecord>) -> u64 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"???.### 1,1,3");
-/// assert_eq!(1, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r".??..??...?##. 1,1,3");
-/// assert_eq!(16384, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"?#?#?#?#?#?#?#? 1,3,1,6");
-/// assert_eq!(1, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"????.#...#... 4,1,1");
-/// assert_eq!(16, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"????.######..#####. 1,6,5");
-/// assert_eq!(2500, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"?###???????? 3,2,1");
-/// assert_eq!(506250, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day12::*;
-/// let input = parse(
-/// r"???.### 1,1,3
-/// .??..??...?##. 1,1,3
-/// ?#?#?#?#?#?#?#? 1,3,1,6
-/// ????.#...#... 4,1,1
-/// ????.######..#####. 1,6,5
-/// ?###???????? 3,2,1");
-/// assert_eq!(525152, part2(&input));
-/// ```
 pub fn part2(input: &Vec<SpringRecord>) -> u64 {
     input
         .par_iter()
@@ -1,8 +1,8 @@
 use advent::*;
 
-advent_day!(Day13, parse, Vec<Vec<Vec<u8>>>, part1, part2);
+advent_day!(Day13, 13, parse, Vec<Vec<Vec<u8>>>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<Vec<Vec<u8>>> {
+pub fn parse(input: &str) -> Result<Vec<Vec<Vec<u8>>>, ParseError> {
     let mut pattern_list = vec![];
     let mut pattern = vec![];
     for line in input.lines() {
@@ -15,29 +15,9 @@ pub fn parse(input: &str) -> Vec<Vec<Vec<u8>>> {
     }
 
     pattern_list.push(pattern);
-    pattern_list
+    Ok(pattern_list)
 }
 
-/// ```rust
-/// use advent_of_code_2023::day13::*;
-/// let input = parse(
-/// "#.##..##.
-/// ..#.##.#.
-///###......#
-///###......#
-/// ..#.##.#.
-/// ..##..##.
-///##.#.##.#.
-///
-///##...##..#
-///##....#..#
-/// ..##..###
-///######.##.
-///######.##.
-/// ..##..###
-///##....#..#");
-/// assert_eq!(405, part1(&input));
-/// ```
 pub fn part1(input: &Vec<Vec<Vec<u8>>>) -> u32 {
     fn vertical_mirror(pattern: &Vec<Vec<u8>>) -> Option<u32> {
         let width = pattern[0].len();
@@ -85,26 +65,6 @@ pub fn part1(input: &Vec<Vec<Vec<u8>>>) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day13::*;
-/// let input = parse(
-/// "#.##..##.
-/// ..#.##.#.
-///###......#
-///###......#
-/// ..#.##.#.
-/// ..##..##.
-///##.#.##.#.
-///
-///##...##..#
-///##....#..#
-/// ..##..###
-///######.##.
-///######.##.
-/// ..##..###
-///##....#..#");
-/// assert_eq!(400, part2(&input));
-/// ```
 pub fn part2(input: &Vec<Vec<Vec<u8>>>) -> u32 {
     fn vertical_mirror(pattern: &Vec<Vec<u8>>) -> Option<u32> {
         let width = pattern[0].len();
@@ -1,15 +1,14 @@
-use std::{
-    cmp::Ordering,
-    collections::{hash_map::DefaultHasher, HashMap},
-    hash::Hasher,
-    ops::Range,
-};
+use std::{cmp::Ordering, iter, ops::Range};
 
-use advent::*;
+use advent::{
+    visualize::{Cell, Frame, Palette, Rgb},
+    *,
+};
 
-advent_day!(Day14, parse, DishMap, part1, part2);
+advent_day!(Day14, 14, parse, DishMap, part1, part2);
+advent_visualize!(parse, tilted_dish);
 
-pub fn parse(input: &str) -> DishMap {
+pub fn parse(input: &str) -> Result<DishMap, ParseError> {
     let mut rounds = vec![];
     let mut squares = vec![];
     let mut width = 0;
@@ -33,12 +32,12 @@ pub fn parse(input: &str) -> DishMap {
         }
     }
 
-    DishMap {
+    Ok(DishMap {
         width,
         height: height + 1,
         rounds,
         squares,
-    }
+    })
 }
 
 pub struct DishMap {
@@ -48,6 +47,54 @@ pub struct DishMap {
     squares: Vec<UPoint>,
 }
 
+impl DishMap {
+    /// The rocks where they are.
+    pub fn rocks(&self) -> Grid<Rock> {
+        let mut rocks = Grid::filled(self.width, self.height, Rock::Empty);
+        for &square in &self.squares {
+            rocks[square] = Rock::Cube;
+        }
+        for &round in &self.rounds {
+            rocks[round] = Rock::Round;
+        }
+        rocks
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Palette for Rock {
+    fn colour(&self) -> Rgb {
+        match self {
+            Rock::Round => Rgb::YELLOW,
+            Rock::Cube => Rgb::GREY,
+            Rock::Empty => Rgb::BLACK,
+        }
+    }
+}
+
+impl From<Rock> for char {
+    fn from(rock: Rock) -> Self {
+        match rock {
+            Rock::Round => 'O',
+            Rock::Cube => '#',
+            Rock::Empty => '.',
+        }
+    }
+}
+
+fn frame(rocks: &Grid<Rock>) -> Frame {
+    Frame::from_grid(rocks, |&rock| match rock {
+        Rock::Empty => Cell::new('.'),
+        rock => Cell::coloured(rock.into(), rock.colour()),
+    })
+}
+
 struct Segment {
     range: Range<usize>,
     rocks: u32,
@@ -112,21 +159,6 @@ where
     segments
 }
 
-/// ```rust
-/// use advent_of_code_2023::day14::*;
-/// let input = parse(
-/// "O....#....
-/// O.OO#....#
-/// .....##...
-/// OO.#O....O
-/// .O.....O#.
-/// O.#..O.#.#
-/// ..O..#O..O
-/// .......O..
-///##....###..
-///##OO..#....");
-/// assert_eq!(136, part1(&input));
-/// ```
 pub fn part1(input: &DishMap) -> u32 {
     let mut vertical_segments =
         setup_segments(input.width, input.height, &input.squares, |p| p.x, |p| p.y);
@@ -152,21 +184,6 @@ pub fn part1(input: &DishMap) -> u32 {
     weight
 }
 
-/// ```rust
-/// use advent_of_code_2023::day14::*;
-/// let input = parse(
-/// "O....#....
-/// O.OO#....#
-/// .....##...
-/// OO.#O....O
-/// .O.....O#.
-/// O.#..O.#.#
-/// ..O..#O..O
-/// .......O..
-///##....###..
-///##OO..#....");
-/// assert_eq!(64, part2(&input));
-/// ```
 pub fn part2(input: &DishMap) -> u32 {
     let mut vertical_segments =
         setup_segments(input.width, input.height, &input.squares, |p| p.x, |p| p.y);
@@ -228,19 +245,6 @@ pub fn part2(input: &DishMap) -> u32 {
         segment.rocks += 1;
     }
 
-    let get_state = |horizontal_segments: &Vec<Vec<Segment>>| {
-        let mut hasher = DefaultHasher::new();
-        let mut load = 0;
-
-        for (y, row) in horizontal_segments.iter().enumerate() {
-            for segment in row {
-                load += segment.rocks * (input.height - y) as u32;
-                hasher.write_u32(segment.rocks);
-            }
-        }
-        (hasher.finish(), load)
-    };
-
     fn rotate<FLookup: Fn(usize, usize) -> usize>(
         from_segment: &mut Vec<Vec<Segment>>,
         to_segment: &mut Vec<Vec<Segment>>,
@@ -283,11 +287,20 @@ pub fn part2(input: &DishMap) -> u32 {
         true,
     );
 
-    let mut loop_detect = HashMap::<u64, (u32, u32)>::new();
-    let (state, load) = get_state(&horizontal_segments);
-    loop_detect.insert(state, (1, load));
-
-    for i in 2..=1000000000 {
+    // The first spin cycle is done bar its east tilt, which doesn't change the load. Each step
+    // from here finishes one cycle and gets the next one just as far.
+    let rocks = |horizontal_segments: &Vec<Vec<Segment>>| {
+        horizontal_segments
+            .iter()
+            .flatten()
+            .map(|segment| segment.rocks)
+            .collect::<Vec<_>>()
+    };
+    let initial = rocks(&horizontal_segments);
+    let spun = cycle::hashed(initial, 1000000000 - 1, |state| {
+        for (segment, &count) in horizontal_segments.iter_mut().flatten().zip(state) {
+            segment.rocks = count;
+        }
         rotate(
             &mut horizontal_segments,
             &mut vertical_segments,
@@ -312,24 +325,70 @@ pub fn part2(input: &DishMap) -> u32 {
             |from_index, to_index| lookup_table[to_index][from_index].unwrap().1,
             true,
         );
-        let (state, load) = get_state(&horizontal_segments);
-        match loop_detect.insert(state, (i, load)) {
-            Some((index, _)) => {
-                let loop_index = ((1000000000 - index) % (i - index)) + index;
-                return loop_detect
-                    .values()
-                    .find_map(|(index, load)| {
-                        if *index == loop_index {
-                            Some(*load)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap();
+        rocks(&horizontal_segments)
+    });
+
+    horizontal_segments
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| iter::repeat((input.height - y) as u32).take(row.len()))
+        .zip(spun.state)
+        .map(|(weight, rocks)| weight * rocks)
+        .sum()
+}
+
+/// The rocks once the dish is tilted north, like part 1 does.
+///
+/// ```rust
+/// use advent_of_code_2023::day14::*;
+/// let input = parse("O.#\n.O.\nOO.").unwrap();
+/// assert_eq!("OO#\nOO.\n...\n", tilted_north(&input).to_text(|&rock| rock.into()));
+/// ```
+pub fn tilted_north(input: &DishMap) -> Grid<Rock> {
+    let mut vertical_segments =
+        setup_segments(input.width, input.height, &input.squares, |p| p.x, |p| p.y);
+
+    for round in input.rounds.iter() {
+        let column = vertical_segments.get_mut(round.x as usize).unwrap();
+        let segment_index = column
+            .binary_search_by(|segment| {
+                if segment.range.contains(&(round.y as usize)) {
+                    Ordering::Equal
+                } else {
+                    segment.range.start.cmp(&(round.y as usize))
+                }
+            })
+            .unwrap();
+        column[segment_index].rocks += 1;
+    }
+
+    let mut rocks = Grid::filled(input.width, input.height, Rock::Empty);
+    for &square in &input.squares {
+        rocks[square] = Rock::Cube;
+    }
+    for (x, column) in vertical_segments.iter().enumerate() {
+        for segment in column {
+            let start = segment.range.start;
+            for y in start..start + segment.rocks as usize {
+                rocks[UPoint::new(x as u32, y as u32)] = Rock::Round;
             }
-            None => continue,
         }
     }
+    rocks
+}
 
-    unreachable!()
+/// The dish as it comes, then tilted north.
+///
+/// ```rust
+/// use advent_of_code_2023::day14::*;
+/// let input = parse("O.#\n.O.\nOO.").unwrap();
+/// let frames = tilted_dish(input).map(|frame| frame.to_string()).collect::<Vec<_>>();
+/// assert_eq!(vec!["O.#\n.O.\nOO.\nas it comes\n", "OO#\nOO.\n...\ntilted north\n"], frames);
+/// ```
+pub fn tilted_dish(input: DishMap) -> impl Iterator<Item = Frame> {
+    [
+        frame(&input.rocks()).with_caption("as it comes"),
+        frame(&tilted_north(&input)).with_caption("tilted north"),
+    ]
+    .into_iter()
 }
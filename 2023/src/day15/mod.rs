@@ -2,33 +2,31 @@ use std::{mem::MaybeUninit, str::Bytes};
 
 use advent::*;
 
-advent_day!(Day15, parse, Initialization<'a>, part1, part2);
+advent_day!(Day15, 15, parse, Initialization<'a>, part1, part2);
 
-pub fn parse(input: &str) -> Initialization {
-    Initialization {
+pub fn parse(input: &str) -> Result<Initialization, ParseError> {
+    Ok(Initialization {
         hash_test: input.lines().flat_map(|line| line.split(",")).collect(),
         instructions: input
             .lines()
-            .flat_map(|line| {
-                line.split(",").map(|item| {
-                        let (label, operation) = match item.chars().last().unwrap() {
-                            '-' => (&item[..item.len() - 1], Operation::Remove),
-                            _ => (
-                                &item[..item.len() - 2],
-                                Operation::Add(
-                                    item.chars().last().unwrap().to_digit(10).unwrap() as u8
-                                ),
-                            ),
-                        };
-                        Instruction {
-                            label,
-                            hash: hash(label.bytes()),
-                            operation,
-                        }
-                    })
+            .flat_map(|line| line.split(","))
+            .map(|item| {
+                let (label, operation) = match item.chars().last() {
+                    Some('-') => (&item[..item.len() - 1], Operation::Remove),
+                    Some(focal_length @ '0'..='9') if item.len() >= 2 => (
+                        &item[..item.len() - 2],
+                        Operation::Add(focal_length as u8 - b'0'),
+                    ),
+                    _ => return Err(ParseError::at(input, item, "`-` or `=` and a focal length")),
+                };
+                Ok(Instruction {
+                    label,
+                    hash: hash(label.bytes()),
+                    operation,
+                })
             })
-            .collect(),
-    }
+            .collect::<Result<_, _>>()?,
+    })
 }
 
 pub struct Initialization<'a> {
@@ -47,66 +45,6 @@ enum Operation {
     Add(u8),
 }
 
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("rn=1");
-/// assert_eq!(30, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("cm-");
-/// assert_eq!(253, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("qp=3");
-/// assert_eq!(97, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("cm=2");
-/// assert_eq!(47, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("qp-");
-/// assert_eq!(14, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("pc=4");
-/// assert_eq!(180, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("ot=9");
-/// assert_eq!(9, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("ab=5");
-/// assert_eq!(197, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("pc-");
-/// assert_eq!(48, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("pc=6");
-/// assert_eq!(214, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("ot=7");
-/// assert_eq!(231, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7");
-/// assert_eq!(1320, part1(&input));
-/// ```
 pub fn part1(input: &Initialization) -> u32 {
     input
         .hash_test
@@ -119,11 +57,6 @@ fn hash(bytes: Bytes) -> u8 {
     bytes.fold(0u8, |hash, b| hash.wrapping_add(b).wrapping_mul(17))
 }
 
-/// ```rust
-/// use advent_of_code_2023::day15::*;
-/// let input = parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7");
-/// assert_eq!(145, part2(&input));
-/// ```
 pub fn part2(input: &Initialization) -> u32 {
     let mut boxes = {
         let mut boxes: [MaybeUninit<Vec<(&str, u8)>>; 256] =
@@ -1,11 +1,19 @@
-use advent::*;
+use advent::{
+    visualize::{Cell, Frame, Render, Rgb},
+    *,
+};
 use bitflags::bitflags;
 use rayon::prelude::*;
 
-advent_day!(Day16, parse, LaserChessBoard, part1, part2);
+advent_day!(Day16, 16, parse, LaserChessBoard, part1, part2);
+advent_visualize!(parse, laser_beams);
 
-pub fn parse(input: &str) -> LaserChessBoard {
-    let width = input.lines().nth(0).unwrap().len();
+pub fn parse(input: &str) -> Result<LaserChessBoard, ParseError> {
+    let width = input
+        .lines()
+        .nth(0)
+        .ok_or_else(|| ParseError::end_of_input(input, "a row of tiles"))?
+        .len();
     let height = input.lines().count();
 
     let mut board = LaserChessBoard {
@@ -33,16 +41,22 @@ pub fn parse(input: &str) -> LaserChessBoard {
         ]);
     }
 
-    input.lines().enumerate().for_each(|(y, line)| {
-        line.chars().enumerate().for_each(|(x, cell)| {
+    for (y, line) in input.lines().enumerate() {
+        for (x, cell) in line.char_indices() {
             if let Some(tile_type) = match cell {
                 '.' => None,
                 '/' => Some(TileType::ForwardMirror),
                 '\\' => Some(TileType::BackMirror),
                 '-' => Some(TileType::HorizontalSplitter),
                 '|' => Some(TileType::VerticalSplitter),
-                _ => unreachable!(),
+                _ => {
+                    let found = &line[x..x + cell.len_utf8()];
+                    return Err(ParseError::at(input, found, "one of `./\\-|`"));
+                }
             } {
+                if x >= width {
+                    return Err(ParseError::at(input, &line[x..], "the end of the row"));
+                }
                 board.rows[y].push(Tile {
                     location: IPoint::new(x as i32, y as i32),
                     tile_type,
@@ -52,13 +66,13 @@ pub fn parse(input: &str) -> LaserChessBoard {
                     tile_type,
                 });
             };
-        });
+        }
 
         board.rows[y].push(Tile {
             location: IPoint::new(width as i32, y as i32),
             tile_type: TileType::Oblivion,
         });
-    });
+    }
 
     for x in 0..width {
         board.columns[x].push(Tile {
@@ -67,7 +81,7 @@ pub fn parse(input: &str) -> LaserChessBoard {
         });
     }
 
-    board
+    Ok(board)
 }
 
 pub struct LaserChessBoard {
@@ -101,38 +115,149 @@ enum TileType {
     Oblivion,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day16::*;
-/// let input = parse(
-/// r".|...\....
-/// |.-.\.....
-/// .....|-...
-/// ........|.
-/// ..........
-/// .........\
-/// ..../.\\..
-/// .-.-/..|..
-/// .|....-|.\
-/// ..//.|....");
-/// assert_eq!(46, part1(&input));
-/// ```
 pub fn part1(input: &LaserChessBoard) -> u32 {
     let starting_laser = (IPoint::origin(), LaserDirection::East);
 
     energized_tiles(input, starting_laser)
 }
 
-fn energized_tiles(input: &LaserChessBoard, starting_laser: (Point<i32>, LaserDirection)) -> u32 {
-    let mut beam_paths: Vec<Vec<LaserDirection>> = vec![];
-    for _y in 0..input.height {
-        beam_paths.push(vec![LaserDirection::empty(); input.width]);
+/// Lasers making their way across a board, a straight run at a time.
+struct Beams {
+    /// Which ways lasers have crossed every tile, row by row.
+    paths: Vec<Vec<LaserDirection>>,
+    pending: Vec<(IPoint, LaserDirection)>,
+}
+
+impl Beams {
+    fn new(board: &LaserChessBoard, starting_laser: (IPoint, LaserDirection)) -> Self {
+        Self {
+            paths: vec![vec![LaserDirection::empty(); board.width]; board.height],
+            pending: vec![starting_laser],
+        }
+    }
+
+    /// Follows a pending laser up to whatever it hits, `false` once there are none left to
+    /// follow.
+    fn advance(&mut self, board: &LaserChessBoard) -> bool {
+        while let Some((location, direction)) = self.pending.pop() {
+            // Eliminate out-of-bounds lasers
+            if location.x < 0
+                || location.y < 0
+                || location.x >= board.width as i32
+                || location.y >= board.height as i32
+            {
+                continue;
+            }
+
+            // Loop detection
+            let tile_beam_path = self.paths[location.y as usize][location.x as usize];
+            if tile_beam_path.contains(direction) {
+                // Laser path followed, skip
+                continue;
+            }
+
+            // find nearest target
+            match direction {
+                LaserDirection::East => {
+                    let target_index = board.rows[location.y as usize]
+                        .binary_search_by(|tile| tile.location.x.cmp(&location.x))
+                        .unwrap_or_else(|index| index);
+                    let target = board.rows[location.y as usize].get(target_index);
+
+                    let target_location = if let Some(tile) = target {
+                        queue_laser(&mut self.pending, direction, tile);
+                        tile.location.x.clamp(0, board.width as i32 - 1)
+                    } else {
+                        board.width as i32 - 1
+                    };
+
+                    for x in location.x..=target_location {
+                        self.paths[location.y as usize][x as usize] =
+                            self.paths[location.y as usize][x as usize].union(direction);
+                    }
+                }
+                LaserDirection::West => {
+                    let target_index = board.rows[location.y as usize]
+                        .binary_search_by(|tile| tile.location.x.cmp(&location.x))
+                        .unwrap_or_else(|index| index - 1);
+                    let target = board.rows[location.y as usize].get(target_index);
+
+                    let target_location = if let Some(tile) = target {
+                        queue_laser(&mut self.pending, direction, tile);
+                        tile.location.x.clamp(0, board.width as i32 - 1)
+                    } else {
+                        0
+                    };
+
+                    for x in target_location..=location.x {
+                        self.paths[location.y as usize][x as usize] =
+                            self.paths[location.y as usize][x as usize].union(direction);
+                    }
+                }
+                LaserDirection::South => {
+                    let target_index = board.columns[location.x as usize]
+                        .binary_search_by(|tile| tile.location.y.cmp(&location.y))
+                        .unwrap_or_else(|index| index);
+                    let target = board.columns[location.x as usize].get(target_index);
+
+                    let target_location = if let Some(tile) = target {
+                        queue_laser(&mut self.pending, direction, tile);
+                        tile.location.y.clamp(0, board.height as i32 - 1)
+                    } else {
+                        board.height as i32 - 1
+                    };
+
+                    for y in location.y..=target_location {
+                        self.paths[y as usize][location.x as usize] =
+                            self.paths[y as usize][location.x as usize].union(direction);
+                    }
+                }
+                LaserDirection::North => {
+                    let target_index = board.columns[location.x as usize]
+                        .binary_search_by(|tile| tile.location.y.cmp(&location.y))
+                        .unwrap_or_else(|index| index - 1);
+                    let target = board.columns[location.x as usize].get(target_index);
+
+                    let target_location = if let Some(tile) = target {
+                        queue_laser(&mut self.pending, direction, tile);
+                        tile.location.y.clamp(0, board.height as i32 - 1)
+                    } else {
+                        0
+                    };
+
+                    for y in target_location..=location.y {
+                        self.paths[y as usize][location.x as usize] =
+                            self.paths[y as usize][location.x as usize].union(direction);
+                    }
+                }
+                _ => unreachable!(),
+            };
+            return true;
+        }
+        false
     }
 
-    let mut pending_lasers = vec![(starting_laser)];
+    fn energized(&self) -> u32 {
+        self.paths
+            .iter()
+            .flatten()
+            .filter(|path| !path.is_empty())
+            .count() as u32
+    }
+}
 
-    let queue_laser = |pending_lasers: &mut Vec<(IPoint, LaserDirection)>,
-                       incident_laser: LaserDirection,
-                       tile: &Tile| match tile.tile_type {
+fn energized_tiles(input: &LaserChessBoard, starting_laser: (Point<i32>, LaserDirection)) -> u32 {
+    let mut beams = Beams::new(input, starting_laser);
+    while beams.advance(input) {}
+    beams.energized()
+}
+
+fn queue_laser(
+    pending_lasers: &mut Vec<(IPoint, LaserDirection)>,
+    incident_laser: LaserDirection,
+    tile: &Tile,
+) {
+    match tile.tile_type {
         TileType::Oblivion => {}
         TileType::ForwardMirror => match incident_laser {
             LaserDirection::North => {
@@ -198,131 +323,9 @@ fn energized_tiles(input: &LaserChessBoard, starting_laser: (Point<i32>, LaserDi
             }
             _ => unreachable!(),
         },
-    };
-
-    while let Some((location, direction)) = pending_lasers.pop() {
-        // Eliminate out-of-bounds lasers
-        if location.x < 0
-            || location.y < 0
-            || location.x >= input.width as i32
-            || location.y >= input.height as i32
-        {
-            continue;
-        }
-
-        // Loop detection
-        let tile_beam_path = beam_paths[location.y as usize][location.x as usize];
-        if tile_beam_path.contains(direction) {
-            // Laser path followed, skip
-            continue;
-        }
-
-        // find nearest target
-        match direction {
-            LaserDirection::East => {
-                let target_index = input.rows[location.y as usize]
-                    .binary_search_by(|tile| tile.location.x.cmp(&location.x))
-                    .unwrap_or_else(|index| index);
-                let target = input.rows[location.y as usize].get(target_index);
-
-                let target_location = if let Some(tile) = target {
-                    queue_laser(&mut pending_lasers, direction, tile);
-                    tile.location.x.clamp(0, input.width as i32 - 1)
-                } else {
-                    input.width as i32 - 1
-                };
-
-                for x in location.x..=target_location {
-                    beam_paths[location.y as usize][x as usize] =
-                        beam_paths[location.y as usize][x as usize].union(direction);
-                }
-            }
-            LaserDirection::West => {
-                let target_index = input.rows[location.y as usize]
-                    .binary_search_by(|tile| tile.location.x.cmp(&location.x))
-                    .unwrap_or_else(|index| index - 1);
-                let target = input.rows[location.y as usize].get(target_index);
-
-                let target_location = if let Some(tile) = target {
-                    queue_laser(&mut pending_lasers, direction, tile);
-                    tile.location.x.clamp(0, input.width as i32 - 1)
-                } else {
-                    0
-                };
-
-                for x in target_location..=location.x {
-                    beam_paths[location.y as usize][x as usize] =
-                        beam_paths[location.y as usize][x as usize].union(direction);
-                }
-            }
-            LaserDirection::South => {
-                let target_index = input.columns[location.x as usize]
-                    .binary_search_by(|tile| tile.location.y.cmp(&location.y))
-                    .unwrap_or_else(|index| index);
-                let target = input.columns[location.x as usize].get(target_index);
-
-                let target_location = if let Some(tile) = target {
-                    queue_laser(&mut pending_lasers, direction, tile);
-                    tile.location.y.clamp(0, input.height as i32 - 1)
-                } else {
-                    input.height as i32 - 1
-                };
-
-                for y in location.y..=target_location {
-                    beam_paths[y as usize][location.x as usize] =
-                        beam_paths[y as usize][location.x as usize].union(direction);
-                }
-            }
-            LaserDirection::North => {
-                let target_index = input.columns[location.x as usize]
-                    .binary_search_by(|tile| tile.location.y.cmp(&location.y))
-                    .unwrap_or_else(|index| index - 1);
-                let target = input.columns[location.x as usize].get(target_index);
-
-                let target_location = if let Some(tile) = target {
-                    queue_laser(&mut pending_lasers, direction, tile);
-                    tile.location.y.clamp(0, input.height as i32 - 1)
-                } else {
-                    0
-                };
-
-                for y in target_location..=location.y {
-                    beam_paths[y as usize][location.x as usize] =
-                        beam_paths[y as usize][location.x as usize].union(direction);
-                }
-            }
-            _ => unreachable!(),
-        };
-    }
-
-    let mut beam_count = 0;
-
-    for y in 0..input.height {
-        for x in 0..input.width {
-            if !beam_paths[y][x].is_empty() {
-                beam_count += 1;
-            }
-        }
     }
-
-    beam_count
 }
 
-/// ```rust
-/// use advent_of_code_2023::day16::*;
-/// let input = parse(
-/// r".|...\....
-/// |.-.\.....
-/// .....|-...
-/// ........|.
-/// ..........
-/// .........\
-/// ..../.\\..
-/// .-.-/..|..
-/// .|....-|.\
-/// ..//.|....");
-/// assert_eq!(51, part2(&input));
-/// ```
 pub fn part2(input: &LaserChessBoard) -> u32 {
     let mut starting_positions = vec![];
     for x in 0..input.width as i32 {
@@ -343,3 +346,70 @@ pub fn part2(input: &LaserChessBoard) -> u32 {
         .max()
         .unwrap()
 }
+
+/// A board with its lasers partway through energizing it.
+struct Lit {
+    board: LaserChessBoard,
+    beams: Beams,
+}
+
+impl Render for Lit {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::new(self.board.width, self.board.height);
+        for (y, paths) in self.beams.paths.iter().enumerate() {
+            for (x, path) in paths.iter().enumerate() {
+                let glyph = match *path {
+                    LaserDirection::North => '^',
+                    LaserDirection::South => 'v',
+                    LaserDirection::West => '<',
+                    LaserDirection::East => '>',
+                    path if path.is_empty() => {
+                        frame.set(UPoint::new(x as u32, y as u32), '.');
+                        continue;
+                    }
+                    path => char::from_digit(path.bits().count_ones(), 10).unwrap(),
+                };
+                frame.set(
+                    UPoint::new(x as u32, y as u32),
+                    Cell::coloured(glyph, Rgb::YELLOW),
+                );
+            }
+        }
+        for tile in self.board.rows.iter().flatten() {
+            let glyph = match tile.tile_type {
+                TileType::ForwardMirror => '/',
+                TileType::BackMirror => '\\',
+                TileType::HorizontalSplitter => '-',
+                TileType::VerticalSplitter => '|',
+                TileType::Oblivion => continue,
+            };
+            // Mirrors and splitters keep the colour of any laser they've caught.
+            let point = UPoint::new(tile.location.x as u32, tile.location.y as u32);
+            let colour = frame.cells[point].colour;
+            frame.set(point, Cell { glyph, colour });
+        }
+        frame.with_caption(format!("{} tiles energized", self.beams.energized()))
+    }
+}
+
+/// The laser from part 1 lighting up the board, a frame for every straight run it makes.
+///
+/// ```rust
+/// use advent_of_code_2023::day16::*;
+/// let input = parse(".\\.\n.-.\n...").unwrap();
+/// let frames = laser_beams(input).collect::<Vec<_>>();
+/// assert_eq!(".\\.\n.-.\n...\n0 tiles energized\n", frames[0].to_string());
+/// assert_eq!(">\\.\n<->\n...\n5 tiles energized\n", frames.last().unwrap().to_string());
+/// ```
+pub fn laser_beams(board: LaserChessBoard) -> impl Iterator<Item = Frame> {
+    let beams = Beams::new(&board, (IPoint::origin(), LaserDirection::East));
+    let mut lit = Some(Lit { board, beams });
+    std::iter::from_fn(move || {
+        let current = lit.as_mut()?;
+        let frame = current.render();
+        if !current.beams.advance(&current.board) {
+            lit = None;
+        }
+        Some(frame)
+    })
+}
@@ -5,7 +5,7 @@ use std::{
 
 use advent::*;
 
-advent_day!(Day17, parse, HeatMap, part1, part2);
+advent_day!(Day17, 17, parse, HeatMap, part1, part2);
 
 pub struct HeatMap {
     blocks: Vec<Vec<u8>>,
@@ -98,62 +98,33 @@ impl Record {
     }
 }
 
-pub fn parse(input: &str) -> HeatMap {
+pub fn parse(input: &str) -> Result<HeatMap, ParseError> {
     let bounds = UPoint::new(
-        input.lines().next().unwrap().len() as u32,
+        input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a row of heat loss digits"))?
+            .len() as u32,
         input.lines().count() as u32,
     );
-    HeatMap {
+    Ok(HeatMap {
         blocks: input
             .lines()
             .map(|line| {
-                line.chars()
-                    .map(|b| b.to_digit(10).unwrap() as u8)
+                line.char_indices()
+                    .map(|(x, b)| match b.to_digit(10) {
+                        Some(digit) => Ok(digit as u8),
+                        None => Err(ParseError::at(input, &line[x..x + b.len_utf8()], "a digit")),
+                    })
                     .collect()
             })
-            .collect(),
+            .collect::<Result<_, _>>()?,
         bounds,
         end: UPoint::new(bounds.x - 1, bounds.y - 1),
         // end: UPoint::new(5, 0),
-    }
+    })
 }
 
-/// ```rust
-/// use advent_of_code_2023::day17::*;
-/// let input = parse(
-/// r"2413432311323
-/// 3215453535623
-/// 3255245654254
-/// 3446585845452
-/// 4546657867536
-/// 1438598798454
-/// 4457876987766
-/// 3637877979653
-/// 4654967986887
-/// 4564679986453
-/// 1224686865563
-/// 2546548887735
-/// 4322674655533");
-/// assert_eq!(102, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day17::*;
-/// let input = parse(
-/// r"9419932319999
-/// 9915459939999
-/// 9999999954299
-/// 9999999999499
-/// 9999999999539
-/// 9999999999959
-/// 9999999999969
-/// 9999999999953
-/// 9999999999997
-/// 9999999999993
-/// 9999999999963
-/// 9999999999939
-/// 9999999999933");
-/// assert_eq!(102, part1(&input));
-/// ```
 pub fn part1(map: &HeatMap) -> u32 {
     let mut priority_queue = BinaryHeap::<Reverse<PathState>>::new();
     let mut best_heat: Vec<Vec<Record>> = vec![];
@@ -224,34 +195,6 @@ pub fn part1(map: &HeatMap) -> u32 {
     unreachable!()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day17::*;
-/// let input = parse(
-/// r"2413432311323
-/// 3215453535623
-/// 3255245654254
-/// 3446585845452
-/// 4546657867536
-/// 1438598798454
-/// 4457876987766
-/// 3637877979653
-/// 4654967986887
-/// 4564679986453
-/// 1224686865563
-/// 2546548887735
-/// 4322674655533");
-/// assert_eq!(94, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day17::*;
-/// let input = parse(
-/// r"111111111111
-/// 999999999991
-/// 999999999991
-/// 999999999991
-/// 999999999991");
-/// assert_eq!(71, part2(&input));
-/// ```
 pub fn part2(map: &HeatMap) -> u32 {
     let mut priority_queue = BinaryHeap::<Reverse<PathState>>::new();
     let mut best_heat: Vec<Vec<Record>> = vec![];
@@ -2,7 +2,7 @@ use advent::*;
 use bitflags::*;
 use regex::Regex;
 
-advent_day!(Day18, parse, DigPlan, part1, part2);
+advent_day!(Day18, 18, parse, DigPlan, part1, part2);
 
 pub struct DigPlan {
     intructions: Vec<DigInstruction>,
@@ -23,14 +23,16 @@ bitflags! {
     }
 }
 
-pub fn parse(input: &str) -> DigPlan {
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
     let regex = Regex::new(r"([UDLR]) (\d+) \(#([0-9a-f]{6})\)").unwrap();
-    DigPlan {
+    Ok(DigPlan {
         intructions: input
             .lines()
             .map(|line| {
-                let capture = regex.captures(line).unwrap();
-                DigInstruction {
+                let capture = regex.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "`<direction> <length> (#<colour>)`")
+                })?;
+                Ok(DigInstruction {
                     direction: match capture.get(1).unwrap().as_str() {
                         "U" => Direction::North,
                         "D" => Direction::South,
@@ -38,33 +40,14 @@ pub fn parse(input: &str) -> DigPlan {
                         "L" => Direction::West,
                         _ => unreachable!(),
                     },
-                    length: capture.get(2).unwrap().as_str().parse::<u32>().unwrap(),
+                    length: parse::token(input, capture.get(2).unwrap().as_str())?,
                     colour: u32::from_str_radix(capture.get(3).unwrap().as_str(), 16).unwrap(),
-                }
+                })
             })
-            .collect(),
-    }
+            .collect::<Result<_, _>>()?,
+    })
 }
 
-/// ```rust
-/// use advent_of_code_2023::day18::*;
-/// let input = parse(
-/// r"R 6 (#70c710)
-/// D 5 (#0dc571)
-/// L 2 (#5713f0)
-/// D 2 (#d2c081)
-/// R 2 (#59c680)
-/// D 2 (#411b91)
-/// L 5 (#8ceee2)
-/// U 2 (#caa173)
-/// L 1 (#1b58a2)
-/// U 2 (#caa171)
-/// R 2 (#7807d2)
-/// U 3 (#a77fa3)
-/// L 2 (#015232)
-/// U 2 (#7a21e3)");
-/// assert_eq!(62, part1(&input));
-/// ```
 pub fn part1(plan: &DigPlan) -> u64 {
     let mut points = vec![IPoint::origin()];
     let mut rotation_state = Rotation::Neutral;
@@ -148,25 +131,6 @@ pub fn part1(plan: &DigPlan) -> u64 {
     total.abs() as u64 / 2
 }
 
-/// ```rust
-/// use advent_of_code_2023::day18::*;
-/// let input = parse(
-/// r"R 6 (#70c710)
-/// D 5 (#0dc571)
-/// L 2 (#5713f0)
-/// D 2 (#d2c081)
-/// R 2 (#59c680)
-/// D 2 (#411b91)
-/// L 5 (#8ceee2)
-/// U 2 (#caa173)
-/// L 1 (#1b58a2)
-/// U 2 (#caa171)
-/// R 2 (#7807d2)
-/// U 3 (#a77fa3)
-/// L 2 (#015232)
-/// U 2 (#7a21e3)");
-/// assert_eq!(952408144115, part2(&input));
-/// ```
 pub fn part2(plan: &DigPlan) -> u64 {
     part1(&DigPlan {
         intructions: plan
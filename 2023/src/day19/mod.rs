@@ -1,22 +1,31 @@
-use std::{collections::HashMap, ops::RangeInclusive, sync::OnceLock};
+use std::{ops::RangeInclusive, sync::OnceLock};
 
-use advent::*;
+use advent::{intern::Interner, *};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use regex::Regex;
 
-advent_day!(Day19, parse, PartsSystem<'a>, part1, part2);
+advent_day!(Day19, 19, parse, PartsSystem, part1, part2);
 
-pub struct PartsSystem<'a> {
-    workflows: HashMap<&'a str, Workflow<'a>>,
+pub struct PartsSystem {
+    /// Indexed by the id of their name.
+    workflows: Vec<Workflow>,
+    /// The id of `in`, where every part starts.
+    entry: u16,
     parts: Vec<MachinePart>,
 }
 
-struct Workflow<'a> {
-    rules: Vec<Rule<'a>>,
-    fallback: Target<'a>,
+impl PartsSystem {
+    fn entry(&self) -> &Workflow {
+        &self.workflows[self.entry as usize]
+    }
+}
+
+struct Workflow {
+    rules: Vec<Rule>,
+    fallback: Target,
 }
 
-impl Workflow<'_> {
+impl Workflow {
     fn apply(&self, part: &MachinePart) -> &Target {
         match self.rules.iter().filter_map(|rule| rule.apply(part)).next() {
             Some(target) => target,
@@ -36,21 +45,21 @@ enum Operator {
     LessThan,
 }
 
-enum Target<'a> {
+enum Target {
     Accept,
     Reject,
-    Workflow(&'a str),
+    Workflow(u16),
 }
 
-struct Rule<'a> {
+struct Rule {
     category: Category,
     operator: Operator,
     operand: u16,
-    target: Target<'a>,
+    target: Target,
 }
 
-impl<'a> Rule<'a> {
-    fn apply(&'a self, part: &MachinePart) -> Option<&'a Target<'a>> {
+impl Rule {
+    fn apply(&self, part: &MachinePart) -> Option<&Target> {
         let value = match self.category {
             Category::X => part.x,
             Category::M => part.m,
@@ -89,35 +98,50 @@ static WORKFLOW_MATCH: OnceLock<Regex> = OnceLock::new();
 static PART_MATCH: OnceLock<Regex> = OnceLock::new();
 static RULE_MATCH: OnceLock<Regex> = OnceLock::new();
 
-pub fn parse(input: &str) -> PartsSystem {
-    let workflow_match = WORKFLOW_MATCH.get_or_init(|| Regex::new(r"(?P<name>[[:lower:]]+)\{(?P<rules>(?:(?:[xmas])(?:[<>])(?:\d+):(?:[AR]|\w+))(?:,(?:(?:[xmas])(?:[<>])(?:\d+):(?:[AR]|\w+)))*),(?P<fallback>[AR]|\w+)\}").unwrap());
+/// Workflows, a blank line, then parts, one to a line.
+///
+/// ```rust
+/// use advent_of_code_2023::day19::parse;
+/// assert!(parse("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}").is_ok());
+/// let error = parse("in{x<10:A,R}\nnope\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+/// assert_eq!((2, 1), (error.line, error.column));
+/// let error = parse("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1}").err().unwrap();
+/// assert_eq!((4, 1), (error.line, error.column));
+/// ```
+pub fn parse(input: &str) -> Result<PartsSystem, ParseError> {
+    let workflow_match = WORKFLOW_MATCH.get_or_init(|| Regex::new(r"^(?P<name>[[:lower:]]+)\{(?P<rules>(?:(?:[xmas])(?:[<>])(?:\d+):(?:[AR]|\w+))(?:,(?:(?:[xmas])(?:[<>])(?:\d+):(?:[AR]|\w+)))*),(?P<fallback>[AR]|\w+)\}$").unwrap());
     let part_match = PART_MATCH.get_or_init(|| {
-        Regex::new(r"\{x=(?P<x>\d+),m=(?P<m>\d+),a=(?P<a>\d+),s=(?P<s>\d+)\}").unwrap()
+        Regex::new(r"^\{x=(?P<x>\d+),m=(?P<m>\d+),a=(?P<a>\d+),s=(?P<s>\d+)\}$").unwrap()
     });
 
-    fn parse_workflow<'a>(rules: &'a str, fallback: &'a str) -> Workflow<'a> {
+    fn parse_workflow<'a>(
+        input: &str,
+        rules: &'a str,
+        fallback: &'a str,
+        names: &mut Interner<'a, u16>,
+    ) -> Result<Workflow, ParseError> {
         let rule_match = RULE_MATCH.get_or_init(|| {
             Regex::new(r"(?:(?P<cat>[xmas])(?P<op>[<>])(?P<operand>\d+):(?P<target>[AR]|\w+))")
                 .unwrap()
         });
 
-        Workflow {
+        Ok(Workflow {
             rules: rule_match
                 .captures_iter(rules)
-                .map(|rule_capture| Rule {
-                    category: parse_category(rule_capture.name("cat").unwrap().as_str()),
-                    operator: parse_operator(rule_capture.name("op").unwrap().as_str()),
-                    operand: rule_capture
-                        .name("operand")
-                        .unwrap()
-                        .as_str()
-                        .parse()
-                        .unwrap(),
-                    target: parse_target(rule_capture.name("target").unwrap().as_str()),
+                .map(|rule_capture| {
+                    Ok(Rule {
+                        category: parse_category(rule_capture.name("cat").unwrap().as_str()),
+                        operator: parse_operator(rule_capture.name("op").unwrap().as_str()),
+                        operand: parse::token(
+                            input,
+                            rule_capture.name("operand").unwrap().as_str(),
+                        )?,
+                        target: parse_target(rule_capture.name("target").unwrap().as_str(), names),
+                    })
                 })
-                .collect(),
-            fallback: parse_target(fallback),
-        }
+                .collect::<Result<_, _>>()?,
+            fallback: parse_target(fallback, names),
+        })
     }
 
     fn parse_category(category: &str) -> Category {
@@ -138,78 +162,81 @@ pub fn parse(input: &str) -> PartsSystem {
         }
     }
 
-    fn parse_target(target: &str) -> Target {
+    fn parse_target<'a>(target: &'a str, names: &mut Interner<'a, u16>) -> Target {
         match target.bytes().next().unwrap() {
             b'A' => Target::Accept,
             b'R' => Target::Reject,
-            _ => Target::Workflow(target),
+            _ => Target::Workflow(names.intern(target)),
         }
     }
 
-    PartsSystem {
-        workflows: input
-            .lines()
-            .filter_map(|line| {
-                workflow_match.captures(line).map(|workflow| {
-                    (
-                        workflow.name("name").unwrap().as_str(),
-                        parse_workflow(
-                            workflow.name("rules").unwrap().as_str(),
-                            workflow.name("fallback").unwrap().as_str(),
-                        ),
-                    )
-                })
+    let mut blocks = parse::blocks(input);
+    let (Some(workflow_lines), Some(part_lines)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::end_of_input(input, "workflows and then parts"));
+    };
+
+    let mut names = Interner::new();
+    let mut workflows = vec![];
+    let workflow_captures = parse::lines(workflow_lines, |line| {
+        workflow_match
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "a workflow like `px{a<2006:qkq,rfg}`"))
+    })?;
+    for workflow in workflow_captures {
+        let name = names.intern(workflow.name("name").unwrap().as_str());
+        workflows.resize_with(workflows.len().max(name as usize + 1), || None);
+        workflows[name as usize] = Some(parse_workflow(
+            input,
+            workflow.name("rules").unwrap().as_str(),
+            workflow.name("fallback").unwrap().as_str(),
+            &mut names,
+        )?);
+    }
+    workflows.resize_with(names.len(), || None);
+    let workflows = workflows
+        .into_iter()
+        .enumerate()
+        .map(|(name, workflow)| {
+            workflow.ok_or_else(|| {
+                ParseError::at(input, names.resolve(name as u16), "a defined workflow")
             })
-            .collect(),
-        parts: input
-            .lines()
-            .filter_map(|line| {
-                part_match.captures(line).map(|part| MachinePart {
-                    x: part.name("x").unwrap().as_str().parse().unwrap(),
-                    m: part.name("m").unwrap().as_str().parse().unwrap(),
-                    a: part.name("a").unwrap().as_str().parse().unwrap(),
-                    s: part.name("s").unwrap().as_str().parse().unwrap(),
-                })
+        })
+        .collect::<Result<_, _>>()?;
+    let entry = names
+        .get("in")
+        .ok_or_else(|| ParseError::end_of_input(input, "an `in` workflow"))?;
+
+    Ok(PartsSystem {
+        workflows,
+        entry,
+        parts: parse::lines(part_lines, |line| {
+            let part = part_match
+                .captures(line)
+                .ok_or_else(|| ParseError::at(input, line, "a part like `{x=787,m=2655,a=1222,s=2876}`"))?;
+            let rating = |name| parse::token(input, part.name(name).unwrap().as_str());
+            Ok(MachinePart {
+                x: rating("x")?,
+                m: rating("m")?,
+                a: rating("a")?,
+                s: rating("s")?,
             })
-            .collect(),
-    }
+        })?,
+    })
 }
 
-/// ```rust
-/// use advent_of_code_2023::day19::*;
-/// let input = parse(
-/// r"px{a<2006:qkq,m>2090:A,rfg}
-/// pv{a>1716:R,A}
-/// lnx{m>1548:A,A}
-/// rfg{s<537:gd,x>2440:R,A}
-/// qs{s>3448:A,lnx}
-/// qkq{x<1416:A,crn}
-/// crn{x>2662:A,R}
-/// in{s<1351:px,qqz}
-/// qqz{s>2770:qs,m<1801:hdj,R}
-/// gd{a>3333:R,R}
-/// hdj{m>838:A,pv}
-///
-/// {x=787,m=2655,a=1222,s=2876}
-/// {x=1679,m=44,a=2067,s=496}
-/// {x=2036,m=264,a=79,s=2244}
-/// {x=2461,m=1339,a=466,s=291}
-/// {x=2127,m=1623,a=2188,s=1013}");
-/// assert_eq!(19114, part1(&input));
-/// ```
 pub fn part1(system: &PartsSystem) -> u32 {
     system
         .parts
         .par_iter()
         .filter_map(|part| {
-            let mut workflow = system.workflows.get("in").unwrap();
+            let mut workflow = system.entry();
 
             loop {
                 match workflow.apply(part) {
                     Target::Accept => return Some(part.score()),
                     Target::Reject => return None,
-                    Target::Workflow(target_workflow) => {
-                        workflow = system.workflows.get(target_workflow).unwrap()
+                    &Target::Workflow(target_workflow) => {
+                        workflow = &system.workflows[target_workflow as usize]
                     }
                 }
             }
@@ -217,28 +244,6 @@ pub fn part1(system: &PartsSystem) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day19::*;
-/// let input = parse(
-/// r"px{a<2006:qkq,m>2090:A,rfg}
-/// pv{a>1716:R,A}
-/// lnx{m>1548:A,A}
-/// rfg{s<537:gd,x>2440:R,A}
-/// qs{s>3448:A,lnx}
-/// qkq{x<1416:A,crn}
-/// crn{x>2662:A,R}
-/// in{s<1351:px,qqz}
-/// qqz{s>2770:qs,m<1801:hdj,R}
-/// gd{a>3333:R,R}
-/// hdj{m>838:A,pv}
-///
-/// {x=787,m=2655,a=1222,s=2876}
-/// {x=1679,m=44,a=2067,s=496}
-/// {x=2036,m=264,a=79,s=2244}
-/// {x=2461,m=1339,a=466,s=291}
-/// {x=2127,m=1623,a=2188,s=1013}");
-/// assert_eq!(167409079868000, part2(&input));
-/// ```
 pub fn part2(system: &PartsSystem) -> u64 {
     #[derive(Clone)]
     struct PartPattern {
@@ -338,7 +343,7 @@ pub fn part2(system: &PartsSystem) -> u64 {
         }
     }
 
-    let entry_workflow = system.workflows.get("in").unwrap();
+    let entry_workflow = system.entry();
     let mut valid_patterns = vec![];
 
     fn find_patterns(
@@ -355,7 +360,7 @@ pub fn part2(system: &PartsSystem) -> u64 {
                 Target::Reject => {}
                 Target::Workflow(target_workflow) => find_patterns(
                     system,
-                    system.workflows.get(target_workflow).unwrap(),
+                    &system.workflows[target_workflow as usize],
                     current_pattern.refine(rule),
                     valid_patterns,
                 ),
@@ -368,7 +373,7 @@ pub fn part2(system: &PartsSystem) -> u64 {
             Target::Reject => {}
             Target::Workflow(target_workflow) => find_patterns(
                 system,
-                system.workflows.get(target_workflow).unwrap(),
+                &system.workflows[target_workflow as usize],
                 current_pattern,
                 valid_patterns,
             ),
@@ -1,46 +1,36 @@
 use std::{
     cell::{Cell, RefCell},
-    collections::{HashMap, VecDeque},
+    collections::VecDeque,
     rc::Rc,
 };
 
-use advent::*;
-use num::Integer;
-use regex::Regex;
+use advent::{intern::Interner, *};
 
-advent_day!(Day20, parse, Vec<ModuleDefinition<'a>>, part1, part2);
+advent_day!(Day20, 20, parse, Vec<ModuleDefinition>, part1, part2);
 
-pub fn parse(input: &str) -> Vec<ModuleDefinition> {
-    let module_pattern =
-        Regex::new(r"(?P<name>(?:[%|&]\w+)|broadcaster) -> (?P<targets>\w+(?:, \w+)*)").unwrap();
+pub fn parse(input: &str) -> Result<Vec<ModuleDefinition>, ParseError> {
+    let mut names = Interner::new();
 
-    input
-        .lines()
-        .map(|line| {
-            let matches = module_pattern.captures(line).unwrap();
-            let name = matches.name("name").unwrap().as_str();
-            let (name, type_code) = if name.starts_with("%") {
-                (&name[1..], ModuleType::FlipFlop)
-            } else if name.starts_with("&") {
-                (&name[1..], ModuleType::Conjunction)
-            } else if name == "broadcaster" {
-                (name, ModuleType::Broadcast)
-            } else {
-                unreachable!()
-            };
-            ModuleDefinition {
-                name,
-                type_code,
-                targets: matches
-                    .name("targets")
-                    .unwrap()
-                    .as_str()
-                    .split_terminator(",")
-                    .map(|target| target.trim())
-                    .collect(),
-            }
+    parse::lines(input, |line| {
+        let (name, targets) = parse::split_once(input, line, " -> ")?;
+        let (name, type_code) = if name.starts_with("%") {
+            (&name[1..], ModuleType::FlipFlop)
+        } else if name.starts_with("&") {
+            (&name[1..], ModuleType::Conjunction)
+        } else if name == "broadcaster" {
+            (name, ModuleType::Broadcast)
+        } else {
+            return Err(ParseError::at(input, name, "`%`, `&` or `broadcaster`"));
+        };
+        Ok(ModuleDefinition {
+            name: names.intern(name),
+            type_code,
+            targets: targets
+                .split_terminator(",")
+                .map(|target| names.intern(target.trim()))
+                .collect(),
         })
-        .collect()
+    })
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -51,10 +41,19 @@ enum ModuleType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ModuleDefinition<'a> {
-    name: &'a str,
+pub struct ModuleDefinition {
+    name: u16,
     type_code: ModuleType,
-    targets: Vec<&'a str>,
+    targets: Vec<u16>,
+}
+
+/// How many module names there are, so the length of a `Vec` indexed by them.
+fn module_count(input: &[ModuleDefinition]) -> usize {
+    input
+        .iter()
+        .flat_map(|module| module.targets.iter().chain([&module.name]))
+        .max()
+        .map_or(0, |&name| name as usize + 1)
 }
 
 trait Module {
@@ -138,12 +137,9 @@ impl Conjunction {
     fn cycle_check(&self, button_count: u32) -> Option<u64> {
         self.current_cycle.set(button_count);
         if self.loop_detect.borrow().iter().all(|input| *input > 0) {
-            Some(
-                self.loop_detect
-                    .borrow()
-                    .iter()
-                    .fold(1u64, |lcm: u64, cycle| lcm.lcm(&(*cycle as u64))),
-            )
+            Some(math::lcm_all(
+                self.loop_detect.borrow().iter().map(|&cycle| cycle as u64),
+            ))
         } else {
             None
         }
@@ -241,41 +237,21 @@ impl Pulse {
     }
 }
 
-/// ```rust
-/// use advent_of_code_2023::day20::*;
-/// let input = parse(
-/// r"broadcaster -> a, b, c
-/// %a -> b
-/// %b -> c
-/// %c -> inv
-/// &inv -> a");
-/// assert_eq!(32000000, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2023::day20::*;
-/// let input = parse(
-/// r"broadcaster -> a
-/// %a -> inv, con
-/// &inv -> b
-/// %b -> con
-/// &con -> output");
-/// assert_eq!(11687500, part1(&input));
-/// ```
 pub fn part1(input: &Vec<ModuleDefinition>) -> u32 {
     let null_module = Rc::new(NullModule::new());
     let broadcast = Rc::new(Broadcast::new());
-    let mut conjunctions: HashMap<&str, Rc<Conjunction>> = HashMap::new();
-    let mut flipflops: HashMap<&str, Rc<FlipFlop>> = HashMap::new();
+    let mut conjunctions: Vec<Option<Rc<Conjunction>>> = vec![None; module_count(input)];
+    let mut flipflops: Vec<Option<Rc<FlipFlop>>> = vec![None; module_count(input)];
 
     // First pass, create modules
     for module_definition in input {
         match module_definition.type_code {
             ModuleType::Broadcast => {}
             ModuleType::Conjunction => {
-                let _ = conjunctions.insert(module_definition.name, Rc::new(Conjunction::new()));
+                conjunctions[module_definition.name as usize] = Some(Rc::new(Conjunction::new()));
             }
             ModuleType::FlipFlop => {
-                let _ = flipflops.insert(module_definition.name, Rc::new(FlipFlop::new()));
+                flipflops[module_definition.name as usize] = Some(Rc::new(FlipFlop::new()));
             }
         }
     }
@@ -283,13 +259,13 @@ pub fn part1(input: &Vec<ModuleDefinition>) -> u32 {
     // Second pass, connect modules
     let button = Button::new(broadcast.clone());
     for module_definition in input {
-        let find_target = |target| {
-            conjunctions
-                .get(target)
+        let find_target = |&target: &u16| {
+            conjunctions[target as usize]
+                .as_ref()
                 .map_or_else(
                     || {
-                        flipflops
-                            .get(target)
+                        flipflops[target as usize]
+                            .as_ref()
                             .map(|ff| (ff.clone() as Rc<dyn Module>, ff.reserve_input()))
                     },
                     |cj| Some((cj.clone() as Rc<dyn Module>, cj.reserve_input())),
@@ -305,13 +281,15 @@ pub fn part1(input: &Vec<ModuleDefinition>) -> u32 {
                 }
             }
             ModuleType::Conjunction => {
-                let source = conjunctions.get(module_definition.name).unwrap();
+                let source = conjunctions[module_definition.name as usize]
+                    .as_ref()
+                    .unwrap();
                 for target in module_definition.targets.iter() {
                     source.connect(find_target(target));
                 }
             }
             ModuleType::FlipFlop => {
-                let source = flipflops.get(module_definition.name).unwrap();
+                let source = flipflops[module_definition.name as usize].as_ref().unwrap();
                 for target in module_definition.targets.iter() {
                     source.connect(find_target(target));
                 }
@@ -346,8 +324,8 @@ pub fn part1(input: &Vec<ModuleDefinition>) -> u32 {
 pub fn part2(input: &Vec<ModuleDefinition>) -> u64 {
     let null_module = Rc::new(NullModule::new());
     let broadcast = Rc::new(Broadcast::new());
-    let mut conjunctions: HashMap<&str, Rc<Conjunction>> = HashMap::new();
-    let mut flipflops: HashMap<&str, Rc<FlipFlop>> = HashMap::new();
+    let mut conjunctions: Vec<Option<Rc<Conjunction>>> = vec![None; module_count(input)];
+    let mut flipflops: Vec<Option<Rc<FlipFlop>>> = vec![None; module_count(input)];
     let mut end_node: Option<Rc<Conjunction>> = None;
 
     // First pass, create modules
@@ -355,10 +333,10 @@ pub fn part2(input: &Vec<ModuleDefinition>) -> u64 {
         match module_definition.type_code {
             ModuleType::Broadcast => {}
             ModuleType::Conjunction => {
-                let _ = conjunctions.insert(module_definition.name, Rc::new(Conjunction::new()));
+                conjunctions[module_definition.name as usize] = Some(Rc::new(Conjunction::new()));
             }
             ModuleType::FlipFlop => {
-                let _ = flipflops.insert(module_definition.name, Rc::new(FlipFlop::new()));
+                flipflops[module_definition.name as usize] = Some(Rc::new(FlipFlop::new()));
             }
         }
     }
@@ -366,13 +344,13 @@ pub fn part2(input: &Vec<ModuleDefinition>) -> u64 {
     // Second pass, connect modules
     let button = Button::new(broadcast.clone());
     for module_definition in input {
-        let find_target = |target| {
-            conjunctions
-                .get(target)
+        let find_target = |&target: &u16| {
+            conjunctions[target as usize]
+                .as_ref()
                 .map_or_else(
                     || {
-                        flipflops
-                            .get(target)
+                        flipflops[target as usize]
+                            .as_ref()
                             .map(|ff| (ff.clone() as Rc<dyn Module>, ff.reserve_input()))
                     },
                     |cj| Some((cj.clone() as Rc<dyn Module>, cj.reserve_input())),
@@ -388,7 +366,9 @@ pub fn part2(input: &Vec<ModuleDefinition>) -> u64 {
                 }
             }
             ModuleType::Conjunction => {
-                let source = conjunctions.get(module_definition.name).unwrap();
+                let source = conjunctions[module_definition.name as usize]
+                    .as_ref()
+                    .unwrap();
 
                 for target in module_definition.targets.iter() {
                     let target_module = find_target(target);
@@ -401,7 +381,7 @@ pub fn part2(input: &Vec<ModuleDefinition>) -> u64 {
                 }
             }
             ModuleType::FlipFlop => {
-                let source = flipflops.get(module_definition.name).unwrap();
+                let source = flipflops[module_definition.name as usize].as_ref().unwrap();
                 for target in module_definition.targets.iter() {
                     source.connect(find_target(target));
                 }
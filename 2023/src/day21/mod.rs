@@ -1,27 +1,50 @@
 use advent::*;
 
-advent_day!(Day21, parse, GardenMap, part1, part2);
-
-pub fn parse(input: &str) -> GardenMap {
-    GardenMap {
+advent_day!(
+    Day21,
+    21,
+    parse,
+    GardenMap,
+    part1,
+    part2,
+    params = [STEPS],
+    part_with
+);
+
+/// How far the elf walks in part 1.
+pub const STEPS: Param = Param::new("steps", "64");
+
+pub fn parse(input: &str) -> Result<GardenMap, ParseError> {
+    Ok(GardenMap {
         map: input
             .lines()
-            .map(|line| {
+            .enumerate()
+            .map(|(y, line)| {
                 line.bytes()
-                    .map(|plot| match plot {
-                        b'.' => GardenPlot::Garden,
-                        b'#' => GardenPlot::Rock,
-                        b'S' => GardenPlot::Start,
-                        _ => unreachable!(),
+                    .enumerate()
+                    .map(|(x, plot)| match plot {
+                        b'.' => Ok(GardenPlot::Garden),
+                        b'#' => Ok(GardenPlot::Rock),
+                        b'S' => Ok(GardenPlot::Start),
+                        _ => Err(ParseError::new(
+                            y + 1,
+                            x + 1,
+                            (plot as char).to_string(),
+                            "one of `.#S`",
+                        )),
                     })
                     .collect()
             })
-            .collect(),
+            .collect::<Result<_, _>>()?,
         bounds: Point::new(
-            input.lines().next().unwrap().len() as u32,
+            input
+                .lines()
+                .next()
+                .ok_or_else(|| ParseError::end_of_input(input, "a row of garden plots"))?
+                .len() as u32,
             input.lines().count() as u32,
         ),
-    }
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -36,24 +59,15 @@ pub struct GardenMap {
     bounds: UPoint,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day21::*;
-/// let input = parse(
-/// r"...........
-/// .....###.#.
-/// .###.##..#.
-/// ..#.#...#..
-/// ....#.#....
-/// .##..S####.
-/// .##..#...#.
-/// .......##..
-/// .##.#.####.
-/// .##..##.##.
-/// ...........");
-/// assert_eq!(16, step_count( &input, 6));
-/// ```
-pub fn part1(input: &GardenMap) -> u32 {
-    step_count(input, 64)
+pub fn part1(input: &GardenMap) -> Answer {
+    params::with_defaults(part_with, input, 1)
+}
+
+pub fn part_with(input: &GardenMap, part: u8, params: &Params) -> Result<Answer, ParamError> {
+    Ok(match part {
+        1 => step_count(input, params.get(&STEPS)?).into(),
+        _ => part2(input).into(),
+    })
 }
 
 pub fn step_count(input: &GardenMap, max_steps: u32) -> u32 {
@@ -5,7 +5,7 @@ use std::{
 
 use advent::*;
 
-advent_day!(Day22, parse, Vec<Line>, part1, part2);
+advent_day!(Day22, 22, parse, Vec<Line>, part1, part2);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct DigInstruction {
@@ -43,60 +43,38 @@ impl PartialOrd for Line {
 }
 
 impl Line {
-    pub fn new(start: (u32, u32, u32), end: (u32, u32, u32)) -> Self {
-        let (axis, length) = if start.0 < end.0 {
-            (Axis::X, end.0 - start.0 + 1)
-        } else if start.1 < end.1 {
-            (Axis::Y, end.1 - start.1 + 1)
+    pub fn new(start: Point3D<u32>, end: Point3D<u32>) -> Self {
+        let (axis, length) = if start.x < end.x {
+            (Axis::X, end.x - start.x + 1)
+        } else if start.y < end.y {
+            (Axis::Y, end.y - start.y + 1)
         } else {
-            (Axis::Z, end.2 - start.2 + 1)
+            (Axis::Z, end.z - start.z + 1)
         };
 
         Self {
-            x: start.0,
-            y: start.1,
-            z: start.2,
+            x: start.x,
+            y: start.y,
+            z: start.z,
             axis,
             length,
         }
     }
 }
 
-pub fn parse(input: &str) -> Vec<Line> {
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (start, end) = line.split_once("~").unwrap();
-            let mut start_coords = start.split(",").map(|p| p.parse::<u32>().unwrap());
-            let mut end_coords = end.split(",").map(|p| p.parse::<u32>().unwrap());
-            Line::new(
-                (
-                    start_coords.next().unwrap(),
-                    start_coords.next().unwrap(),
-                    start_coords.next().unwrap(),
-                ),
-                (
-                    end_coords.next().unwrap(),
-                    end_coords.next().unwrap(),
-                    end_coords.next().unwrap(),
-                ),
-            )
+            let (start, end) = parse::split_once(input, line, "~")?;
+            Ok(Line::new(
+                parse::token(input, start)?,
+                parse::token(input, end)?,
+            ))
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day22::*;
-/// let input = parse(
-/// r"1,0,1~1,2,1
-/// 0,0,2~2,0,2
-/// 0,2,3~2,2,3
-/// 0,0,4~0,2,4
-/// 2,0,5~2,2,5
-/// 0,1,6~2,1,6
-/// 1,1,8~1,1,9");
-/// assert_eq!(5, part1(&input));
-/// ```
 pub fn part1(blocks: &Vec<Line>) -> u64 {
     let mut blocks = BinaryHeap::from_iter(blocks.iter());
     let mut supporting = vec![HashSet::new()];
@@ -181,18 +159,6 @@ pub fn part1(blocks: &Vec<Line>) -> u64 {
         .count() as u64
 }
 
-/// ```rust
-/// use advent_of_code_2023::day22::*;
-/// let input = parse(
-/// r"1,0,1~1,2,1
-/// 0,0,2~2,0,2
-/// 0,2,3~2,2,3
-/// 0,0,4~0,2,4
-/// 2,0,5~2,2,5
-/// 0,1,6~2,1,6
-/// 1,1,8~1,1,9");
-/// assert_eq!(7, part2(&input));
-/// ```
 pub fn part2(blocks: &Vec<Line>) -> u64 {
     let mut blocks = BinaryHeap::from_iter(blocks.iter());
     let mut supporting = vec![HashSet::new()];
@@ -2,22 +2,40 @@ use advent::*;
 use nalgebra::*;
 // use ndarray::prelude::*;
 // use ndarray_linalg::Solve;
-use num::complex::ComplexFloat;
-use rayon::iter::{
-    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
-};
 
-advent_day!(Day24, parse, Vec<HeisenbergCompensator>, part1, part2);
-
-pub fn parse(input: &str) -> Vec<HeisenbergCompensator> {
+advent_day!(
+    Day24,
+    24,
+    parse,
+    Vec<HeisenbergCompensator>,
+    part1,
+    part2,
+    params = [MIN, MAX],
+    part_with
+);
+
+/// Where part 1's test area starts, along both x and y.
+pub const MIN: Param = Param::new("min", "200000000000000");
+/// Where the test area ends.
+pub const MAX: Param = Param::new("max", "400000000000000");
+
+/// Each hailstone is `<position> @ <velocity>`.
+///
+/// ```rust
+/// use advent_of_code_2023::day24::parse;
+/// let error = parse("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1").unwrap_err();
+/// assert_eq!((2, 13), (error.line, error.column));
+/// assert!(parse("19, 13, 30 -2, 1, -2").is_err());
+/// ```
+pub fn parse(input: &str) -> Result<Vec<HeisenbergCompensator>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (position, velocity) = line.split_once("@").unwrap();
-            HeisenbergCompensator {
-                position: position.parse().unwrap(),
-                velocity: velocity.parse().unwrap(),
-            }
+            let (position, velocity) = parse::split_once(input, line, "@")?;
+            Ok(HeisenbergCompensator {
+                position: parse::token(input, position)?,
+                velocity: parse::token(input, velocity)?,
+            })
         })
         .collect()
 }
@@ -28,19 +46,31 @@ pub struct HeisenbergCompensator {
     velocity: Point3D<i64>,
 }
 
-/// ```rust
-/// use advent_of_code_2023::day24::*;
-/// let input = parse(
-/// r"19, 13, 30 @ -2,  1, -2
-/// 18, 19, 22 @ -1, -1, -2
-/// 20, 25, 34 @ -2, -2, -4
-/// 12, 31, 28 @ -1, -2, -1
-/// 20, 19, 15 @  1, -5, -3");
-/// assert_eq!(2, snow_collisions(&input, 7f64, 27f64));
-/// ```
-/// 13038 TOO LOW!
-pub fn part1(storm: &Vec<HeisenbergCompensator>) -> u32 {
-    snow_collisions(storm, 200000000000000f64, 400000000000000f64)
+pub fn part1(storm: &Vec<HeisenbergCompensator>) -> Answer {
+    params::with_defaults(part_with, storm, 1)
+}
+
+pub fn part_with(
+    storm: &Vec<HeisenbergCompensator>,
+    part: u8,
+    params: &Params,
+) -> Result<Answer, ParamError> {
+    Ok(match part {
+        1 => {
+            let min = params.get(&MIN)?;
+            match params.get(&MAX)? {
+                max if max < min => {
+                    return Err(ParamError::unsuitable(
+                        &MAX,
+                        max,
+                        format!("the test area starts at {min}"),
+                    ))
+                }
+                max => snow_collisions(storm, min, max).into(),
+            }
+        }
+        _ => part2(storm).into(),
+    })
 }
 
 pub fn snow_collisions(storm: &Vec<HeisenbergCompensator>, min: f64, max: f64) -> u32 {
@@ -74,17 +104,7 @@ pub fn snow_collisions(storm: &Vec<HeisenbergCompensator>, min: f64, max: f64) -
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2023::day24::*;
-/// let input = parse(
-/// r"19, 13, 30 @ -2,  1, -2
-/// 18, 19, 22 @ -1, -1, -2
-/// 20, 25, 34 @ -2, -2, -4
-/// 12, 31, 28 @ -1, -2, -1
-/// 20, 19, 15 @  1, -5, -3");
-/// //assert_eq!(47, part2(&input));
-/// ```
-pub fn part2(storm: &Vec<HeisenbergCompensator>) -> f64 {
+pub fn part2(storm: &Vec<HeisenbergCompensator>) -> i64 {
     let p0 = &storm[0].position;
     let p1 = &storm[1].position;
     let p2 = &storm[2].position;
@@ -92,7 +112,7 @@ pub fn part2(storm: &Vec<HeisenbergCompensator>) -> f64 {
     let v1 = &storm[1].velocity;
     let v2 = &storm[2].velocity;
 
-    let b = [
+    let b = vector![
         (p0.y as f64 * v0.x as f64 - p1.y as f64 * v1.x as f64)
             - (p0.x as f64 * v0.y as f64 - p1.x as f64 * v1.y as f64),
         (p0.y as f64 * v0.x as f64 - p2.y as f64 * v2.x as f64)
@@ -156,10 +176,8 @@ pub fn part2(storm: &Vec<HeisenbergCompensator>) -> f64 {
 
     ];
 
-    let r = a.lu().solve_mu(&b).unwrap();
-    // println!("r: {:?}", r);
-    println!("p2: {:?}", r[0] + r[1] + r[2]);
-    r[0] + r[1] + r[2]
+    let r = a.lu().solve(&b).unwrap();
+    (r[0] + r[1] + r[2]).round() as i64
 
     // // Collection of all points by t, then for each hail
     // let mut time_points = vec![];
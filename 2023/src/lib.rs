@@ -8,7 +8,10 @@
 // #![feature(iter_map_windows)]
 // #![feature(lazy_cell)]
 // #![feature(new_uninit)]
-#![feature(test)]
+
+pub const YEAR: u16 = 2023;
+
+use advent::{DayError, LoadedDay, Solver};
 
 pub mod day01;
 pub mod day02;
@@ -33,4 +36,16 @@ pub mod day20;
 pub mod day21;
 pub mod day22;
 pub mod day23;
-// pub mod day24;
+pub mod day24;
+
+pub fn new_day(day_id: u8) -> Option<LoadedDay> {
+    try_new_day(day_id).map(|day| day.unwrap_or_else(|error| panic!("Day {day_id}: {error}")))
+}
+
+pub fn try_new_day(day_id: u8) -> Option<Result<LoadedDay, DayError>> {
+    solver(day_id).map(|solver| solver.load())
+}
+
+pub fn solver(day_id: u8) -> Option<Solver> {
+    advent::registry::solver(YEAR, day_id)
+}
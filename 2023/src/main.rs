@@ -1,33 +1,10 @@
-#![feature(test)]
-
-use advent::*;
-use advent_of_code_2023::*;
+use advent::registry;
+use advent_of_code_2023::YEAR;
 
 fn main() {
     println!("AdventOfCode 2023");
 
-    run_day!(1, day01::Day01);
-    run_day!(2, day02::Day02);
-    run_day!(3, day03::Day03);
-    run_day!(4, day04::Day04);
-    run_day!(5, day05::Day05);
-    run_day!(6, day06::Day06);
-    run_day!(7, day07::Day07);
-    run_day!(8, day08::Day08);
-    run_day!(9, day09::Day09);
-    run_day!(10, day10::Day10);
-    run_day!(11, day11::Day11);
-    run_day!(12, day12::Day12);
-    run_day!(13, day13::Day13);
-    run_day!(14, day14::Day14);
-    run_day!(15, day15::Day15);
-    run_day!(16, day16::Day16);
-    run_day!(17, day17::Day17);
-    run_day!(18, day18::Day18);
-    run_day!(19, day19::Day19);
-    run_day!(20, day20::Day20);
-    run_day!(21, day21::Day21);
-    run_day!(22, day22::Day22);
-    run_day!(23, day23::Day23);
-    // run_day!(24, day24::Day24);
+    for solver in registry::year(YEAR) {
+        println!("{}", solver.summary());
+    }
 }
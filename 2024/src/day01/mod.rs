@@ -5,32 +5,22 @@ use std::{
 
 use advent::*;
 
-advent_day!(Day01, parse, Vec<(i32, i32)>, part1, part2);
+advent_day!(Day01, 1, parse, Vec<(i32, i32)>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
         .map(|line| {
             let mut tokens = line.split_whitespace();
-            (
-                tokens.next().unwrap().parse::<i32>().unwrap(),
-                tokens.next().unwrap().parse::<i32>().unwrap(),
-            )
+            let mut location_id = || match tokens.next() {
+                Some(token) => parse::token::<i32>(input, token),
+                None => Err(ParseError::at(input, line, "two location IDs")),
+            };
+            Ok((location_id()?, location_id()?))
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day01::*;
-/// let input = parse(
-/// r"3   4
-/// 4   3
-/// 2   5
-/// 1   3
-/// 3   9
-/// 3   3");
-/// assert_eq!(11, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> i32 {
     let mut left_heap = BinaryHeap::with_capacity(1000);
     let mut right_heap = BinaryHeap::with_capacity(1000);
@@ -47,17 +37,6 @@ pub fn part1(input: &InputType) -> i32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day01::*;
-/// let input = parse(
-/// r"3   4
-/// 4   3
-/// 2   5
-/// 1   3
-/// 3   9
-/// 3   3");
-/// assert_eq!(31, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> i32 {
     let mut counts = HashMap::new();
     for key in input.iter().map(|item| item.1) {
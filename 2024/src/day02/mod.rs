@@ -1,29 +1,18 @@
 use advent::*;
 
-advent_day!(Day02, parse, Vec<Vec<i32>>, part1, part2);
+advent_day!(Day02, 2, parse, Vec<Vec<i32>>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
         .map(|line| {
             line.split_whitespace()
-                .map(|number| number.parse::<i32>().unwrap())
+                .map(|number| parse::token::<i32>(input, number))
                 .collect()
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day02::*;
-/// let input = parse(
-/// r"7 6 4 2 1
-/// 1 2 7 8 9
-/// 9 7 6 2 1
-/// 1 3 2 4 5
-/// 8 6 4 4 1
-/// 1 3 6 7 9");
-/// assert_eq!(2, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> usize {
     input.iter().filter(|&row| is_safe(row.iter())).count()
 }
@@ -43,17 +32,6 @@ fn is_safe<'a, T: Iterator<Item = &'a i32>>(input: T) -> bool {
         .all(|(count, score)| score.abs() == count)
 }
 
-/// ```rust
-/// use advent_of_code_2024::day02::*;
-/// let input = parse(
-/// r"7 6 4 2 1
-/// 1 2 7 8 9
-/// 9 7 6 2 1
-/// 1 3 2 4 5
-/// 8 6 4 4 1
-/// 1 3 6 7 9");
-/// assert_eq!(4, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> usize {
     input
         .iter()
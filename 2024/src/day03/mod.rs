@@ -2,18 +2,12 @@ use regex::Regex;
 
 use advent::*;
 
-advent_day!(Day03, parse, str, part1, part2);
+advent_day!(Day03, 3, parse, str, part1, part2);
 
-pub fn parse(input: &str) -> &InputType<'_> {
-    input
+pub fn parse(input: &str) -> Result<&InputType<'_>, ParseError> {
+    Ok(input)
 }
 
-/// ```rust
-/// use advent_of_code_2024::day03::*;
-/// let input = parse(
-/// r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
-/// assert_eq!(161, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> i32 {
     let pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
 
@@ -26,12 +20,6 @@ pub fn part1(input: &InputType) -> i32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day03::*;
-/// let input = parse(
-/// r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
-/// assert_eq!(48, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> i32 {
     let pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|(do)\(\)|(don't)\(\)").unwrap();
 
@@ -3,42 +3,27 @@ use std::{
     sync::atomic::{self, AtomicUsize},
 };
 
-use advent::*;
+use advent::{
+    visualize::{Cell, Frame, Render, Rgb},
+    *,
+};
 
-advent_day!(Day06, parse, Vec<Vec<u8>>, part1, part2);
+advent_day!(Day06, 6, parse, Grid<u8>, part1, part2);
+advent_visualize!(parse, guard_walk);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    input
-        .lines()
-        .map(|line| line.as_bytes().iter().cloned().collect())
-        .collect()
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    Grid::bytes(input)
 }
 
-/// ```rust
-/// use advent_of_code_2024::day06::*;
-/// let input = parse(
-/// r"....#.....
-/// .........#
-/// ..........
-/// ..#.......
-/// .......#..
-/// ..........
-/// .#..^.....
-/// ........#.
-/// #.........
-/// ......#...");
-/// assert_eq!(41, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> usize {
-    let bounds = Point::new(input.first().unwrap().len(), input.len());
-    let start = find_start(input).unwrap();
+    let start: Point<usize> = input.find(&b'^').unwrap();
     let mut path = HashSet::new();
     let mut pos = start;
     let mut dir = Direction::North;
     path.insert(start);
 
-    while let Some(next) = pos.direction_checked(dir, &bounds) {
-        match input[next.y][next.x] {
+    while let Some(next) = input.step(pos, dir) {
+        match input[next] {
             b'#' => dir = dir.right(),
             _ => {
                 pos = next;
@@ -50,24 +35,8 @@ pub fn part1(input: &InputType) -> usize {
     path.len()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day06::*;
-/// let input = parse(
-/// r"....#.....
-/// .........#
-/// ..........
-/// ..#.......
-/// .......#..
-/// ..........
-/// .#..^.....
-/// ........#.
-/// #.........
-/// ......#...");
-/// assert_eq!(6, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> usize {
-    let bounds = Point::new(input.first().unwrap().len(), input.len());
-    let start = find_start(input).unwrap();
+    let start: Point<usize> = input.find(&b'^').unwrap();
     let mut path = HashMap::new();
     let mut pos = start;
     let loops = AtomicUsize::new(0);
@@ -76,15 +45,15 @@ pub fn part2(input: &InputType) -> usize {
         let loops = &loops;
         let mut dir = Direction::North;
         path.insert(start, dir);
-        while let Some(next) = pos.direction_checked(dir, &bounds) {
-            match input[next.y][next.x] {
+        while let Some(next) = input.step(pos, dir) {
+            match input[next] {
                 b'#' => dir = dir.right(),
                 _ => {
                     if let None = path.insert(next, dir) {
                         // Haven't walked here, drop an obstruction down
                         let mut current_path = path.clone();
                         s.spawn(move |_| {
-                            if test_loop(input, bounds, next, pos, dir, &mut current_path) {
+                            if test_loop(input, next, pos, dir, &mut current_path) {
                                 loops.fetch_add(1, atomic::Ordering::Relaxed);
                             }
                         });
@@ -96,18 +65,17 @@ pub fn part2(input: &InputType) -> usize {
     });
 
     fn test_loop(
-        map: &Vec<Vec<u8>>,
-        bounds: Point<usize>,
+        map: &Grid<u8>,
         obstruction: Point<usize>,
         mut pos: Point<usize>,
         mut dir: Direction,
         path: &mut HashMap<Point<usize>, Direction>,
     ) -> bool {
-        while let Some(next) = pos.direction_checked(dir, &bounds) {
+        while let Some(next) = map.step(pos, dir) {
             if next == obstruction {
                 dir = dir.right()
             } else {
-                match map[next.y][next.x] {
+                match map[next] {
                     b'#' => dir = dir.right(),
                     _ => {
                         if let Some(breadcrumb) = path.insert(next, dir) {
@@ -128,13 +96,84 @@ pub fn part2(input: &InputType) -> usize {
     loops.load(atomic::Ordering::Relaxed)
 }
 
-fn find_start(input: &InputType) -> Result<Point<usize>, ()> {
-    for y in 0..input.len() {
-        for x in 0..input.first().unwrap().len() {
-            if input[y][x] == b'^' {
-                return Ok(Point::new(x, y));
-            }
+/// The guard partway through their patrol.
+struct Patrol {
+    map: Grid<u8>,
+    guard: UPoint,
+    facing: Direction,
+    visited: Grid<bool>,
+    steps: usize,
+}
+
+impl Patrol {
+    /// Turns or moves once, `false` when the guard walks off the map instead.
+    fn step(&mut self) -> bool {
+        let Some(next) = self.map.step(self.guard, self.facing) else {
+            return false;
+        };
+        if self.map[next] == b'#' {
+            self.facing = self.facing.right();
+        } else {
+            self.guard = next;
+            self.visited[next] = true;
+            self.steps += 1;
         }
+        true
     }
-    Err(())
+}
+
+impl Render for Patrol {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.map, |&tile| match tile {
+            b'#' => Cell::coloured('#', Rgb::GREY),
+            _ => Cell::new('.'),
+        });
+        for (point, _) in self
+            .visited
+            .iter::<UPoint>()
+            .filter(|&(_, &visited)| visited)
+        {
+            frame.set(point, Cell::coloured('X', Rgb::BLUE));
+        }
+        let guard = match self.facing {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        };
+        frame.set(self.guard, Cell::coloured(guard, Rgb::RED));
+        let visited = self.visited.iter::<UPoint>().filter(|&(_, &v)| v).count();
+        frame.with_caption(format!("{} steps, {visited} positions", self.steps))
+    }
+}
+
+/// The patrol from part 1, a frame for every turn and step.
+///
+/// ```rust
+/// use advent_of_code_2024::day06::*;
+/// let input = parse(".#..\n...#\n.^..").unwrap();
+/// let frames = guard_walk(input).collect::<Vec<_>>();
+/// assert_eq!(".#..\n...#\n.^..\n0 steps, 1 positions\n", frames[0].to_string());
+/// assert_eq!(".#..\n.X>#\n.X..\n2 steps, 3 positions\n", frames[3].to_string());
+/// assert_eq!(6, frames.len());
+/// ```
+pub fn guard_walk(map: InputType<'static>) -> impl Iterator<Item = Frame> {
+    let guard = map.find(&b'^').unwrap();
+    let mut visited = map.map(|_| false);
+    visited[guard] = true;
+    let mut patrol = Some(Patrol {
+        map,
+        guard,
+        facing: Direction::North,
+        visited,
+        steps: 0,
+    });
+    std::iter::from_fn(move || {
+        let current = patrol.as_mut()?;
+        let frame = current.render();
+        if !current.step() {
+            patrol = None;
+        }
+        Some(frame)
+    })
 }
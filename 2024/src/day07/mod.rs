@@ -1,37 +1,23 @@
 use advent::*;
 
-advent_day!(Day07, parse, Vec<(i64, Vec<i64>)>, part1, part2);
+advent_day!(Day07, 7, parse, Vec<(i64, Vec<i64>)>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (sum, terms) = line.split_once(':').unwrap();
-            (
-                sum.parse().unwrap(),
+            let (sum, terms) = parse::split_once(input, line, ":")?;
+            Ok((
+                parse::token(input, sum)?,
                 terms
                     .split_whitespace()
-                    .map(|term| term.parse().unwrap())
-                    .collect(),
-            )
+                    .map(|term| parse::token(input, term))
+                    .collect::<Result<_, _>>()?,
+            ))
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day07::*;
-/// let input = parse(
-/// r"190: 10 19
-/// 3267: 81 40 27
-/// 83: 17 5
-/// 156: 15 6
-/// 7290: 6 8 6 15
-/// 161011: 16 10 13
-/// 192: 17 8 14
-/// 21037: 9 7 18 13
-/// 292: 11 6 16 20");
-/// assert_eq!(3749, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> i64 {
     fn is_solvable(target: i64, terms: &Vec<i64>) -> bool {
         fn solve(accumulator: i64, terms: &[i64], target: i64) -> bool {
@@ -55,20 +41,6 @@ pub fn part1(input: &InputType) -> i64 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day07::*;
-/// let input = parse(
-/// r"190: 10 19
-/// 3267: 81 40 27
-/// 83: 17 5
-/// 156: 15 6
-/// 7290: 6 8 6 15
-/// 161011: 16 10 13
-/// 192: 17 8 14
-/// 21037: 9 7 18 13
-/// 292: 11 6 16 20");
-/// assert_eq!(11387, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> i64 {
     fn concat(left: i64, right: i64) -> i64 {
         left * 10i64.pow(right.ilog10() + 1) + right
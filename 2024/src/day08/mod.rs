@@ -2,32 +2,15 @@ use std::collections::{HashMap, HashSet};
 
 use advent::*;
 
-advent_day!(Day08, parse, Vec<Vec<u8>>, part1, part2);
+advent_day!(Day08, 8, parse, Vec<Vec<u8>>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    input
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    Ok(input
         .lines()
         .map(|line| line.as_bytes().iter().cloned().collect())
-        .collect()
+        .collect())
 }
 
-/// ```rust
-/// use advent_of_code_2024::day08::*;
-/// let input = parse(
-/// r"............
-/// ........0...
-/// .....0......
-/// .......0....
-/// ....0.......
-/// ......A.....
-/// ............
-/// ............
-/// ........A...
-/// .........A..
-/// ............
-/// ............");
-/// assert_eq!(14, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> usize {
     let bounds = Point::new(input.first().unwrap().len() as i32, input.len() as i32);
     let mut antinodes = HashSet::new();
@@ -57,23 +40,6 @@ pub fn part1(input: &InputType) -> usize {
     antinodes.len()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day08::*;
-/// let input = parse(
-/// r"............
-/// ........0...
-/// .....0......
-/// .......0....
-/// ....0.......
-/// ......A.....
-/// ............
-/// ............
-/// ........A...
-/// .........A..
-/// ............
-/// ............");
-/// assert_eq!(34, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> usize {
     let bounds = Point::new(input.first().unwrap().len() as i32, input.len() as i32);
     let mut antinodes = HashSet::new();
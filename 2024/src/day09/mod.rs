@@ -2,24 +2,23 @@ use std::iter;
 
 use advent::*;
 
-advent_day!(Day09, parse, Vec<u8>, part1, part2);
-
-pub fn parse(input: &str) -> InputType<'_> {
-    input.as_bytes().iter().map(|b| *b - b'0').collect()
+advent_day!(Day09, 9, parse, Vec<u8>, part1, part2);
+
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    let input = input.trim_end();
+    input
+        .char_indices()
+        .map(|(index, digit)| match digit.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(ParseError::at(
+                input,
+                &input[index..index + digit.len_utf8()],
+                "a digit",
+            )),
+        })
+        .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day09::*;
-/// let input = parse(
-/// r"12345");
-/// assert_eq!(60, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day09::*;
-/// let input = parse(
-/// r"2333133121414131402");
-/// assert_eq!(1928, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> isize {
     let mut head = input
         .iter()
@@ -68,12 +67,6 @@ pub fn part1(input: &InputType) -> isize {
     checksum
 }
 
-/// ```rust
-/// use advent_of_code_2024::day09::*;
-/// let input = parse(
-/// r"2333133121414131402");
-/// assert_eq!(2858, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> u64 {
     let mut file_system: Vec<_> = input
         .iter()
@@ -1,28 +1,26 @@
 use advent::*;
 use rayon::prelude::*;
 
-advent_day!(Day10, parse, Vec<Vec<u8>>, part1, part2);
+advent_day!(Day10, 10, parse, Vec<Vec<u8>>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
-        .map(|line| line.as_bytes().iter().map(|b| b - b'0').collect())
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, height)| match height.to_digit(10) {
+                    Some(height) => Ok(height as u8),
+                    None => Err(ParseError::at(
+                        input,
+                        &line[index..index + height.len_utf8()],
+                        "a digit",
+                    )),
+                })
+                .collect()
+        })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day10::*;
-/// let input = parse(
-/// r"89010123
-/// 78121874
-/// 87430965
-/// 96549874
-/// 45678903
-/// 32019012
-/// 01329801
-/// 10456732");
-/// assert_eq!(36, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> u32 {
     let bounds = UPoint::new(input.first().unwrap().len() as u32, input.len() as u32);
 
@@ -84,19 +82,6 @@ pub fn part1(input: &InputType) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day10::*;
-/// let input = parse(
-/// r"89010123
-/// 78121874
-/// 87430965
-/// 96549874
-/// 45678903
-/// 32019012
-/// 01329801
-/// 10456732");
-/// assert_eq!(81, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> u32 {
     let bounds = UPoint::new(input.first().unwrap().len() as u32, input.len() as u32);
 
@@ -1,29 +1,14 @@
 use advent::*;
 
-advent_day!(Day12, parse, Vec<Vec<u8>>, part1, part2);
+advent_day!(Day12, 12, parse, Vec<Vec<u8>>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    input
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    Ok(input
         .lines()
         .map(|line| line.as_bytes().iter().map(|b| *b).collect())
-        .collect()
+        .collect())
 }
 
-/// ```rust
-/// use advent_of_code_2024::day12::*;
-/// let input = parse(
-/// r"RRRRIICCFF
-/// RRRRIICCCF
-/// VVRRRCCFFF
-/// VVRCCCJFFF
-/// VVVVCJJCFE
-/// VVIVCCJJEE
-/// VVIIICJJEE
-/// MIIIIIJJEE
-/// MIIISIJEEE
-/// MMMISSJEEE");
-/// assert_eq!(1930, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> u32 {
     let bounds = UPoint::new(input.first().unwrap().len() as u32, input.len() as u32);
     let visited = &mut (0..bounds.y)
@@ -109,51 +94,6 @@ pub fn part1(input: &InputType) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day12::*;
-/// let input = parse(
-/// r"AAAA
-/// BBCD
-/// BBCC
-/// EEEC");
-/// assert_eq!(80, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day12::*;
-/// let input = parse(
-/// r"EEEEE
-/// EXXXX
-/// EEEEE
-/// EXXXX
-/// EEEEE");
-/// assert_eq!(236, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day12::*;
-/// let input = parse(
-/// r"AAAAAA
-/// AAABBA
-/// AAABBA
-/// ABBAAA
-/// ABBAAA
-/// AAAAAA");
-/// assert_eq!(368, part2(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day12::*;
-/// let input = parse(
-/// r"RRRRIICCFF
-/// RRRRIICCCF
-/// VVRRRCCFFF
-/// VVRCCCJFFF
-/// VVVVCJJCFE
-/// VVIVCCJJEE
-/// VVIIICJJEE
-/// MIIIIIJJEE
-/// MIIISIJEEE
-/// MMMISSJEEE");
-/// assert_eq!(1206, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> u32 {
     let bounds = UPoint::new(input.first().unwrap().len() as u32, input.len() as u32);
     let visited = &mut (0..bounds.y)
@@ -169,48 +109,23 @@ pub fn part2(input: &InputType) -> u32 {
         .collect();
 
     fn count_corners(position: UPoint, map: &InputType, bounds: &UPoint) -> u8 {
-        let mut corners = 0;
-        fn get_cell(position: Option<UPoint>, map: &InputType) -> Option<u8> {
-            if let Some(position) = position {
-                Some(map[position.y as usize][position.x as usize])
-            } else {
-                None
-            }
-        }
+        let cell = |direction| {
+            position
+                .step_checked(direction, bounds)
+                .map(|position| map[position.y as usize][position.x as usize])
+        };
         let center = Some(map[position.y as usize][position.x as usize]);
-        let east = position.east_checked(bounds);
-        let west = position.west_checked();
-        let northeast = get_cell(east.and_then(|p| p.north_checked()), map);
-        let northwest = get_cell(west.and_then(|p| p.north_checked()), map);
-        let southeast = get_cell(east.and_then(|p| p.south_checked(bounds)), map);
-        let southwest = get_cell(west.and_then(|p| p.south_checked(bounds)), map);
-        let north = get_cell(position.north_checked(), map);
-        let south = get_cell(position.south_checked(bounds), map);
-        let east = get_cell(east, map);
-        let west = get_cell(west, map);
-
-        if (northeast != center && north == center && east == center)
-            || (north != center && east != center)
-        {
-            corners += 1;
-        }
-        if (northwest != center && north == center && west == center)
-            || (north != center && west != center)
-        {
-            corners += 1;
-        }
-        if (southeast != center && south == center && east == center)
-            || (south != center && east != center)
-        {
-            corners += 1;
-        }
-        if (southwest != center && south == center && west == center)
-            || (south != center && west != center)
-        {
-            corners += 1;
-        }
 
-        corners
+        // Each pair of neighbouring sides, like north and east, can make a corner between them.
+        Direction8::CARDINAL
+            .into_iter()
+            .filter(|side| {
+                let (side, next, diagonal) =
+                    (cell(*side), cell(side.right()), cell(side.right45()));
+                (diagonal != center && side == center && next == center)
+                    || (side != center && next != center)
+            })
+            .count() as u8
     }
 
     fn measure(
@@ -2,9 +2,9 @@ use advent::*;
 use num::Integer;
 use regex::Regex;
 
-advent_day!(Day13, parse, Vec<ClawGame>, part1, part2);
+advent_day!(Day13, 13, parse, Vec<ClawGame>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     let pattern = Regex::new(
         r"Button A: X\+(?<AX>\d+), Y\+(?<AY>\d+)
 Button B: X\+(?<BX>\d+), Y\+(?<BY>\d+)
@@ -14,13 +14,16 @@ Prize: X=(?<X>\d+), Y=(?<Y>\d+)",
 
     pattern
         .captures_iter(input)
-        .map(|capture| ClawGame {
-            ax: capture.name("AX").unwrap().as_str().parse().unwrap(),
-            ay: capture.name("AY").unwrap().as_str().parse().unwrap(),
-            bx: capture.name("BX").unwrap().as_str().parse().unwrap(),
-            by: capture.name("BY").unwrap().as_str().parse().unwrap(),
-            x: capture.name("X").unwrap().as_str().parse().unwrap(),
-            y: capture.name("Y").unwrap().as_str().parse().unwrap(),
+        .map(|capture| {
+            let number = |name| parse::token(input, capture.name(name).unwrap().as_str());
+            Ok(ClawGame {
+                ax: number("AX")?,
+                ay: number("AY")?,
+                bx: number("BX")?,
+                by: number("BY")?,
+                x: number("X")?,
+                y: number("Y")?,
+            })
         })
         .collect()
 }
@@ -35,26 +38,6 @@ pub struct ClawGame {
     pub y: i32,
 }
 
-/// ```rust
-/// use advent_of_code_2024::day13::*;
-/// let input = parse(
-/// r"Button A: X+94, Y+34
-/// Button B: X+22, Y+67
-/// Prize: X=8400, Y=5400
-///
-/// Button A: X+26, Y+66
-/// Button B: X+67, Y+21
-/// Prize: X=12748, Y=12176
-///
-/// Button A: X+17, Y+86
-/// Button B: X+84, Y+37
-/// Prize: X=7870, Y=6450
-///
-/// Button A: X+69, Y+23
-/// Button B: X+27, Y+71
-/// Prize: X=18641, Y=10279");
-/// assert_eq!(480, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> i32 {
     input
         .iter()
@@ -81,26 +64,6 @@ pub fn part1(input: &InputType) -> i32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day13::*;
-/// let input = parse(
-/// r"Button A: X+94, Y+34
-/// Button B: X+22, Y+67
-/// Prize: X=8400, Y=5400
-///
-/// Button A: X+26, Y+66
-/// Button B: X+67, Y+21
-/// Prize: X=12748, Y=12176
-///
-/// Button A: X+17, Y+86
-/// Button B: X+84, Y+37
-/// Prize: X=7870, Y=6450
-///
-/// Button A: X+69, Y+23
-/// Button B: X+27, Y+71
-/// Prize: X=18641, Y=10279");
-/// assert_eq!(875318608908, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> i64 {
     input
         .iter()
@@ -1,27 +1,38 @@
-use advent::*;
+use advent::{
+    math::lcm,
+    visualize::{Cell, Frame, Render, Rgb},
+    *,
+};
 use array2d::Array2D;
-use regex::Regex;
 
-advent_day!(Day14, parse, Vec<Robot>, part1, part2);
+advent_day!(
+    Day14,
+    14,
+    parse,
+    Vec<Robot>,
+    part1,
+    part2,
+    params = [WIDTH, HEIGHT],
+    part_with
+);
+advent_visualize!(parse, robot_dance);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    let pattern = Regex::new(r"p=(?<PX>\d+),(?<PY>\d+) v=(?<VX>-?\d+),(?<VY>-?\d+)").unwrap();
-    input
-        .lines()
-        .map(|line| {
-            let capture = pattern.captures(line).unwrap();
-            Robot {
-                position: IPoint::new(
-                    capture.name("PX").unwrap().as_str().parse().unwrap(),
-                    capture.name("PY").unwrap().as_str().parse().unwrap(),
-                ),
-                velocity: IPoint::new(
-                    capture.name("VX").unwrap().as_str().parse().unwrap(),
-                    capture.name("VY").unwrap().as_str().parse().unwrap(),
-                ),
-            }
+/// How wide the floor the robots patrol is.
+pub const WIDTH: Param = Param::new("width", "101");
+/// How tall the floor the robots patrol is.
+pub const HEIGHT: Param = Param::new("height", "103");
+
+/// How many robots line up side by side in the frame around the Christmas tree.
+const TREE_RUN: i32 = 31;
+
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    parse::lines(input, |line| {
+        let [px, py, vx, vy] = parse::integer_array(input, line)?;
+        Ok(Robot {
+            position: IPoint::new(px, py),
+            velocity: IPoint::new(vx, vy),
         })
-        .collect()
+    })
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -37,41 +48,46 @@ enum Quadrant {
     SE,
 }
 
-/// ```rust
-/// use advent_of_code_2024::day14::*;
-/// let input = parse(
-/// r"p=0,4 v=3,-3
-/// p=6,3 v=-1,-3
-/// p=10,3 v=-1,2
-/// p=2,0 v=2,-1
-/// p=0,0 v=1,3
-/// p=3,0 v=-2,-2
-/// p=7,6 v=-1,-3
-/// p=3,0 v=-1,-2
-/// p=9,3 v=2,3
-/// p=7,3 v=-1,2
-/// p=2,4 v=2,-3
-/// p=9,5 v=-3,-3");
-/// assert_eq!(12, part1_with_bounds::<11, 7>(&input));
-/// ```
-pub fn part1(input: &InputType) -> u32 {
-    part1_with_bounds::<101, 103>(input)
+pub fn part1(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 1)
+}
+
+pub fn part_with(input: &InputType, part: u8, params: &Params) -> Result<Answer, ParamError> {
+    let bounds = floor_bounds(params)?;
+    match part {
+        1 => Ok(part1_with_bounds(input, bounds).into()),
+        _ if bounds.x < TREE_RUN => Err(ParamError::unsuitable(
+            &WIDTH,
+            bounds.x,
+            format!("the tree is framed by {TREE_RUN} robots in a row"),
+        )),
+        _ => part2_with_bounds(input, bounds).map(Answer::from).ok_or_else(|| {
+            ParamError::NoAnswer("the robots never line up into a tree".to_owned())
+        }),
+    }
 }
 
-pub fn part1_with_bounds<const WIDTH: i32, const HEIGHT: i32>(input: &InputType) -> u32 {
+/// The size of the floor, which needs some room each way for robots to patrol.
+fn floor_bounds(params: &Params) -> Result<IPoint, ParamError> {
+    let length = |param| match params.get(param)? {
+        length if length > 0 => Ok(length),
+        length => Err(ParamError::unsuitable(param, length, "the floor has no room")),
+    };
+    Ok(IPoint::new(length(&WIDTH)?, length(&HEIGHT)?))
+}
+
+pub fn part1_with_bounds(input: &InputType, bounds: IPoint) -> u32 {
     let mut ne_count = 0;
     let mut nw_count = 0;
     let mut se_count = 0;
     let mut sw_count = 0;
-    let x_center: i32 = WIDTH / 2;
-    let y_center: i32 = HEIGHT / 2;
+    let x_center: i32 = bounds.x / 2;
+    let y_center: i32 = bounds.y / 2;
 
     input
         .iter()
         .filter_map(|robot| {
-            let position = robot.position + robot.velocity * 100;
-            let wrapped_position =
-                IPoint::new(position.x.rem_euclid(WIDTH), position.y.rem_euclid(HEIGHT));
+            let wrapped_position = (robot.position + robot.velocity * 100).rem_euclid(&bounds);
             match (
                 wrapped_position.x.cmp(&x_center),
                 wrapped_position.y.cmp(&y_center),
@@ -93,33 +109,44 @@ pub fn part1_with_bounds<const WIDTH: i32, const HEIGHT: i32>(input: &InputType)
     ne_count * nw_count * se_count * sw_count
 }
 
-/// No test cases
-pub fn part2(input: &InputType) -> u32 {
-    part2_with_bounds::<101, 103, 32>(input)
+pub fn part2(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 2)
 }
 
-pub fn part2_with_bounds<const WIDTH: i32, const HEIGHT: i32, const TREE_RUN: i32>(
-    input: &InputType,
-) -> u32 {
-    let mut robots = input.to_owned();
+/// The robots with their starting positions folded onto the floor, which may be smaller than the
+/// one the input was written for.
+fn on_floor(robots: &[Robot], bounds: IPoint) -> Vec<Robot> {
+    robots
+        .iter()
+        .map(|robot| Robot {
+            position: robot.position.rem_euclid(&bounds),
+            velocity: robot.velocity,
+        })
+        .collect()
+}
 
-    fn update<const WIDTH: i32, const HEIGHT: i32>(robots: &mut Vec<Robot>) {
-        robots.iter_mut().for_each(|robot| {
-            robot.position.x = (robot.position.x + robot.velocity.x).rem_euclid(WIDTH);
-            robot.position.y = (robot.position.y + robot.velocity.y).rem_euclid(HEIGHT);
-        });
+/// Moves every robot on by a second, wrapping around the edges of the floor.
+fn update(robots: &mut [Robot], bounds: IPoint) {
+    for robot in robots {
+        robot.position = (robot.position + robot.velocity).rem_euclid(&bounds);
     }
+}
+
+/// The seconds until the robots line up into a tree, if they ever do before they're all back where
+/// they started.
+pub fn part2_with_bounds(input: &InputType, bounds: IPoint) -> Option<u32> {
+    let mut robots = on_floor(input, bounds);
 
-    fn run_length<const WIDTH: i32, const HEIGHT: i32>(robots: &Vec<Robot>) -> u32 {
-        let mut robo_grid = Array2D::filled_with(false, HEIGHT as usize, WIDTH as usize);
+    fn run_length(robots: &Vec<Robot>, bounds: IPoint) -> u32 {
+        let mut robo_grid = Array2D::filled_with(false, bounds.y as usize, bounds.x as usize);
         let mut max_run = 0;
         for position in robots.iter().map(|robot| robot.position) {
             robo_grid[(position.y as usize, position.x as usize)] = true;
         }
 
-        for y in 0..HEIGHT {
+        for y in 0..bounds.y {
             let mut run_length = 0;
-            for x in 0..WIDTH {
+            for x in 0..bounds.x {
                 if robo_grid[(y as usize, x as usize)] {
                     run_length += 1;
                 } else {
@@ -130,11 +157,71 @@ pub fn part2_with_bounds<const WIDTH: i32, const HEIGHT: i32, const TREE_RUN: i3
         }
         max_run
     }
+    // Every robot is back where it started after a number of seconds dividing the floor's size.
+    let period = lcm(bounds.x, bounds.y) as u32;
     let mut frame = 0;
-
-    while run_length::<WIDTH, HEIGHT>(&robots) < 31 {
+    while run_length(&robots, bounds) < TREE_RUN as u32 {
         frame += 1;
-        update::<WIDTH, HEIGHT>(&mut robots);
+        if frame == period {
+            return None;
+        }
+        update(&mut robots, bounds);
+    }
+    Some(frame)
+}
+
+/// The robots on the floor after some seconds.
+struct Floor {
+    robots: Vec<Robot>,
+    bounds: IPoint,
+    second: u32,
+}
+
+impl Render for Floor {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::new(self.bounds.x as usize, self.bounds.y as usize);
+        for robot in &self.robots {
+            frame.set(robot.position, Cell::coloured('#', Rgb::GREEN));
+        }
+        frame.with_caption(format!("{} seconds", self.second))
     }
-    frame
+}
+
+/// The robots moving about until they line up into the Christmas tree of part 2, on a floor the
+/// default size.
+pub fn robot_dance(robots: InputType<'static>) -> impl Iterator<Item = Frame> {
+    let bounds = floor_bounds(&Params::default()).expect("the default floor has room");
+    let seconds = part2_with_bounds(&robots, bounds).unwrap_or(0);
+    robot_dance_for(robots, bounds, seconds)
+}
+
+/// A frame for every second from the start up to `seconds`.
+///
+/// ```rust
+/// use advent::IPoint;
+/// use advent_of_code_2024::day14::*;
+/// let input = parse("p=0,0 v=1,1\np=2,1 v=-1,0").unwrap();
+/// let frames = robot_dance_for(input, IPoint::new(3, 2), 2).map(|frame| frame.to_string());
+/// assert_eq!(
+///     vec!["#  \n  #\n0 seconds\n", "   \n # \n1 seconds\n", "  #\n#  \n2 seconds\n"],
+///     frames.collect::<Vec<_>>()
+/// );
+/// ```
+pub fn robot_dance_for(
+    robots: InputType<'static>,
+    bounds: IPoint,
+    seconds: u32,
+) -> impl Iterator<Item = Frame> {
+    let mut floor = Floor {
+        robots: on_floor(&robots, bounds),
+        bounds,
+        second: 0,
+    };
+    (0..=seconds).map(move |second| {
+        while floor.second < second {
+            update(&mut floor.robots, bounds);
+            floor.second += 1;
+        }
+        floor.render()
+    })
 }
@@ -1,7 +1,11 @@
-use advent::*;
+use advent::{
+    visualize::{Cell, Frame, Palette, Render, Rgb},
+    *,
+};
 use array2d::Array2D;
 
-advent_day!(Day15, parse, Warehouse, part1, part2);
+advent_day!(Day15, 15, parse, Warehouse, part1, part2);
+advent_visualize!(parse, wide_warehouse);
 
 #[derive(Debug)]
 pub struct Warehouse {
@@ -19,8 +23,23 @@ pub enum Tile {
     Empty,
 }
 
-pub fn parse(input: &str) -> InputType<'_> {
-    let width = input.lines().next().unwrap().len();
+impl Palette for Tile {
+    fn colour(&self) -> Rgb {
+        match self {
+            Tile::Box | Tile::BoxL | Tile::BoxR => Rgb::YELLOW,
+            Tile::Robot => Rgb::RED,
+            Tile::Wall => Rgb::GREY,
+            Tile::Empty => Rgb::BLACK,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    let width = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a floor plan"))?
+        .len();
     let floorplan = input.lines().take(width).flat_map(|line| {
         line.as_bytes().iter().map(|tile| match tile {
             b'#' => Tile::Wall,
@@ -32,65 +51,32 @@ pub fn parse(input: &str) -> InputType<'_> {
 
     let movesets = input
         .lines()
+        .enumerate()
         .skip(width + 1)
-        .flat_map(|line| {
-            line.as_bytes().iter().map(|dir| match dir {
-                b'>' => Direction::East,
-                b'^' => Direction::North,
-                b'<' => Direction::West,
-                b'v' => Direction::South,
-                _ => panic!("At the disco"),
+        .flat_map(|(y, line)| {
+            line.bytes().enumerate().map(move |(x, dir)| match dir {
+                b'>' => Ok(Direction::East),
+                b'^' => Ok(Direction::North),
+                b'<' => Ok(Direction::West),
+                b'v' => Ok(Direction::South),
+                _ => Err(ParseError::new(
+                    y + 1,
+                    x + 1,
+                    (dir as char).to_string(),
+                    "one of `>^<v`",
+                )),
             })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Warehouse {
-        floorplan: Array2D::from_iter_row_major(floorplan, width, width).unwrap(),
+    Ok(Warehouse {
+        floorplan: Array2D::from_iter_row_major(floorplan, width, width).map_err(|_| {
+            ParseError::at(input, input, format!("a {width} by {width} floor plan"))
+        })?,
         moveset: movesets,
-    }
+    })
 }
 
-/// ```rust
-/// use advent_of_code_2024::day15::*;
-/// let input = parse(
-/// r"########
-/// ##..O.O.#
-/// ###@.O..#
-/// ##...O..#
-/// ##.#.O..#
-/// ##...O..#
-/// ##......#
-/// #########
-///
-/// <^^>>>vv<v>>v<<");
-/// assert_eq!(2028, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day15::*;
-/// let input = parse(
-/// r"##########
-/// ##..O..O.O#
-/// ##......O.#
-/// ##.OO..O.O#
-/// ##..O@..O.#
-/// ##O#..O...#
-/// ##O..O..O.#
-/// ##.OO.O.OO#
-/// ##....O...#
-/// ###########
-///
-/// <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-/// vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-/// ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-/// <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-/// ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-/// ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-/// >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-/// <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-/// ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-/// v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^");
-/// assert_eq!(10092, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> usize {
     let ((row, col), _) = input
         .floorplan
@@ -139,41 +125,18 @@ pub fn part1(input: &InputType) -> usize {
     gps(warehouse)
 }
 
-/// ```rust
-/// use advent_of_code_2024::day15::*;
-/// let input = parse(
-/// r"##########
-/// ##..O..O.O#
-/// ##......O.#
-/// ##.OO..O.O#
-/// ##..O@..O.#
-/// ##O#..O...#
-/// ##O..O..O.#
-/// ##.OO.O.OO#
-/// ##....O...#
-/// ###########
-///
-/// <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-/// vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-/// ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-/// <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-/// ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-/// ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-/// >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-/// <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-/// ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-/// v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^");
-/// assert_eq!(9021, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> usize {
-    let bounds = UPoint::new(
-        (input.floorplan.column_len() * 2) as u32,
-        input.floorplan.row_len() as u32,
-    );
+    let (mut warehouse, mut robot) = widen(&input.floorplan);
+    for &command in input.moveset.iter() {
+        robot = move_wide(&mut warehouse, robot, command);
+    }
+    gps(warehouse)
+}
 
+/// The warehouse of part 2, everything but the robot twice as wide, and where the robot starts.
+fn widen(floorplan: &Array2D<Tile>) -> (Array2D<Tile>, UPoint) {
     // Enthiccen
-    let thicc = input
-        .floorplan
+    let thicc = floorplan
         .elements_row_major_iter()
         .flat_map(|tile| match tile {
             Tile::Box => [Tile::BoxL, Tile::BoxR],
@@ -182,147 +145,147 @@ pub fn part2(input: &InputType) -> usize {
             _ => [Tile::Empty, Tile::Empty],
         });
     let mut warehouse =
-        Array2D::from_iter_row_major(thicc, bounds.y as usize, bounds.x as usize).unwrap();
+        Array2D::from_iter_row_major(thicc, floorplan.column_len(), floorplan.row_len() * 2)
+            .unwrap();
     let ((row, col), _) = warehouse
         .enumerate_row_major()
         .find(|(_, &tile)| tile == Tile::Robot)
         .unwrap();
     warehouse[(row, col)] = Tile::Empty;
-    let mut robot = UPoint::new(col as u32, row as u32);
+    (warehouse, UPoint::new(col as u32, row as u32))
+}
 
-    fn can_push_vertical(
-        warehouse: &mut Array2D<Tile>,
-        bounds: &UPoint,
-        position: UPoint,
-        direction: Direction,
-    ) -> bool {
-        if let Some(target) = position.direction_checked(direction, bounds) {
-            match warehouse[(target.y as usize, target.x as usize)] {
-                Tile::Empty => true,
-                Tile::BoxL => {
-                    can_push_vertical(warehouse, bounds, target, direction)
-                        && can_push_vertical(
-                            warehouse,
-                            bounds,
-                            UPoint::new(target.x + 1, target.y),
-                            direction,
-                        )
-                }
-                Tile::BoxR => {
-                    can_push_vertical(warehouse, bounds, target, direction)
-                        && can_push_vertical(
-                            warehouse,
-                            bounds,
-                            UPoint::new(target.x - 1, target.y),
-                            direction,
-                        )
-                }
-                Tile::Wall => false,
-                _ => unreachable!(),
+/// Moves the robot a step through the wide warehouse, pushing whatever boxes give way.
+fn move_wide(warehouse: &mut Array2D<Tile>, robot: UPoint, command: Direction) -> UPoint {
+    let bounds = UPoint::new(warehouse.row_len() as u32, warehouse.column_len() as u32);
+    let moved = match command {
+        Direction::North | Direction::South => {
+            if can_push_vertical(warehouse, &bounds, robot, command) {
+                push_tile_vertical(warehouse, &bounds, robot, command)
+            } else {
+                Err(())
             }
-        } else {
-            false
         }
-    }
+        Direction::East | Direction::West => {
+            push_tile_horizontal(warehouse, &bounds, robot, command)
+        }
+    };
+    moved.unwrap_or(robot)
+}
 
-    fn push_tile_vertical(
-        warehouse: &mut Array2D<Tile>,
-        bounds: &UPoint,
-        position: UPoint,
-        direction: Direction,
-    ) -> Result<UPoint, ()> {
-        if let Some(target) = position.direction_checked(direction, bounds) {
-            match warehouse[(target.y as usize, target.x as usize)] {
-                Tile::Empty => {
-                    swap(warehouse, position, target);
-                    Ok(target)
-                }
-                Tile::BoxL => {
-                    if push_tile_vertical(warehouse, bounds, target, direction).is_ok()
-                        && push_tile_vertical(
-                            warehouse,
-                            bounds,
-                            UPoint::new(target.x + 1, target.y),
-                            direction,
-                        )
-                        .is_ok()
-                    {
-                        swap(warehouse, position, target);
-                        Ok(target)
-                    } else {
-                        Err(())
-                    }
-                }
-                Tile::BoxR => {
-                    if push_tile_vertical(warehouse, bounds, target, direction).is_ok()
-                        && push_tile_vertical(
-                            warehouse,
-                            bounds,
-                            UPoint::new(target.x - 1, target.y),
-                            direction,
-                        )
-                        .is_ok()
-                    {
-                        swap(warehouse, position, target);
-                        Ok(target)
-                    } else {
-                        Err(())
-                    }
-                }
-                Tile::Wall => Err(()),
-                _ => unreachable!(),
+fn can_push_vertical(
+    warehouse: &mut Array2D<Tile>,
+    bounds: &UPoint,
+    position: UPoint,
+    direction: Direction,
+) -> bool {
+    if let Some(target) = position.direction_checked(direction, bounds) {
+        match warehouse[(target.y as usize, target.x as usize)] {
+            Tile::Empty => true,
+            Tile::BoxL => {
+                can_push_vertical(warehouse, bounds, target, direction)
+                    && can_push_vertical(
+                        warehouse,
+                        bounds,
+                        UPoint::new(target.x + 1, target.y),
+                        direction,
+                    )
             }
-        } else {
-            Err(())
+            Tile::BoxR => {
+                can_push_vertical(warehouse, bounds, target, direction)
+                    && can_push_vertical(
+                        warehouse,
+                        bounds,
+                        UPoint::new(target.x - 1, target.y),
+                        direction,
+                    )
+            }
+            Tile::Wall => false,
+            _ => unreachable!(),
         }
+    } else {
+        false
     }
+}
 
-    fn push_tile_horizontal(
-        warehouse: &mut Array2D<Tile>,
-        bounds: &UPoint,
-        position: UPoint,
-        direction: Direction,
-    ) -> Result<UPoint, ()> {
-        if let Some(target) = position.direction_checked(direction, bounds) {
-            match warehouse[(target.y as usize, target.x as usize)] {
-                Tile::Empty => {
+fn push_tile_vertical(
+    warehouse: &mut Array2D<Tile>,
+    bounds: &UPoint,
+    position: UPoint,
+    direction: Direction,
+) -> Result<UPoint, ()> {
+    if let Some(target) = position.direction_checked(direction, bounds) {
+        match warehouse[(target.y as usize, target.x as usize)] {
+            Tile::Empty => {
+                swap(warehouse, position, target);
+                Ok(target)
+            }
+            Tile::BoxL => {
+                if push_tile_vertical(warehouse, bounds, target, direction).is_ok()
+                    && push_tile_vertical(
+                        warehouse,
+                        bounds,
+                        UPoint::new(target.x + 1, target.y),
+                        direction,
+                    )
+                    .is_ok()
+                {
                     swap(warehouse, position, target);
                     Ok(target)
+                } else {
+                    Err(())
                 }
-                Tile::BoxL | Tile::BoxR => {
-                    if let Ok(_) = push_tile_horizontal(warehouse, bounds, target, direction) {
-                        swap(warehouse, position, target);
-                        Ok(target)
-                    } else {
-                        Err(())
-                    }
+            }
+            Tile::BoxR => {
+                if push_tile_vertical(warehouse, bounds, target, direction).is_ok()
+                    && push_tile_vertical(
+                        warehouse,
+                        bounds,
+                        UPoint::new(target.x - 1, target.y),
+                        direction,
+                    )
+                    .is_ok()
+                {
+                    swap(warehouse, position, target);
+                    Ok(target)
+                } else {
+                    Err(())
                 }
-                Tile::Wall => Err(()),
-                _ => panic!("FK"),
             }
-        } else {
-            Err(())
+            Tile::Wall => Err(()),
+            _ => unreachable!(),
         }
+    } else {
+        Err(())
     }
+}
 
-    for command in input.moveset.iter() {
-        if let Ok(position) = match command {
-            Direction::North | Direction::South => {
-                if can_push_vertical(&mut warehouse, &bounds, robot, *command) {
-                    push_tile_vertical(&mut warehouse, &bounds, robot, *command)
+fn push_tile_horizontal(
+    warehouse: &mut Array2D<Tile>,
+    bounds: &UPoint,
+    position: UPoint,
+    direction: Direction,
+) -> Result<UPoint, ()> {
+    if let Some(target) = position.direction_checked(direction, bounds) {
+        match warehouse[(target.y as usize, target.x as usize)] {
+            Tile::Empty => {
+                swap(warehouse, position, target);
+                Ok(target)
+            }
+            Tile::BoxL | Tile::BoxR => {
+                if let Ok(_) = push_tile_horizontal(warehouse, bounds, target, direction) {
+                    swap(warehouse, position, target);
+                    Ok(target)
                 } else {
                     Err(())
                 }
             }
-            Direction::East | Direction::West => {
-                push_tile_horizontal(&mut warehouse, &bounds, robot, *command)
-            }
-        } {
-            robot = position;
+            Tile::Wall => Err(()),
+            _ => panic!("FK"),
         }
+    } else {
+        Err(())
     }
-
-    gps(warehouse)
 }
 
 fn gps(warehouse: Array2D<Tile>) -> usize {
@@ -340,3 +303,64 @@ fn swap(warehouse: &mut Array2D<Tile>, position: Point<u32>, target: Point<u32>)
     warehouse[(target.y as usize, target.x as usize)] = current;
     warehouse[(position.y as usize, position.x as usize)] = Tile::Empty;
 }
+
+/// The wide warehouse of part 2 after the first `moved` moves.
+struct Shuffle {
+    warehouse: Array2D<Tile>,
+    robot: UPoint,
+    moved: usize,
+    moves: usize,
+}
+
+impl Render for Shuffle {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::new(self.warehouse.row_len(), self.warehouse.column_len());
+        for ((y, x), tile) in self.warehouse.enumerate_row_major() {
+            let cell = match tile {
+                Tile::Wall => Cell::coloured('#', tile.colour()),
+                Tile::Box => Cell::coloured('O', tile.colour()),
+                Tile::BoxL => Cell::coloured('[', tile.colour()),
+                Tile::BoxR => Cell::coloured(']', tile.colour()),
+                Tile::Robot | Tile::Empty => Cell::new('.'),
+            };
+            frame.set(UPoint::new(x as u32, y as u32), cell);
+        }
+        frame.set(self.robot, Cell::coloured('@', Tile::Robot.colour()));
+        frame.with_caption(format!("move {} of {}", self.moved, self.moves))
+    }
+}
+
+/// The robot of part 2 shoving boxes about, a frame for every move.
+///
+/// ```rust
+/// use advent_of_code_2024::day15::*;
+/// let input = parse("#####\n#.O@#\n#...#\n#####\n#####\n\n<<").unwrap();
+/// let frames = wide_warehouse(input).map(|frame| frame.to_string()).collect::<Vec<_>>();
+/// assert_eq!(3, frames.len());
+/// assert_eq!("##########\n##..[]@.##", &frames[0][..21]);
+/// assert_eq!("##########\n##[]@...##", &frames[2][..21]);
+/// ```
+/// ```rust
+/// use advent::visualize::{export::Image, Rgb};
+/// use advent_of_code_2024::day15::*;
+/// let input = parse("#####\n#.O@#\n#...#\n#####\n#####\n\n<<").unwrap();
+/// let picture = Image::from_frame(&wide_warehouse(input).last().unwrap(), 1);
+/// let row = (0..10).map(|x| picture.pixel(x, 1)).collect::<Vec<_>>();
+/// let (wall, floor) = (Rgb::GREY, Rgb::BLACK);
+/// assert_eq!(vec![wall, wall, Rgb::YELLOW, Rgb::YELLOW, Rgb::RED, floor, floor, floor, wall, wall], row);
+/// ```
+pub fn wide_warehouse(input: InputType<'static>) -> impl Iterator<Item = Frame> {
+    let (warehouse, robot) = widen(&input.floorplan);
+    let mut shuffle = Shuffle {
+        warehouse,
+        robot,
+        moved: 0,
+        moves: input.moveset.len(),
+    };
+    let first = shuffle.render();
+    std::iter::once(first).chain(input.moveset.into_iter().map(move |command| {
+        shuffle.robot = move_wide(&mut shuffle.warehouse, shuffle.robot, command);
+        shuffle.moved += 1;
+        shuffle.render()
+    }))
+}
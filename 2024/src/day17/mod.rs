@@ -1,7 +1,7 @@
 use advent::*;
 use regex::Regex;
 
-advent_day!(Day17, parse, (Registers, Vec<u8>), part1, part2);
+advent_day!(Day17, 17, parse, (Registers, Vec<u8>), part1, part2);
 
 #[derive(Debug, Clone, Copy)]
 pub struct Registers {
@@ -117,7 +117,7 @@ impl<'a> Computer<'a> {
     }
 }
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     let pattern = Regex::new(
         r"Register A: (?<A>\d+)
 Register B: (?<B>\d+)
@@ -127,21 +127,24 @@ Program: (?<P>\d(,\d)+)",
     )
     .unwrap();
 
-    let captures = pattern.captures(input).unwrap();
-    (
+    let captures = pattern
+        .captures(input)
+        .ok_or_else(|| ParseError::at(input, input, "three registers and a program"))?;
+    let register = |name| parse::token(input, captures.name(name).unwrap().as_str());
+    Ok((
         Registers {
-            a: captures.name("A").unwrap().as_str().parse().unwrap(),
-            b: captures.name("B").unwrap().as_str().parse().unwrap(),
-            c: captures.name("C").unwrap().as_str().parse().unwrap(),
+            a: register("A")?,
+            b: register("B")?,
+            c: register("C")?,
         },
         captures
             .name("P")
             .unwrap()
             .as_str()
             .split(',')
-            .map(|b| b.parse().unwrap())
-            .collect(),
-    )
+            .map(|b| parse::token(input, b))
+            .collect::<Result<_, _>>()?,
+    ))
 }
 
 /// ```rust
@@ -151,7 +154,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 0
 /// Register C: 9
 ///
-/// Program: 2,6");
+/// Program: 2,6").unwrap();
 /// assert_eq!(1, compute(&input).registers.b);
 /// ```
 /// ```rust
@@ -161,7 +164,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 0
 /// Register C: 0
 ///
-/// Program: 5,0,5,1,5,4");
+/// Program: 5,0,5,1,5,4").unwrap();
 /// assert_eq!("0,1,2".to_string(), part1(&input));
 /// ```
 /// ```rust
@@ -171,7 +174,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 0
 /// Register C: 0
 ///
-/// Program: 0,1,5,4,3,0");
+/// Program: 0,1,5,4,3,0").unwrap();
 /// assert_eq!("4,2,5,6,7,7,7,7,3,1,0".to_string(), part1(&input));
 /// ```
 /// ```rust
@@ -181,7 +184,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 29
 /// Register C: 0
 ///
-/// Program: 1,7");
+/// Program: 1,7").unwrap();
 /// assert_eq!(26, compute(&input).registers.b);
 /// ```
 /// ```rust
@@ -191,7 +194,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 2024
 /// Register C: 43690
 ///
-/// Program: 4,0");
+/// Program: 4,0").unwrap();
 /// assert_eq!(44354, compute(&input).registers.b);
 /// ```
 /// ```rust
@@ -201,7 +204,7 @@ Program: (?<P>\d(,\d)+)",
 /// Register B: 0
 /// Register C: 0
 ///
-/// Program: 0,1,5,4,3,0");
+/// Program: 0,1,5,4,3,0").unwrap();
 /// assert_eq!("4,6,3,5,6,3,5,2,1,0".to_string(), part1(&input));
 /// ```
 pub fn part1(input: &InputType) -> String {
@@ -240,19 +243,9 @@ pub fn compute<'a>(input: &'a InputType) -> Computer<'a> {
 /// Register B: 0
 /// Register C: 0
 ///
-/// Program: 0,3,5,4,3,0");
+/// Program: 0,3,5,4,3,0").unwrap();
 /// assert_eq!("0,3,5,4,3,0".to_string(), part1(&input));
 /// ```
-/// ```rust
-/// use advent_of_code_2024::day17::*;
-/// let input = parse(
-/// r"Register A: 2024
-/// Register B: 0
-/// Register C: 0
-///
-/// Program: 0,3,5,4,3,0");
-/// assert_eq!(117440, part2(&input));
-/// ```
 
 pub fn part2(input: &InputType) -> u64 {
     let (registers, program) = input;
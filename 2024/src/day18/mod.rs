@@ -1,233 +1,116 @@
-use std::collections::BinaryHeap;
-
-use advent::*;
-use array2d::Array2D;
-
-advent_day!(Day18, parse, Vec<UPoint>, part1, part2);
-
-pub fn parse(input: &str) -> InputType<'_> {
+use advent::{search::bfs, *};
+
+advent_day!(
+    Day18,
+    18,
+    parse,
+    Vec<UPoint>,
+    part1,
+    part2,
+    params = [WIDTH, HEIGHT, BYTES],
+    part_with
+);
+
+pub const WIDTH: Param = Param::new("width", "71");
+pub const HEIGHT: Param = Param::new("height", "71");
+/// How many bytes have fallen by the time part 1 looks for a way out.
+pub const BYTES: Param = Param::new("bytes", "1024");
+
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (x, y) = line.split_once(',').unwrap();
-            UPoint::new(x.parse().unwrap(), y.parse().unwrap())
+            let (x, y) = parse::split_once(input, line, ",")?;
+            Ok(UPoint::new(
+                parse::token(input, x)?,
+                parse::token(input, y)?,
+            ))
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day18::*;
-/// let input = parse(
-/// r"5,4
-/// 4,2
-/// 4,5
-/// 3,0
-/// 2,1
-/// 6,3
-/// 2,4
-/// 1,5
-/// 0,6
-/// 3,3
-/// 2,6
-/// 5,1
-/// 1,2
-/// 5,5
-/// 2,5
-/// 6,5
-/// 1,4
-/// 0,4
-/// 6,4
-/// 1,1
-/// 6,1
-/// 1,0
-/// 0,5
-/// 1,6
-/// 2,0");
-/// assert_eq!(22, memory_space::<7, 7, 12>(&input));
-/// ```
-pub fn part1(input: &InputType) -> u32 {
-    memory_space::<71, 71, 1024>(input)
+pub fn part1(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 1)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Byte {
-    Safe,
-    Path,
-    Corrupt,
+pub fn part2(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 2)
 }
 
-pub fn memory_space<const WIDTH: u32, const HEIGHT: u32, const BYTES: u32>(
-    input: &InputType,
-) -> u32 {
-    let bounds = UPoint::new(WIDTH, HEIGHT);
-    let end = UPoint::new(WIDTH - 1, HEIGHT - 1);
-    let mut memory = Array2D::filled_with(Byte::Safe, HEIGHT as usize, WIDTH as usize);
-    for address in input.iter().take(BYTES as usize) {
-        memory[(address.y as usize, address.x as usize)] = Byte::Corrupt;
-    }
-
-    let mut pending = BinaryHeap::new();
-    memory[(0, 0)] = Byte::Path;
-    pending.push(MinScore(0, UPoint::new(0, 0)));
-
-    let score = {
-        loop {
-            if let Some(MinScore(score, position)) = pending.pop() {
-                if let Some(next) = position.north_checked() {
-                    if next == end {
-                        break score + 1;
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.west_checked() {
-                    if next == end {
-                        break score + 1;
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.south_checked(&bounds) {
-                    if next == end {
-                        break score + 1;
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.east_checked(&bounds) {
-                    if next == end {
-                        break score + 1;
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-            } else {
-                panic!("Couldn't find end");
-            }
+pub fn part_with(input: &InputType, part: u8, params: &Params) -> Result<Answer, ParamError> {
+    let bounds = memory_bounds(input, params)?;
+    match part {
+        1 => {
+            let bytes = params.get(&BYTES)?;
+            shortest_path_len(input, bounds, bytes)
+                .map(Answer::from)
+                .ok_or_else(|| {
+                    ParamError::NoAnswer(format!(
+                        "the exit is cut off once {bytes} bytes have fallen"
+                    ))
+                })
         }
-    };
-
-    score
+        _ => blocking_byte(input, bounds)
+            .map(|blocking| format!("{},{}", blocking.x, blocking.y).into())
+            .ok_or_else(|| {
+                ParamError::NoAnswer("no byte ever cuts the exit off".to_owned())
+            }),
+    }
 }
 
-/// ```rust
-/// use advent_of_code_2024::day18::*;
-/// let input = parse(
-/// r"5,4
-/// 4,2
-/// 4,5
-/// 3,0
-/// 2,1
-/// 6,3
-/// 2,4
-/// 1,5
-/// 0,6
-/// 3,3
-/// 2,6
-/// 5,1
-/// 1,2
-/// 5,5
-/// 2,5
-/// 6,5
-/// 1,4
-/// 0,4
-/// 6,4
-/// 1,1
-/// 6,1
-/// 1,0
-/// 0,5
-/// 1,6
-/// 2,0");
-/// assert_eq!(advent::UPoint::new(6,1), blocking_byte::<7, 7>(&input));
-/// ```
-pub fn part2(input: &InputType) -> String {
-    let blocking = blocking_byte::<71, 71>(input);
-    format!("{},{}", blocking.x, blocking.y)
+/// The size of the memory space, which has to have room for every byte that falls.
+fn memory_bounds(input: &InputType, params: &Params) -> Result<UPoint, ParamError> {
+    let length = |param, line, furthest: Option<u32>| match params.get(param)? {
+        0 => Err(ParamError::unsuitable(param, 0, "the memory space has no room")),
+        length => match furthest {
+            Some(furthest) if furthest >= length => Err(ParamError::unsuitable(
+                param,
+                length,
+                format!("a byte falls in {line} {furthest}"),
+            )),
+            _ => Ok(length),
+        },
+    };
+    Ok(UPoint::new(
+        length(&WIDTH, "column", input.iter().map(|byte| byte.x).max())?,
+        length(&HEIGHT, "row", input.iter().map(|byte| byte.y).max())?,
+    ))
 }
 
-pub fn blocking_byte<const WIDTH: u32, const HEIGHT: u32>(input: &InputType) -> UPoint {
-    let mut min = 0;
-    let mut max = input.len() - 1;
-    let mut pass = 0;
-
-    while min != max {
-        pass += 1;
-        let mid = (min + max) / 2;
-        if mid == min {
-            break;
-        }
-        if let Some(_) = is_solvable::<WIDTH, HEIGHT>(input, mid) {
-            min = mid;
+/// The first byte to cut the exit off, if any does.
+pub fn blocking_byte(input: &InputType, bounds: UPoint) -> Option<UPoint> {
+    // The exit can be reached with `open` bytes fallen, and can't with `blocked`. Past the end
+    // stands for every byte leaving it open.
+    let mut open = 0;
+    let mut blocked = input.len() + 1;
+    while blocked - open > 1 {
+        let mid = (open + blocked) / 2;
+        if shortest_path_len(input, bounds, mid).is_some() {
+            open = mid;
         } else {
-            max = mid;
-        }
-        if pass > 16 {
-            break;
+            blocked = mid;
         }
     }
-
-    input[min]
+    input.get(open).filter(|_| blocked <= input.len()).copied()
 }
 
-pub fn is_solvable<const WIDTH: u32, const HEIGHT: u32>(
-    input: &InputType,
-    bytes: usize,
-) -> Option<u32> {
-    let bounds = UPoint::new(WIDTH, HEIGHT);
-    let end = UPoint::new(WIDTH - 1, HEIGHT - 1);
-    let mut memory = Array2D::filled_with(Byte::Safe, HEIGHT as usize, WIDTH as usize);
-    for address in input.iter().take(bytes) {
-        memory[(address.y as usize, address.x as usize)] = Byte::Corrupt;
+/// The fewest steps to the exit once `bytes` bytes have fallen, if it can still be reached.
+pub fn shortest_path_len(input: &InputType, bounds: UPoint, bytes: usize) -> Option<u32> {
+    let end = UPoint::new(bounds.x - 1, bounds.y - 1);
+    let mut corrupt = Grid::filled(bounds.x as usize, bounds.y as usize, false);
+    for &address in input.iter().take(bytes) {
+        corrupt[address] = true;
     }
 
-    let mut pending = BinaryHeap::new();
-    memory[(0, 0)] = Byte::Path;
-    pending.push(MinScore(0, UPoint::new(0, 0)));
-
-    let score = {
-        loop {
-            if let Some(MinScore(score, position)) = pending.pop() {
-                if let Some(next) = position.north_checked() {
-                    if next == end {
-                        break Some(score + 1);
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.west_checked() {
-                    if next == end {
-                        break Some(score + 1);
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.south_checked(&bounds) {
-                    if next == end {
-                        break Some(score + 1);
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-                if let Some(next) = position.east_checked(&bounds) {
-                    if next == end {
-                        break Some(score + 1);
-                    } else if memory[(next.y as usize, next.x as usize)] == Byte::Safe {
-                        memory[(next.y as usize, next.x as usize)] = Byte::Path;
-                        pending.push(MinScore(score + 1, next));
-                    }
-                }
-            } else {
-                break None;
-            }
-        }
-    };
-
-    score
+    let corrupt = &corrupt;
+    bfs(
+        UPoint::origin(),
+        |&position| {
+            corrupt
+                .neighbours4(position)
+                .filter(move |&next| !corrupt[next])
+        },
+        |&position| position == end,
+    )
+    .map(|path| path.cost as u32)
 }
@@ -3,29 +3,19 @@ use dashmap::DashMap;
 use onig::Regex;
 use rayon::prelude::*;
 
-advent_day!(Day19, parse, (Vec<&'a str>, Vec<&'a str>), part1, part2);
+advent_day!(Day19, 19, parse, (Vec<&'a str>, Vec<&'a str>), part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    let towels = input.lines().next().unwrap().split(", ").collect();
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    let towels = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a list of towels"))?
+        .split(", ")
+        .collect();
     let designs = input.lines().skip(2).collect();
-    (towels, designs)
+    Ok((towels, designs))
 }
 
-/// ```rust
-/// use advent_of_code_2024::day19::*;
-/// let input = parse(
-/// r"r, wr, b, g, bwu, rb, gb, br
-///
-/// brwrr
-/// bggr
-/// gbbr
-/// rrbgbr
-/// ubwu
-/// bwurrg
-/// brgr
-/// bbrgwb");
-/// assert_eq!(6, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> usize {
     let (towels, designs) = input;
     let mut pattern = String::from("^(");
@@ -38,21 +28,6 @@ pub fn part1(input: &InputType) -> usize {
         .count()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day19::*;
-/// let input = parse(
-/// r"r, wr, b, g, bwu, rb, gb, br
-///
-/// brwrr
-/// bggr
-/// gbbr
-/// rrbgbr
-/// ubwu
-/// bwurrg
-/// brgr
-/// bbrgwb");
-/// assert_eq!(16, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> u64 {
     let (towels, designs) = input;
     let memo = &DashMap::new();
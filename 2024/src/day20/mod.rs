@@ -1,7 +1,19 @@
 use advent::*;
 use array2d::Array2D;
 
-advent_day!(Day20, parse, Array2D<Tile>, part1, part2);
+advent_day!(
+    Day20,
+    20,
+    parse,
+    Array2D<Tile>,
+    part1,
+    part2,
+    params = [SAVING],
+    part_with
+);
+
+/// How many picoseconds a cheat has to save at least to count.
+pub const SAVING: Param = Param::new("saving", "100");
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tile {
@@ -11,25 +23,29 @@ pub enum Tile {
     Empty,
 }
 
-pub fn parse(input: &str) -> InputType<'_> {
-    Array2D::from_rows(
-        &input
-            .lines()
-            .map(|line| {
-                line.as_bytes()
-                    .iter()
-                    .map(|tile| match tile {
-                        b'.' => Tile::Empty,
-                        b'#' => Tile::Wall,
-                        b'S' => Tile::Start,
-                        b'E' => Tile::End,
-                        _ => panic!("Illegal character"),
-                    })
-                    .collect()
-            })
-            .collect::<Vec<_>>(),
-    )
-    .unwrap()
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.bytes()
+                .enumerate()
+                .map(|(x, tile)| match tile {
+                    b'.' => Ok(Tile::Empty),
+                    b'#' => Ok(Tile::Wall),
+                    b'S' => Ok(Tile::Start),
+                    b'E' => Ok(Tile::End),
+                    _ => Err(ParseError::new(
+                        y + 1,
+                        x + 1,
+                        (tile as char).to_string(),
+                        "one of `.#SE`",
+                    )),
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Array2D::from_rows(&rows).map_err(|_| ParseError::at(input, input, "rows of the same length"))
 }
 
 /// ```rust
@@ -49,7 +65,7 @@ pub fn parse(input: &str) -> InputType<'_> {
 ///# #.#...#.#.#...#
 ///# #.#.#.#.#.#.###
 ///# #...#...#...###
-///# ###############");
+///# ###############").unwrap();
 /// // There are 14 cheats that save 2 picoseconds.
 /// assert_eq!(14, find_cheats(&input, |cheat_length| cheat_length == 2));
 /// // There are 14 cheats that save 4 picoseconds.
@@ -73,8 +89,8 @@ pub fn parse(input: &str) -> InputType<'_> {
 /// //There is one cheat that saves 64 picoseconds.
 /// assert_eq!(1, find_cheats(&input, |cheat_length| cheat_length == 64));
 /// ```
-pub fn part1(input: &InputType) -> usize {
-    find_cheats(input, |cheat_length| cheat_length >= 100)
+pub fn part1(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 1)
 }
 
 pub fn find_cheats<P>(input: &InputType, predicate: P) -> usize
@@ -184,7 +200,7 @@ where
 ///# #.#...#.#.#...#
 ///# #.#.#.#.#.#.###
 ///# #...#...#...###
-///# ###############");
+///# ###############").unwrap();
 /// //There are 32 cheats that save 50 picoseconds.
 /// assert_eq!(32, find_long_cheats(&input, |cheat_length| cheat_length == 50));
 /// //There are 31 cheats that save 52 picoseconds.
@@ -214,8 +230,16 @@ where
 /// //There are 3 cheats that save 76 picoseconds.
 /// assert_eq!(3, find_long_cheats(&input, |cheat_length| cheat_length == 76));
 /// ```
-pub fn part2(input: &InputType) -> usize {
-    find_long_cheats(input, |cheat_length| cheat_length >= 100)
+pub fn part2(input: &InputType) -> Answer {
+    params::with_defaults(part_with, input, 2)
+}
+
+pub fn part_with(input: &InputType, part: u8, params: &Params) -> Result<Answer, ParamError> {
+    let saving: u32 = params.get(&SAVING)?;
+    Ok(match part {
+        1 => find_cheats(input, |cheat_length| cheat_length >= saving as i32).into(),
+        _ => find_long_cheats(input, |cheat_length| cheat_length >= saving).into(),
+    })
 }
 
 pub fn find_long_cheats<P>(input: &InputType, predicate: P) -> usize
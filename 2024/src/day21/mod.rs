@@ -5,18 +5,22 @@ use std::{
 
 use advent::*;
 
-advent_day!(Day21, parse, Vec<[DoorCodeKey; 4]>, part1, part2);
+advent_day!(Day21, 21, parse, Vec<[DoorCodeKey; 4]>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .lines()
         .map(|line| {
+            if let Some(index) = line.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                let found = &line[index..index + line[index..].chars().next().unwrap().len_utf8()];
+                return Err(ParseError::at(input, found, "a digit or `A`"));
+            }
             line.as_bytes()
                 .iter()
                 .map(|&c| c.into())
                 .collect::<Vec<_>>()
                 .try_into()
-                .unwrap()
+                .map_err(|_| ParseError::at(input, line, "a four button door code"))
         })
         .collect()
 }
@@ -383,16 +387,6 @@ fn robot_path(from: &RobotKey, to: &RobotKey) -> &'static [RobotKey] {
     }
 }
 
-/// ```rust
-/// use advent_of_code_2024::day21::*;
-/// let input = parse(
-/// r"029A
-/// 980A
-/// 179A
-/// 456A
-/// 379A");
-/// assert_eq!(126384, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> u32 {
     input
         .iter()
@@ -427,16 +421,6 @@ pub fn part1(input: &InputType) -> u32 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day21::*;
-/// let input = parse(
-/// r"029A
-/// 980A
-/// 179A
-/// 456A
-/// 379A");
-/// assert_eq!(154115708116294, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> u64 {
     const ALL_KEYS: [RobotKey; 5] = [
         RobotKey::A,
@@ -3,10 +3,13 @@ use std::collections::HashMap;
 use advent::*;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-advent_day!(Day22, parse, Vec<u32>, part1, part2);
+advent_day!(Day22, 22, parse, Vec<u32>, part1, part2);
 
-pub fn parse(input: &str) -> InputType<'_> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse::token(input, line))
+        .collect()
 }
 
 /// ```rust
@@ -41,15 +44,6 @@ pub fn generate(mut secret: u32) -> u32 {
     secret % 16777216
 }
 
-/// ```rust
-/// use advent_of_code_2024::day22::*;
-/// let input = parse(
-/// r"1
-/// 10
-/// 100
-/// 2024");
-/// assert_eq!(37327623, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> u64 {
     input
         .par_iter()
@@ -63,15 +57,6 @@ pub fn part1(input: &InputType) -> u64 {
         .sum()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day22::*;
-/// let input = parse(
-/// r"1
-/// 2
-/// 3
-/// 2024");
-/// assert_eq!(23, part2(&input));
-/// ```
 pub fn part2(input: &InputType) -> i32 {
     const SEQUENCE_MAX: usize = 19 * 19 * 19 * 19;
     fn push_sequence(price_change: i32, sequence: i32) -> i32 {
@@ -1,6 +1,6 @@
 use advent::*;
 
-advent_day!(Day25, parse, Vec<Lockset>, part1, part2);
+advent_day!(Day25, 25, parse, Vec<Lockset>, part1, part2);
 
 #[derive(Debug)]
 pub enum Lockset {
@@ -8,93 +8,32 @@ pub enum Lockset {
     Lock([u8; 5]),
 }
 
-pub fn parse(input: &str) -> InputType<'_> {
+pub fn parse(input: &str) -> Result<InputType<'_>, ParseError> {
     input
         .split("\n\n")
         .map(|block| {
-            let decode = block.lines().fold([0u8; 5], |mut decode, line| {
+            let mut decode = [0u8; 5];
+            for line in block.lines() {
+                if line.len() < 5 {
+                    return Err(ParseError::at(input, line, "a row five pins wide"));
+                }
                 let chars = line.as_bytes();
                 for i in 0..5 {
                     if chars[i] == b'#' {
                         decode[i] += 1;
                     }
                 }
-                decode
-            });
+            }
 
             if block.starts_with(".....") {
-                Lockset::Key(decode)
+                Ok(Lockset::Key(decode))
             } else {
-                Lockset::Lock(decode)
+                Ok(Lockset::Lock(decode))
             }
         })
         .collect()
 }
 
-/// ```rust
-/// use advent_of_code_2024::day25::*;
-/// let input = parse(
-/// r"#####
-///# .####
-///# .####
-///# .####
-///# .#.#.
-///# .#...
-///# .....
-///#
-///# #####
-///# ##.##
-///# .#.##
-///# ...##
-///# ...#.
-///# ...#.
-///# .....
-///#
-///# .....
-///# #....
-///# #....
-///# #...#
-///# #.#.#
-///# #.###
-///# #####
-///#
-///# .....
-///# .....
-///# #.#..
-///# ###..
-///# ###.#
-///# ###.#
-///# #####
-///#
-///# .....
-///# .....
-///# .....
-///# #....
-///# #.#..
-///# #.#.#
-///# #####");
-/// assert_eq!(3, part1(&input));
-/// ```
-/// ```rust
-/// use advent_of_code_2024::day25::*;
-/// let input = parse(
-///r"#####
-///# #####
-///# #####
-///# #####
-///# #####
-///# #####
-///# .....
-///#
-///# .....
-///# .....
-///# .....
-///# .....
-///# .....
-///# ..#..
-///# #####");
-/// assert_eq!(0, part1(&input));
-/// ```
 pub fn part1(input: &InputType) -> u32 {
     let mut locks: Vec<&[u8; 5]> = vec![];
     let mut keys: Vec<&[u8; 5]> = vec![];
@@ -133,6 +72,7 @@ pub fn part1(input: &InputType) -> u32 {
 }
 
 /// ```rust
+/// use advent::Answer;
 /// use advent_of_code_2024::day25::*;
 /// let input = parse(
 /// r"#####
@@ -173,9 +113,9 @@ pub fn part1(input: &InputType) -> u32 {
 ///# #....
 ///# #.#..
 ///# #.#.#
-///# #####");
-/// assert_eq!(0, part2(&input));
+///# #####").unwrap();
+/// assert_eq!(Answer::Unsolved, part2(&input));
 /// ```
-pub fn part2(_input: &InputType) -> i32 {
-    0
+pub fn part2(_input: &InputType) -> Answer {
+    Answer::Unsolved
 }
@@ -4,8 +4,8 @@ use num::Integer;
 advent_day!(Day01, 1, Vec<i32>);
 
 impl DayParser<Day> for Day {
-    fn parse(input: &str) -> Self {
-        Self(
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(
             input
                 .lines()
                 .map(|line| {
@@ -13,33 +13,17 @@ impl DayParser<Day> for Day {
                     let direction = match tokens.0 {
                         "L" => -1,
                         "R" => 1,
-                        _ => panic!(),
+                        _ => return Err(ParseError::at(input, line, "`L` or `R`")),
                     };
-                    direction * tokens.1.parse::<i32>().unwrap()
+                    Ok(direction * parse::token::<i32>(input, tokens.1)?)
                 })
-                .collect(),
-        )
+                .collect::<Result<_, _>>()?,
+        ))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day01::*;
-    /// let day = Day::parse(
-    /// r"L68
-    /// L30
-    /// R48
-    /// L5
-    /// R60
-    /// L55
-    /// L1
-    /// L99
-    /// R14
-    /// L82");
-    /// assert_eq!("3", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.input()
             .iter()
             .copied()
@@ -49,40 +33,10 @@ impl AdventDay for Day {
             })
             .filter(|&state| state == 0)
             .count()
-            .to_string()
+            .into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day01::*;
-    /// let day = Day::parse(
-    /// r"L68
-    /// L30
-    /// R48
-    /// L5
-    /// R60
-    /// L55
-    /// L1
-    /// L99
-    /// R14
-    /// L82");
-    /// assert_eq!("6", day.part2());
-    /// ```
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day01::*;
-    /// let day = Day::parse(
-    /// r"L50");
-    /// assert_eq!("1", day.part2());
-    /// ```
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day01::*;
-    /// let day = Day::parse(
-    /// r"L150");
-    /// assert_eq!("2", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         self.input()
             .iter()
             .copied()
@@ -102,6 +56,6 @@ impl AdventDay for Day {
                 Some(q + zc)
             })
             .sum::<i32>()
-            .to_string()
+            .into()
     }
 }
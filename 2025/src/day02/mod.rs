@@ -5,32 +5,25 @@ use advent::*;
 advent_day!(Day02, 2, Vec<(u64, u64)>);
 
 impl DayParser<Day> for Day {
-    fn parse(input: &str) -> Self {
-        Self(
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(
             input
+                .trim_end()
                 .split(',')
                 .map(|range| {
-                    let (start, end) = range.split_once('-').unwrap();
-                    (start.parse::<u64>().unwrap(), end.parse::<u64>().unwrap())
+                    let (start, end) = parse::split_once(input, range, "-")?;
+                    Ok((
+                        parse::token::<u64>(input, start)?,
+                        parse::token::<u64>(input, end)?,
+                    ))
                 })
-                .collect(),
-        )
+                .collect::<Result<_, _>>()?,
+        ))
     }
 }
 
-const SPLITS: [u64; 10] = [
-    1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000,
-];
-
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day02::*;
-    /// let day = Day::parse(
-    /// r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124");
-    /// assert_eq!("1227775554", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let patterns: Vec<Vec<(u32, u64)>> = vec![
             vec![],                 // 0
             vec![],                 // 1
@@ -45,17 +38,10 @@ impl AdventDay for Day {
             vec![(5, 00001_00001)], // 10
         ];
 
-        find_patterns(self.input(), patterns).to_string()
+        find_patterns(self.input(), patterns).into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day02::*;
-    /// let day = Day::parse(
-    /// r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124");
-    /// assert_eq!("4174379265", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let patterns: Vec<Vec<(u32, u64)>> = vec![
             vec![],                                                       // 0
             vec![],                                                       // 1
@@ -70,7 +56,7 @@ impl AdventDay for Day {
             vec![(1, 1111111111), (2, 01_01_01_01_01), (5, 00001_00001)], // 10
         ];
 
-        find_patterns(self.input(), patterns).to_string()
+        find_patterns(self.input(), patterns).into()
     }
 }
 
@@ -82,7 +68,7 @@ fn find_patterns(input: &InputType, patterns: Vec<Vec<(u32, u64)>>) -> u64 {
             let mut ranges = vec![*start];
 
             for i in start.digits()..end.digits() {
-                ranges.push(SPLITS[i as usize]);
+                ranges.push(1u64.shl10(i));
             }
 
             ranges.push(*end + 1);
@@ -3,32 +3,29 @@ use advent::*;
 advent_day!(Day03, 3, Vec<Vec<u8>>);
 
 impl DayParser<Day> for Day {
-    fn parse(input: &str) -> Self {
-        Self(
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(
             input
                 .lines()
                 .map(|line| {
-                    line.chars()
-                        .map(|c| c.to_digit(10).unwrap() as u8)
+                    line.char_indices()
+                        .map(|(index, c)| match c.to_digit(10) {
+                            Some(joltage) => Ok(joltage as u8),
+                            None => Err(ParseError::at(
+                                input,
+                                &line[index..index + c.len_utf8()],
+                                "a digit",
+                            )),
+                        })
                         .collect()
                 })
-                .collect(),
-        )
+                .collect::<Result<_, _>>()?,
+        ))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day03::*;
-    /// let day = Day::parse(
-    /// r"987654321111111
-    /// 811111111111119
-    /// 234234234234278
-    /// 818181911112111");
-    /// assert_eq!("357", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.input()
             .iter()
             .map(|line| {
@@ -42,20 +39,10 @@ impl AdventDay for Day {
                 first_digit as u64 * 10 + second_digit as u64
             })
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day03::*;
-    /// let day = Day::parse(
-    /// r"987654321111111
-    /// 811111111111119
-    /// 234234234234278
-    /// 818181911112111");
-    /// assert_eq!("3121910778619", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         self.input()
             .iter()
             .map(|line| {
@@ -75,7 +62,7 @@ impl AdventDay for Day {
                 joltage
             })
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 }
 
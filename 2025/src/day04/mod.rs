@@ -4,8 +4,8 @@ use num::Integer;
 advent_day!(Day04, 4, Vec<Vec<u8>>);
 
 impl DayParser<Day> for Day {
-    fn parse(input: &'_ str) -> Day {
-        Self(
+    fn parse(input: &'_ str) -> Result<Day, ParseError> {
+        Ok(Self(
             input
                 .lines()
                 .map(|line| {
@@ -17,28 +17,12 @@ impl DayParser<Day> for Day {
                         .collect()
                 })
                 .collect(),
-        )
+        ))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day04::*;
-    /// let day = Day::parse(
-    /// r"..@@.@@@@.
-    /// @@@.@.@.@@
-    /// @@@@@.@.@@
-    /// @.@@@@..@.
-    /// @@.@@@@.@@
-    /// .@@@@@@@.@
-    /// .@.@.@.@@@
-    /// @.@@@.@@@@
-    /// .@@@@@@@@.
-    /// @.@.@@@.@.");
-    /// assert_eq!("13", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         fn add_vec<T: Copy + std::ops::AddAssign<T>>(left: &mut Vec<T>, right: &Vec<T>) {
             for i in 0..left.len() {
                 left[i] += right[i]
@@ -79,26 +63,10 @@ impl AdventDay for Day {
                     .count()
             })
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day04::*;
-    /// let day = Day::parse(
-    /// r"..@@.@@@@.
-    /// @@@.@.@.@@
-    /// @@@@@.@.@@
-    /// @.@@@@..@.
-    /// @@.@@@@.@@
-    /// .@@@@@@@.@
-    /// .@.@.@.@@@
-    /// @.@@@.@@@@
-    /// .@@@@@@@@.
-    /// @.@.@@@.@.");
-    /// assert_eq!("43", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         fn add_vec<T: Copy + std::ops::AddAssign<T>>(left: &mut Vec<T>, right: &Vec<T>) {
             for i in 0..left.len() {
                 left[i] += right[i]
@@ -182,6 +150,6 @@ impl AdventDay for Day {
             }
         }
 
-        removed_count.to_string()
+        removed_count.into()
     }
 }
@@ -1,100 +1,40 @@
-use std::collections::BTreeSet;
-use std::ops::Bound::Included;
-
-use advent::*;
-use itertools::Itertools;
+use advent::{interval::IntervalSet, *};
 
 advent_day!(Day05, 5, (Vec<(u64, u64)>, Vec<u64>));
 
 impl DayParser<Day> for Day {
-    fn parse(input: &'_ str) -> Day {
-        let (ranges, ids) = input.split_once("\n\n").unwrap();
+    fn parse(input: &'_ str) -> Result<Day, ParseError> {
+        let (ranges, ids) = parse::split_once(input, input, "\n\n")?;
         let ranges = ranges
             .lines()
             .map(|range| {
-                let (start, end) = range.split_once('-').unwrap();
-                (start.parse::<u64>().unwrap(), end.parse::<u64>().unwrap())
+                let (start, end) = parse::split_once(input, range, "-")?;
+                Ok((
+                    parse::token::<u64>(input, start)?,
+                    parse::token::<u64>(input, end)?,
+                ))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         let ids = ids
             .lines()
-            .map(|line| line.parse::<u64>().unwrap())
-            .collect();
-        Self((ranges, ids))
+            .map(|line| parse::token::<u64>(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self((ranges, ids)))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day05::*;
-    /// let day = Day::parse(
-    /// r"3-5
-    /// 10-14
-    /// 16-20
-    /// 12-18
-    ///
-    /// 1
-    /// 5
-    /// 8
-    /// 11
-    /// 17
-    /// 32");
-    /// assert_eq!("3", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let (ranges, ids) = self.input();
-        let ids: BTreeSet<u64> = BTreeSet::from_iter(ids.iter().copied());
-        reduce_ranges(ranges)
-            .iter()
-            .map(|range| ids.range((Included(range.0), Included(range.1))).count())
-            .sum::<usize>()
-            .to_string()
+        let fresh = fresh_ingredients(ranges);
+        ids.iter().filter(|&&id| fresh.contains(id)).count().into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day05::*;
-    /// let day = Day::parse(
-    /// r"3-5
-    /// 10-14
-    /// 16-20
-    /// 12-18
-    ///
-    /// 1
-    /// 5
-    /// 8
-    /// 11
-    /// 17
-    /// 32");
-    /// assert_eq!("14", day.part2());
-    /// ```
-    fn part2(&self) -> String {
-        reduce_ranges(&self.input().0)
-            .iter()
-            .map(|range| range.1 - range.0 + 1)
-            .sum::<u64>()
-            .to_string()
+    fn part2(&self) -> Answer {
+        fresh_ingredients(&self.input().0).coverage().into()
     }
 }
 
-fn reduce_ranges(ranges: &Vec<(u64, u64)>) -> Vec<(u64, u64)> {
-    let mut ranges = ranges.iter().sorted_by_key(|item| item.0);
-    let mut current_range = *ranges.next().unwrap();
-    let mut reduced = vec![];
-    while let Some(&(start, end)) = ranges.next() {
-        if current_range.0 <= start && end <= current_range.1 {
-            // Next range is contained by current
-        } else if current_range.0 <= start && start <= current_range.1 {
-            // Next range appends current
-            current_range.1 = end;
-        } else {
-            // Next range is new range
-            reduced.push(current_range);
-            current_range = (start, end);
-        }
-    }
-    reduced.push(current_range);
-
-    reduced
+fn fresh_ingredients(ranges: &[(u64, u64)]) -> IntervalSet<u64> {
+    ranges.iter().map(|&(start, end)| start..=end).collect()
 }
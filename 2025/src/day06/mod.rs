@@ -10,57 +10,56 @@ enum Operator {
 }
 
 impl DayParser<Day> for Day {
-    fn parse(input: &'_ str) -> Day {
+    fn parse(input: &'_ str) -> Result<Day, ParseError> {
         let mut lines = input.lines();
         let mut operand_rows = vec![];
 
-        let mut line = lines.next().unwrap();
+        let mut line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "rows of operands"))?;
         while let Some(next) = lines.next() {
             operand_rows.push(line.to_string());
             line = next;
         }
+        let operator_line = line;
 
         let operator_pattern = Regex::new(r"(([+*])\s+)(?:\s|$)").unwrap();
-        Self(
+        Ok(Self(
             operator_pattern
-                .captures_iter(line)
+                .captures_iter(operator_line)
                 .map(|op_cap| {
                     let operator = match op_cap.at(2).unwrap() {
                         "+" => Operator::Addition,
                         "*" => Operator::Multiplication,
-                        _ => panic!(),
+                        _ => unreachable!(),
                     };
                     let text_width = op_cap.at(0).unwrap().len();
                     let column_width = op_cap.at(1).unwrap().len();
                     let operands = operand_rows
                         .iter_mut()
                         .map(|row| {
-                            let remainder = row.split_off(text_width);
-                            let operand = row.get(0..column_width).unwrap().to_string();
-                            *row = remainder;
-                            operand
+                            let operand = row.get(0..column_width).ok_or_else(|| {
+                                ParseError::at(
+                                    input,
+                                    operator_line,
+                                    "rows as wide as the operators",
+                                )
+                            })?;
+                            let operand = operand.to_string();
+                            *row = row.split_off(text_width.min(row.len()));
+                            Ok(operand)
                         })
-                        .collect::<Vec<_>>();
+                        .collect::<Result<Vec<_>, _>>()?;
 
-                    (operands, operator)
+                    Ok((operands, operator))
                 })
-                .collect::<Vec<_>>(),
-        )
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day06::*;
-    /// let day = Day::parse(
-    /// "123 328  51 64 \
-    /// \n 45 64  387 23 \
-    /// \n  6 98  215 314\
-    /// \n*   +   *   +  ");
-    /// assert_eq!("4277556", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.input()
             .iter()
             .map(|(operand_rows, operator)| {
@@ -79,20 +78,10 @@ impl AdventDay for Day {
                 }
             })
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day06::*;
-    /// let day = Day::parse(
-    /// "123 328  51 64 \
-    /// \n 45 64  387 23 \
-    /// \n  6 98  215 314\
-    /// \n*   +   *   +  ");
-    /// assert_eq!("3263827", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         self.input()
             .iter()
             .map(|(operand_rows, operator)| {
@@ -112,6 +101,6 @@ impl AdventDay for Day {
                 }
             })
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 }
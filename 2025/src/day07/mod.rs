@@ -1,7 +1,11 @@
-use advent::*;
+use advent::{
+    visualize::{self, Frame, Render, Rgb},
+    *,
+};
 use num::Integer;
 
 advent_day!(Day07, 7, Vec<Vec<Cell>>);
+advent_visualize!(Day::parse, tachyon_beams);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
@@ -11,8 +15,8 @@ enum Cell {
 }
 
 impl DayParser<Day> for Day {
-    fn parse(input: &'_ str) -> Day {
-        Self(
+    fn parse(input: &'_ str) -> Result<Day, ParseError> {
+        Ok(Self(
             input
                 .lines()
                 .map(|line| {
@@ -25,34 +29,12 @@ impl DayParser<Day> for Day {
                         .collect()
                 })
                 .collect(),
-        )
+        ))
     }
 }
 
 impl AdventDay for Day {
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day07::*;
-    /// let day = Day::parse(
-    /// r#".......S.......
-    /// ...............
-    /// .......^.......
-    /// ...............
-    /// ......^.^......
-    /// ...............
-    /// .....^.^.^.....
-    /// ...............
-    /// ....^.^...^....
-    /// ...............
-    /// ...^.^...^.^...
-    /// ...............
-    /// ..^...^.....^..
-    /// ...............
-    /// .^.^.^.^.^...^.
-    /// ..............."#);
-    /// assert_eq!("21", day.part1());
-    /// ```
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut state = self.input().first().unwrap().clone();
         let mut split_count = 0;
         for row in self.input().iter().skip(2).step_by(2) {
@@ -65,32 +47,10 @@ impl AdventDay for Day {
                 }
             }
         }
-        split_count.to_string()
+        split_count.into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day07::*;
-    /// let day = Day::parse(
-    /// r#".......S.......
-    /// ...............
-    /// .......^.......
-    /// ...............
-    /// ......^.^......
-    /// ...............
-    /// .....^.^.^.....
-    /// ...............
-    /// ....^.^...^....
-    /// ...............
-    /// ...^.^...^.^...
-    /// ...............
-    /// ..^...^.....^..
-    /// ...............
-    /// .^.^.^.^.^...^.
-    /// ..............."#);
-    /// assert_eq!("40", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut state = self
             .input()
             .first()
@@ -112,6 +72,89 @@ impl AdventDay for Day {
                 }
             }
         }
-        state.iter().sum::<u64>().to_string()
+        state.iter().sum::<u64>().into()
     }
 }
+
+/// The manifold with its beams worked out down to some row.
+struct Manifold {
+    rows: Vec<Vec<Cell>>,
+    /// How many timelines have a beam in each cell, for the rows reached so far.
+    timelines: Vec<Vec<u64>>,
+    splits: usize,
+}
+
+impl Manifold {
+    /// Takes the beams down a row, `false` once they've left the bottom.
+    fn step(&mut self) -> bool {
+        let Some(row) = self.rows.get(self.timelines.len()) else {
+            return false;
+        };
+        let above = self.timelines.last().unwrap();
+        let mut state = above.clone();
+        for i in 0..state.len() {
+            if row[i] == Cell::Splitter && above[i] > 0 {
+                self.splits.inc();
+                state[i] -= above[i];
+                state[i - 1] += above[i];
+                state[i + 1] += above[i];
+            }
+        }
+        self.timelines.push(state);
+        true
+    }
+}
+
+impl Render for Manifold {
+    fn render(&self) -> Frame {
+        let mut frame = Frame::new(self.rows[0].len(), self.rows.len());
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                let lit = |y: usize| self.timelines.get(y).is_some_and(|state| state[x] > 0);
+                let cell = match cell {
+                    Cell::Beam => visualize::Cell::coloured('S', Rgb::YELLOW),
+                    Cell::Splitter if y > 0 && lit(y - 1) => {
+                        visualize::Cell::coloured('^', Rgb::RED)
+                    }
+                    Cell::Splitter => visualize::Cell::new('^'),
+                    Cell::Empty if lit(y) => visualize::Cell::coloured('|', Rgb::YELLOW),
+                    Cell::Empty => visualize::Cell::new('.'),
+                };
+                frame.set(UPoint::new(x as u32, y as u32), cell);
+            }
+        }
+        let timelines = self.timelines.last().unwrap().iter().sum::<u64>();
+        frame.with_caption(format!("{} splits, {timelines} timelines", self.splits))
+    }
+}
+
+/// The beam falling through the manifold, a frame for every row it gets down.
+///
+/// ```rust
+/// use advent::*;
+/// use advent_of_code_2025::day07::*;
+/// let day = Day::parse("..S..\n.....\n..^..\n.....").unwrap();
+/// let frames = tachyon_beams(day).collect::<Vec<_>>();
+/// assert_eq!("..S..\n.....\n..^..\n.....\n0 splits, 1 timelines\n", frames[0].to_string());
+/// assert_eq!("..S..\n..|..\n.|^|.\n.|.|.\n1 splits, 2 timelines\n", frames[3].to_string());
+/// assert_eq!(4, frames.len());
+/// ```
+pub fn tachyon_beams(day: Day) -> impl Iterator<Item = Frame> {
+    let start = day.input()[0]
+        .iter()
+        .map(|&cell| u64::from(cell == Cell::Beam))
+        .collect();
+    let mut manifold = Some(Manifold {
+        rows: day.0,
+        timelines: vec![start],
+        splits: 0,
+    });
+    std::iter::from_fn(move || {
+        let current = manifold.as_mut()?;
+        let frame = current.render();
+        if !current.step() {
+            manifold = None;
+        }
+        Some(frame)
+    })
+}
@@ -1,9 +1,6 @@
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
-};
+use std::{cmp::Reverse, collections::BinaryHeap};
 
-use advent::*;
+use advent::{graph::UnionFind, *};
 
 use fixed::traits::Fixed;
 use kiddo::fixed::{distance::SquaredEuclidean, kdtree::KdTree};
@@ -24,21 +21,16 @@ impl Ord for Connection {
     }
 }
 
-advent_day!(Day08, 8, Vec<[u32; 3]>);
+advent_day!(Day08, 8, Vec<Point3D<u32>>);
 
 impl DayParser<Day> for Day {
-    fn parse(input: &str) -> Self {
-        Self(
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(
             input
                 .lines()
-                .map(|line| {
-                    line.split(',')
-                        .map(|v| v.parse::<u32>().unwrap())
-                        .collect_array()
-                        .unwrap()
-                })
-                .collect::<Vec<_>>(),
-        )
+                .map(|line| parse::token(input, line))
+                .collect::<Result<Vec<_>, ParseError>>()?,
+        ))
     }
 }
 
@@ -66,10 +58,10 @@ impl Day {
     /// 941,993,340
     /// 862,61,35
     /// 984,92,344
-    /// 425,690,689");
-    /// assert_eq!("40", day.part1_impl::<10>());
+    /// 425,690,689").unwrap();
+    /// assert_eq!(40, day.part1_impl::<10>());
     /// ```
-    pub fn part1_impl<const NEAREST: usize>(&self) -> String {
+    pub fn part1_impl<const NEAREST: usize>(&self) -> u64 {
         let points = self.input();
         let mut point_tree: Tree = KdTree::with_capacity(1000);
         let mut distance_rank: BinaryHeap<Connection> = BinaryHeap::with_capacity(NEAREST + 1);
@@ -77,8 +69,8 @@ impl Day {
         let mut worst_distance = Axis::MAX;
 
         // Fill the kdtree and rank nearest neighbours
-        for (index, [x, y, z]) in points.iter().enumerate() {
-            let point = &[Axis::from_num(*x), Axis::from_num(*y), Axis::from_num(*z)];
+        for (index, &point) in points.iter().enumerate() {
+            let point = &<[u32; 3]>::from(point).map(Axis::from_num);
 
             for nearest in
                 point_tree.best_n_within::<SquaredEuclidean>(point, worst_distance, NEAREST)
@@ -95,35 +87,16 @@ impl Day {
             point_tree.add(point, index);
         }
 
-        let mut node_map = (0..points.len()).collect::<Vec<_>>();
-        let mut network_map: Vec<HashSet<usize>> = points
-            .iter()
-            .enumerate()
-            .map(|(index, _)| HashSet::from([index]))
-            .collect::<Vec<_>>();
-        let network_map = &mut network_map;
-
+        let mut circuits = UnionFind::new(points.len());
         for _ in 0..NEAREST {
             let connection = distance_rank.pop().unwrap();
-
-            let point_a = connection.a;
-            let point_b = connection.b;
-            let net_a_id = node_map[point_a];
-            let net_b_id = node_map[point_b];
-            if net_a_id == net_b_id {
-                // Already connected
-                continue;
-            }
-
-            let [net_a, net_b] = network_map.get_disjoint_mut([net_a_id, net_b_id]).unwrap();
-            net_a.extend(net_b.iter());
-            net_b.iter().for_each(|node| node_map[*node] = net_a_id);
-            net_b.clear();
+            circuits.union(connection.a, connection.b);
         }
 
-        let mut network_sizes = network_map
+        let mut network_sizes = circuits
+            .groups()
             .iter()
-            .map(|net| net.len() as u64)
+            .map(|circuit| circuit.len() as u64)
             .collect::<BinaryHeap<_>>();
 
         let mut answer = 1;
@@ -131,49 +104,23 @@ impl Day {
             let size = network_sizes.pop().unwrap();
             answer *= size;
         }
-        answer.to_string()
+        answer
     }
 }
 
 impl AdventDay for Day {
-    fn part1(&self) -> String {
-        self.part1_impl::<1000>()
+    fn part1(&self) -> Answer {
+        self.part1_impl::<1000>().into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day08::*;
-    /// let day = Day::parse(
-    /// r"162,817,812
-    /// 57,618,57
-    /// 906,360,560
-    /// 592,479,940
-    /// 352,342,300
-    /// 466,668,158
-    /// 542,29,236
-    /// 431,825,988
-    /// 739,650,466
-    /// 52,470,668
-    /// 216,146,977
-    /// 819,987,18
-    /// 117,168,530
-    /// 805,96,715
-    /// 346,949,466
-    /// 970,615,88
-    /// 941,993,340
-    /// 862,61,35
-    /// 984,92,344
-    /// 425,690,689");
-    /// assert_eq!("25272", day.part2());
-    /// ```
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let points = self.input();
         let mut point_tree: Tree = KdTree::with_capacity(1000);
         let mut distance_rank: BinaryHeap<Reverse<Connection>> = BinaryHeap::new();
 
         // Fill the kdtree and rank nearest neighbours
-        for (index, [x, y, z]) in points.iter().enumerate() {
-            let point = &[Axis::from_num(*x), Axis::from_num(*y), Axis::from_num(*z)];
+        for (index, &point) in points.iter().enumerate() {
+            let point = &<[u32; 3]>::from(point).map(Axis::from_num);
 
             for nearest in point_tree.nearest_n::<SquaredEuclidean>(point, 6) {
                 distance_rank.push(Reverse(Connection {
@@ -185,32 +132,10 @@ impl AdventDay for Day {
             point_tree.add(point, index);
         }
 
-        let mut node_map = (0..points.len()).collect::<Vec<_>>();
-        let mut network_map: Vec<HashSet<usize>> = points
-            .iter()
-            .enumerate()
-            .map(|(index, _)| HashSet::from([index]))
-            .collect::<Vec<_>>();
-        let network_map = &mut network_map;
-        while network_map.len() > 1 {
-            let Reverse(connection) = distance_rank.pop().unwrap();
-
-            let point_a = connection.a;
-            let point_b = connection.b;
-            let net_a_id = node_map[point_a];
-            let net_b_id = node_map[point_b];
-
-            if net_a_id == net_b_id {
-                continue;
-            }
-
-            let [net_a, net_b] = network_map.get_disjoint_mut([net_a_id, net_b_id]).unwrap();
-            net_a.extend(net_b.iter());
-            net_b.iter().for_each(|node| node_map[*node] = net_a_id);
-            net_b.clear();
-
-            if network_map[net_a_id].len() == points.len() {
-                return (points[point_a][0] as u64 * points[point_b][0] as u64).to_string();
+        let mut circuits = UnionFind::new(points.len());
+        while let Some(Reverse(connection)) = distance_rank.pop() {
+            if circuits.union(connection.a, connection.b) && circuits.sets() == 1 {
+                return (points[connection.a].x as u64 * points[connection.b].x as u64).into();
             }
         }
 
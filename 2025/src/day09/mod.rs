@@ -35,16 +35,19 @@ impl<A: kiddo::fixed::kdtree::Axis, const K: usize> DistanceMetric<A, K> for Are
 }
 
 impl DayParser<Day> for Day {
-    fn parse(input: &str) -> Self {
-        Self(
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(
             input
                 .lines()
                 .map(|line| {
-                    let (x, y) = line.split_once(',').unwrap();
-                    IPoint::new(x.parse().unwrap(), y.parse().unwrap())
+                    let (x, y) = parse::split_once(input, line, ",")?;
+                    Ok(IPoint::new(
+                        parse::token(input, x)?,
+                        parse::token(input, y)?,
+                    ))
                 })
-                .collect(),
-        )
+                .collect::<Result<_, _>>()?,
+        ))
     }
 }
 
@@ -60,7 +63,7 @@ impl Day {
     /// 9,5
     /// 2,5
     /// 2,3
-    /// 7,3");
+    /// 7,3").unwrap();
     /// assert_eq!(50, day.part1_impl(IPoint::new(6,4)));
     /// ```
     pub fn part1_impl(&self, center: IPoint) -> u64 {
@@ -88,25 +91,11 @@ impl Day {
 }
 
 impl AdventDay for Day {
-    fn part1(&self) -> String {
-        self.part1_impl(IPoint::new(50000, 50000)).to_string()
+    fn part1(&self) -> Answer {
+        self.part1_impl(IPoint::new(50000, 50000)).into()
     }
 
-    /// ```rust
-    /// use advent::*;
-    /// use advent_of_code_2025::day09::*;
-    /// let day = Day::parse(
-    /// r"7,1
-    /// 11,1
-    /// 11,7
-    /// 9,7
-    /// 9,5
-    /// 2,5
-    /// 2,3
-    /// 7,3");
-    /// assert_eq!("24", day.part2());
-    /// ```
-    fn part2(&self) -> String {
-        "".to_string()
+    fn part2(&self) -> Answer {
+        Answer::Unsolved
     }
 }
@@ -15,11 +15,10 @@
 // #![feature(new_uninit)]
 #![feature(portable_simd)]
 #![feature(stmt_expr_attributes)]
-#![feature(test)]
 
-use advent::AdventDay;
-#[cfg(feature = "day01")]
-use advent::DayId;
+pub const YEAR: u16 = 2025;
+
+use advent::{DayError, LoadedDay, Solver};
 
 #[cfg(feature = "day01")]
 pub mod day01;
@@ -46,32 +45,14 @@ pub mod day11;
 #[cfg(feature = "day12")]
 pub mod day12;
 
-pub fn new_day(day_id: u8) -> Option<Box<dyn AdventDay>> {
-    match day_id {
-        #[cfg(feature = "day01")]
-        day01::Day::DAY_ID => Some(Box::new(day01::Day::default())),
-        #[cfg(feature = "day02")]
-        day02::Day::DAY_ID => Some(Box::new(day02::Day::default())),
-        #[cfg(feature = "day03")]
-        day03::Day::DAY_ID => Some(Box::new(day03::Day::default())),
-        #[cfg(feature = "day04")]
-        day04::Day::DAY_ID => Some(Box::new(day04::Day::default())),
-        #[cfg(feature = "day05")]
-        day05::Day::DAY_ID => Some(Box::new(day05::Day::default())),
-        #[cfg(feature = "day06")]
-        day06::Day::DAY_ID => Some(Box::new(day06::Day::default())),
-        #[cfg(feature = "day07")]
-        day07::Day::DAY_ID => Some(Box::new(day07::Day::default())),
-        #[cfg(feature = "day08")]
-        day08::Day::DAY_ID => Some(Box::new(day08::Day::default())),
-        #[cfg(feature = "day09")]
-        day09::Day::DAY_ID => Some(Box::new(day09::Day::default())),
-        #[cfg(feature = "day10")]
-        day10::Day::DAY_ID => Some(Box::new(day10::Day::default())),
-        #[cfg(feature = "day11")]
-        day11::Day::DAY_ID => Some(Box::new(day11::Day::default())),
-        #[cfg(feature = "day12")]
-        day12::Day::DAY_ID => Some(Box::new(day12::Day::default())),
-        _ => None,
-    }
+pub fn new_day(day_id: u8) -> Option<LoadedDay> {
+    try_new_day(day_id).map(|day| day.unwrap_or_else(|error| panic!("Day {day_id}: {error}")))
+}
+
+pub fn try_new_day(day_id: u8) -> Option<Result<LoadedDay, DayError>> {
+    solver(day_id).map(|solver| solver.load())
+}
+
+pub fn solver(day_id: u8) -> Option<Solver> {
+    advent::registry::solver(YEAR, day_id)
 }
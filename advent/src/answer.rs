@@ -0,0 +1,143 @@
+use std::fmt;
+
+/// What a part of a day came up with.
+///
+/// Integers are stored as [`Answer::Integer`] whenever they fit in an `i64`, so the same number
+/// compares equal however wide the type that produced it was.
+///
+/// ```rust
+/// use advent::Answer;
+/// assert_eq!(Answer::Integer(42), Answer::from(42u8));
+/// assert_eq!(Answer::Integer(42), Answer::from(42u64));
+/// assert_eq!(Answer::BigInteger(u64::MAX as i128), Answer::from(u64::MAX));
+/// assert_eq!(Answer::Text("co,de,ka,ta".into()), Answer::from("co,de,ka,ta"));
+/// assert_eq!(Answer::Art("#..\n.#.".into()), Answer::from("#..\n.#.".to_string()));
+/// assert_eq!("42", Answer::from(42).to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    /// Integers outside the `i64` range.
+    BigInteger(i128),
+    Text(String),
+    /// Multi-line output, like letters drawn in `#` and `.`, kept verbatim.
+    Art(String),
+    /// The part hasn't been solved yet, as opposed to having a legitimately empty or zero answer.
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Self::Unsolved)
+    }
+
+    /// Whether this is the answer written down as `expected`. Drawings tend to come with a
+    /// trailing newline that written answers trim, so it doesn't count.
+    ///
+    /// ```rust
+    /// use advent::Answer;
+    /// assert!(Answer::from(54630).matches("54630"));
+    /// assert!(Answer::from("#.\n.#\n").matches("#.\n.#"));
+    /// assert!(!Answer::Unsolved.matches(""));
+    /// ```
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Art(art) => art.trim_end_matches('\n') == expected.trim_end_matches('\n'),
+            _ => self == expected,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => value.fmt(f),
+            Self::BigInteger(value) => value.fmt(f),
+            Self::Text(text) | Self::Art(text) => f.pad(text),
+            Self::Unsolved => f.pad("unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_small_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Self::Integer(value.into())
+            }
+        }
+    )*};
+}
+
+answer_from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! answer_from_wide_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                match i64::try_from(value) {
+                    Ok(value) => Self::Integer(value),
+                    Err(_) => Self::BigInteger(value as i128),
+                }
+            }
+        }
+    )*};
+}
+
+answer_from_wide_int!(u64, usize, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => value.into(),
+            // Past `i128::MAX` there's nothing left to do arithmetic on, so keep the digits.
+            Err(_) => Self::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if text.contains('\n') {
+            Self::Art(text)
+        } else {
+            Self::Text(text)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        text.to_owned().into()
+    }
+}
+
+/// Compares against the formatted answer, which is how answers are written down.
+///
+/// ```rust
+/// use advent::Answer;
+/// assert_eq!("3121910778619", Answer::from(3121910778619u64));
+/// assert_ne!("0", Answer::Unsolved);
+/// ```
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Text(text) | Self::Art(text) => text == other,
+            Self::Integer(value) => format!("{value}") == other,
+            Self::BigInteger(value) => format!("{value}") == other,
+            Self::Unsolved => false,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == *self
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    hint::black_box,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    input::{InputError, Parsed},
+    ParseError,
+};
+
+/// A part with its input already parsed, ready to run.
+pub type PreparedPart = Box<dyn Fn()>;
+
+/// An alternative implementation of a part, benchmarked next to the day's own. `advent_bench!`
+/// builds these.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub year: u16,
+    pub day: u8,
+    /// `<module>/<function>`, e.g. `cursed_regex/part2_regex`.
+    pub name: &'static str,
+    pub load_input: fn() -> Result<String, InputError>,
+    /// Parses the input with the variant's own parser and hands back the part, ready to run.
+    pub prepare: fn(&'static str) -> Result<PreparedPart, ParseError>,
+}
+
+impl Variant {
+    /// Prepares the part from `input`, which it shares for as long as it lives.
+    pub fn prepare_owned(&self, input: &Arc<str>) -> Result<Parsed<PreparedPart>, ParseError> {
+        Parsed::new(input, self.prepare)
+    }
+}
+
+/// Per-iteration times over every sample of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub median: Duration,
+    pub min: Duration,
+    /// How many times the function ran, warm-up excluded.
+    pub iterations: u64,
+}
+
+/// A sample has to take at least this long before its time means much.
+const MIN_SAMPLE: Duration = Duration::from_millis(1);
+const SAMPLES: usize = 50;
+const BUDGET: Duration = Duration::from_secs(3);
+
+fn time_batch<T>(f: &mut impl FnMut() -> T, batch: u64) -> Duration {
+    let start = Instant::now();
+    for _ in 0..batch {
+        black_box(f());
+    }
+    start.elapsed()
+}
+
+/// Runs `f` over and over on stable Rust. Fast functions run in batches long enough to time,
+/// slow ones get fewer samples so a benchmark stays within a few seconds.
+///
+/// ```rust
+/// use advent::bench::measure;
+/// let measurement = measure(|| (1..=100u64).sum::<u64>());
+/// assert!(measurement.min <= measurement.median);
+/// assert!(measurement.iterations > 0);
+/// ```
+pub fn measure<T>(mut f: impl FnMut() -> T) -> Measurement {
+    // Warm up while working out how many runs make a sample worth timing.
+    let mut batch = 1;
+    let mut elapsed = time_batch(&mut f, batch);
+    while elapsed < MIN_SAMPLE {
+        batch *= 2;
+        elapsed = time_batch(&mut f, batch);
+    }
+
+    let started = Instant::now();
+    let mut samples = Vec::with_capacity(SAMPLES);
+    while samples.len() < SAMPLES && (samples.len() < 5 || started.elapsed() < BUDGET) {
+        samples.push(time_batch(&mut f, batch) / batch as u32);
+    }
+    samples.sort();
+    Measurement {
+        median: samples[samples.len() / 2],
+        min: samples[0],
+        iterations: samples.len() as u64 * batch,
+    }
+}
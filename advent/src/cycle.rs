@@ -0,0 +1,162 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+use crate::trace;
+
+/// States from step `start` on repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step that reaches the same state as `step`.
+    ///
+    /// ```rust
+    /// use advent::cycle::Cycle;
+    /// let cycle = Cycle { start: 3, length: 7 };
+    /// assert_eq!(2, cycle.earliest(2));
+    /// assert_eq!(4, cycle.earliest(4));
+    /// assert_eq!(6, cycle.earliest(1_000_000_000));
+    /// ```
+    pub fn earliest(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// The state a simulation reaches after a number of steps, worked out without running them all
+/// when it falls into a cycle on the way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extrapolated<S> {
+    pub state: S,
+    /// `None` when the steps ran out before anything repeated.
+    pub cycle: Option<Cycle>,
+}
+
+/// Remembers every state, so each step only ever runs once. Needs the states to hash, and the
+/// memory to keep them all.
+///
+/// ```rust
+/// use advent::cycle::{hashed, Cycle};
+/// // 0 → 1 → 2 → 3 → 4 → 2 → ...
+/// let step = |&n: &u32| if n == 4 { 2 } else { n + 1 };
+/// let extrapolated = hashed(0, 1_000_000_000_000, step);
+/// assert_eq!(Some(Cycle { start: 2, length: 3 }), extrapolated.cycle);
+/// assert_eq!(4, extrapolated.state);
+/// assert_eq!(None, hashed(0, 2, step).cycle);
+/// assert_eq!(2, hashed(0, 2, step).state);
+/// ```
+pub fn hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> Extrapolated<S> {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut history = vec![initial];
+    for index in 1..=steps {
+        let state = step(history.last().expect("starts with the initial state"));
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let cycle = Cycle {
+                    start: *entry.get(),
+                    length: index - entry.get(),
+                };
+                trace::count("steps simulated", index as u64);
+                return Extrapolated {
+                    state: history.swap_remove(cycle.earliest(steps)),
+                    cycle: Some(cycle),
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+        history.push(state);
+    }
+    trace::count("steps simulated", steps as u64);
+    Extrapolated {
+        state: history.pop().expect("starts with the initial state"),
+        cycle: None,
+    }
+}
+
+/// Brent's algorithm: keeps only a couple of states around, for states that are big or don't
+/// hash, at the cost of running some steps more than once.
+///
+/// ```rust
+/// use advent::cycle::{brent, Cycle};
+/// let step = |&n: &u64| n * n % 1_000;
+/// let extrapolated = brent(7, 1_000_000, step);
+/// let cycle = extrapolated.cycle.unwrap();
+/// assert_eq!(Cycle { start: 2, length: 4 }, cycle);
+/// assert_eq!(601, extrapolated.state);
+/// assert_eq!(advent::cycle::hashed(7, 1_000_000, step), extrapolated);
+/// ```
+pub fn brent<S: Clone + PartialEq>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> Extrapolated<S> {
+    // Some steps run more than once, and all of them count.
+    let mut simulated = 0;
+    let mut step = |state: &S| {
+        simulated += 1;
+        step(state)
+    };
+
+    // Find the cycle's length, with the hare running ahead and the tortoise teleporting to it
+    // whenever it's been a power of two steps behind.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for taken in 1.. {
+        if taken > steps {
+            trace::count("steps simulated", simulated);
+            return Extrapolated {
+                state: hare,
+                cycle: None,
+            };
+        }
+        hare = step(&hare);
+        if tortoise == hare {
+            break;
+        }
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        length += 1;
+    }
+
+    // Then where it starts, walking two states `length` steps apart until they meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let cycle = Cycle { start, length };
+    for _ in start..cycle.earliest(steps) {
+        tortoise = step(&tortoise);
+    }
+    trace::count("steps simulated", simulated);
+    Extrapolated {
+        state: tortoise,
+        cycle: Some(cycle),
+    }
+}
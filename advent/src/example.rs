@@ -0,0 +1,230 @@
+use std::{
+    env,
+    fmt::Write,
+    fs::{self, read_dir},
+    io,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use crate::{params::Params, Solver};
+
+/// A puzzle's worked example, one of those listed in `<year>/examples/dayNN.toml`:
+///
+/// ```toml
+/// [[example]]
+/// input = '''
+/// L68
+/// L30
+/// R48
+/// '''
+/// part1 = 1
+/// part2 = 2
+///
+/// [[example]]
+/// name = "a full turn past zero"
+/// input = "L150"
+/// part2 = 2
+/// ```
+///
+/// Inputs are best written as literal strings, which keep backslashes as they are. Parts left out
+/// aren't checked, and `name` defaults to the example's place in the file. Like `answers.toml`,
+/// answers are integers or strings, and `params` is a table of values for the day's
+/// [`Param`](crate::params::Param)s, to solve with instead of the ones real inputs use.
+///
+/// ```rust
+/// use advent::example::Example;
+/// let examples = Example::parse_all("[[example]]\ninput = \"\"\"\n1\n2\n\"\"\"\npart2 = \"#.\\n.#\\n\"").unwrap();
+/// assert_eq!("example 1", examples[0].name);
+/// assert_eq!("1\n2\n", examples[0].input);
+/// assert_eq!(None, examples[0].expected(1));
+/// assert_eq!(Some("#.\n.#"), examples[0].expected(2));
+///
+/// assert!(Example::parse_all("[[example]]\npart1 = 3").is_err());
+/// assert!(Example::parse_all("[[example]]\ninput = \"\"\npart3 = 3").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    answers: [Option<String>; 2],
+}
+
+impl Example {
+    pub fn parse_all(text: &str) -> Result<Vec<Self>, String> {
+        let mut table = text
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let examples = match table.remove("example") {
+            Some(toml::Value::Array(examples)) => examples,
+            Some(_) => return Err("`example` should be an array of tables".into()),
+            None => vec![],
+        };
+        if let Some(key) = table.keys().next() {
+            return Err(format!("`{key}` is not `example`"));
+        }
+        examples
+            .into_iter()
+            .enumerate()
+            .map(|(index, example)| match example {
+                toml::Value::Table(example) => Self::from_table(index + 1, example),
+                _ => Err(format!("example {} should be a table", index + 1)),
+            })
+            .collect()
+    }
+
+    fn from_table(number: usize, table: toml::Table) -> Result<Self, String> {
+        let mut example = Self {
+            name: format!("example {number}"),
+            input: String::new(),
+            params: Params::default(),
+            answers: [None, None],
+        };
+        let mut has_input = false;
+        for (key, value) in table {
+            let context = format!("`{key}` of example {number}");
+            match (key.as_str(), value) {
+                ("name", toml::Value::String(name)) => example.name = name,
+                ("input", toml::Value::String(input)) => {
+                    example.input = input;
+                    has_input = true;
+                }
+                ("part1", value) => example.answers[0] = Some(value_text(value, &context)?),
+                ("part2", value) => example.answers[1] = Some(value_text(value, &context)?),
+                ("params", toml::Value::Table(params)) => {
+                    for (name, value) in params {
+                        let context = format!("parameter `{name}` of example {number}");
+                        example.params.set(name, value_text(value, &context)?);
+                    }
+                }
+                ("name" | "input", _) => return Err(format!("{context} should be a string")),
+                ("params", _) => return Err(format!("{context} should be a table")),
+                _ => {
+                    return Err(format!(
+                        "{context} is not `name`, `input`, `part1`, `part2` or `params`"
+                    ))
+                }
+            }
+        }
+        if !has_input {
+            return Err(format!("example {number} has no `input`"));
+        }
+        Ok(example)
+    }
+
+    /// The answer written down for `part`, 1 or 2.
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.answers[usize::from(part - 1)].as_deref()
+    }
+
+    /// Solves the example with `solver`, describing everything that went wrong if it didn't come
+    /// out as expected.
+    pub fn check(&self, solver: &Solver) -> Result<(), String> {
+        let day = solver
+            .parse_owned(&Arc::from(self.input.as_str()))
+            .map_err(|error| format!("{}: {error}", self.name))?;
+        let mut failures = vec![];
+        for part in 1..=2 {
+            let Some(expected) = self.expected(part) else {
+                continue;
+            };
+            let answer = day
+                .solve_with(part, &self.params)
+                .map_err(|error| format!("{}: {error}", self.name))?;
+            if !answer.matches(expected) {
+                failures.push(format!(
+                    "{} part {part}: expected {}, got {}",
+                    self.name,
+                    quoted(expected),
+                    quoted(&answer.to_string())
+                ));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures.join("\n"))
+        }
+    }
+}
+
+/// Checks a day's examples, the contents of its `examples/dayNN.toml`, panicking with every
+/// failure. The tests year crates generate from their `examples` directories call this, one test
+/// a day.
+pub fn check_all(solver: Solver, fixtures: &str) {
+    let examples = Example::parse_all(fixtures)
+        .unwrap_or_else(|reason| panic!("examples for day {}: {reason}", solver.day));
+    let failures = examples
+        .iter()
+        .filter_map(|example| example.check(&solver).err())
+        .collect::<Vec<_>>();
+    assert!(
+        failures.is_empty(),
+        "day {} got examples wrong:\n{}",
+        solver.day,
+        failures.join("\n")
+    );
+}
+
+fn value_text(value: toml::Value, context: &str) -> Result<String, String> {
+    match value {
+        toml::Value::Integer(value) => Ok(value.to_string()),
+        toml::Value::String(text) => Ok(text.trim_end_matches('\n').to_owned()),
+        _ => Err(format!("{context} should be an integer or a string")),
+    }
+}
+
+/// Drawings start on a line of their own, so the rows line up.
+fn quoted(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{answer}\n")
+    } else {
+        answer.to_owned()
+    }
+}
+
+/// Writes a test for every `examples/dayNN.toml` of the crate being built into
+/// `$OUT_DIR/examples.rs`, for its `tests/examples.rs` to include. Year crates call this from
+/// their build scripts, which makes `advent` a build dependency of theirs too.
+pub fn generate_tests() -> io::Result<()> {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=examples");
+    let mut fixtures = vec![];
+    match read_dir("examples") {
+        Ok(entries) => {
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension() == Some("toml".as_ref()) {
+                    fixtures.push(path);
+                }
+            }
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+    fixtures.sort();
+
+    let package = env::var("CARGO_PKG_NAME").expect("build scripts get a package name");
+    let mut tests = String::new();
+    for path in fixtures {
+        let name = path.file_stem().unwrap().to_str().unwrap().to_owned();
+        let day = name
+            .strip_prefix("day")
+            .and_then(|digits| digits.parse::<u8>().ok())
+            .unwrap_or_else(|| panic!("{path:?} should be named dayNN.toml"));
+        let path = fs::canonicalize(path)?;
+        writeln!(
+            tests,
+            "#[test]\nfn {name}() {{\n    advent::example::check_all(\n        \
+             {package}::solver({day}).expect(\"day {day} is not registered\"),\n        \
+             include_str!({path:?}),\n    );\n}}\n"
+        )
+        .expect("strings take writes");
+    }
+    fs::write(
+        PathBuf::from(env::var_os("OUT_DIR").expect("build scripts get an output directory"))
+            .join("examples.rs"),
+        tests,
+    )
+}
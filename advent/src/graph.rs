@@ -0,0 +1,424 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{
+    grid::{Grid, GridPoint},
+    Direction8,
+};
+
+/// Nodes numbered in the order they were first seen, each keeping the label it was added by, with
+/// weighted directed edges between them. Unweighted graphs weigh their edges with `()`.
+///
+/// ```rust
+/// use advent::graph::Graph;
+/// let graph = Graph::undirected([("kh", "tc"), ("qp", "kh"), ("de", "cg")]);
+/// assert_eq!(5, graph.len());
+/// let kh = graph.id(&"kh").unwrap();
+/// let mut neighbours = graph.neighbours(kh).map(|id| *graph.label(id)).collect::<Vec<_>>();
+/// neighbours.sort();
+/// assert_eq!(vec!["qp", "tc"], neighbours);
+/// assert!(graph.contains_edge(kh, graph.id(&"tc").unwrap()));
+/// assert_eq!(2, graph.components().len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    labels: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Self {
+            labels: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N, ()> {
+    /// Each `(from, to)` pair is an edge one way only.
+    pub fn directed(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in edges {
+            let (from, to) = (graph.node(from), graph.node(to));
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    /// Each `(a, b)` pair is an edge both ways.
+    pub fn undirected(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Self::new();
+        for (a, b) in edges {
+            let (a, b) = (graph.node(a), graph.node(b));
+            graph.add_undirected_edge(a, b, ());
+        }
+        graph
+    }
+}
+
+impl<N: Clone + Eq + Hash, W> Graph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of the node labelled `label`, adding it first if it's new.
+    pub fn node(&mut self, label: N) -> usize {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.ids.insert(label.clone(), id);
+        self.labels.push(label);
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, label: &N) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+}
+
+impl<N, W> Graph<N, W> {
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn label(&self, id: usize) -> &N {
+        &self.labels[id]
+    }
+
+    pub fn labels(&self) -> &[N] {
+        &self.labels
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    /// The nodes `id` has an edge to, with the edges' weights.
+    pub fn edges(&self, id: usize) -> &[(usize, W)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[id].iter().map(|&(to, _)| to)
+    }
+
+    pub fn contains_edge(&self, from: usize, to: usize) -> bool {
+        self.neighbours(from).any(|id| id == to)
+    }
+
+    /// Groups of nodes connected to each other, ignoring which way the edges go.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut sets = UnionFind::new(self.len());
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                sets.union(from, to);
+            }
+        }
+        sets.groups()
+    }
+
+    /// Orders the nodes so every edge points forwards, keeping to the order nodes were added
+    /// where the edges allow it. Fails when the edges loop back on themselves.
+    ///
+    /// ```rust
+    /// use advent::graph::Graph;
+    /// let graph = Graph::directed([(47, 53), (97, 13), (97, 47), (75, 53), (75, 47), (97, 75)]);
+    /// let order = graph.topological_sort().unwrap();
+    /// let order = order.into_iter().map(|id| *graph.label(id)).collect::<Vec<_>>();
+    /// assert_eq!(vec![97, 13, 75, 47, 53], order);
+    ///
+    /// let error = Graph::directed([(1, 2), (2, 3), (3, 2), (3, 4)])
+    ///     .topological_sort()
+    ///     .unwrap_err();
+    /// assert_eq!(3, error.unsorted.len());
+    /// ```
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Cyclic> {
+        let mut incoming = vec![0; self.len()];
+        for &(to, _) in self.edges.iter().flatten() {
+            incoming[to] += 1;
+        }
+        let mut ready = (0..self.len())
+            .filter(|&id| incoming[id] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+            for to in self.neighbours(id) {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Ok(order)
+        } else {
+            Err(Cyclic {
+                unsorted: (0..self.len()).filter(|&id| incoming[id] > 0).collect(),
+            })
+        }
+    }
+
+    /// Every clique, a group of nodes all connected to each other, that can't grow any bigger.
+    /// Edges count as undirected. Uses Bron–Kerbosch with pivoting.
+    ///
+    /// ```rust
+    /// use advent::graph::Graph;
+    /// let graph = Graph::undirected([
+    ///     ("ka", "co"), ("ta", "co"), ("de", "co"), ("ta", "ka"), ("de", "ta"), ("ka", "de"),
+    ///     ("ka", "wq"),
+    /// ]);
+    /// let mut cliques = graph
+    ///     .maximal_cliques()
+    ///     .into_iter()
+    ///     .map(|clique| {
+    ///         let mut labels = clique.into_iter().map(|id| *graph.label(id)).collect::<Vec<_>>();
+    ///         labels.sort();
+    ///         labels.join(",")
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// cliques.sort();
+    /// assert_eq!(vec!["co,de,ka,ta", "ka,wq"], cliques);
+    /// ```
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut adjacent = vec![HashSet::new(); self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                if from != to {
+                    adjacent[from].insert(to);
+                    adjacent[to].insert(from);
+                }
+            }
+        }
+
+        fn bron_kerbosch(
+            adjacent: &[HashSet<usize>],
+            clique: &mut Vec<usize>,
+            mut candidates: HashSet<usize>,
+            mut excluded: HashSet<usize>,
+            cliques: &mut Vec<Vec<usize>>,
+        ) {
+            let Some(&pivot) = candidates
+                .union(&excluded)
+                .max_by_key(|&&id| adjacent[id].len())
+            else {
+                cliques.push(clique.clone());
+                return;
+            };
+            let branches = candidates
+                .difference(&adjacent[pivot])
+                .copied()
+                .collect::<Vec<_>>();
+            for id in branches {
+                clique.push(id);
+                bron_kerbosch(
+                    adjacent,
+                    clique,
+                    candidates.intersection(&adjacent[id]).copied().collect(),
+                    excluded.intersection(&adjacent[id]).copied().collect(),
+                    cliques,
+                );
+                clique.pop();
+                candidates.remove(&id);
+                excluded.insert(id);
+            }
+        }
+
+        let mut cliques = vec![];
+        bron_kerbosch(
+            &adjacent,
+            &mut vec![],
+            (0..self.len()).collect(),
+            HashSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+}
+
+/// A directed graph that [`Graph::topological_sort`] couldn't order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cyclic {
+    /// The nodes on a cycle or only reachable through one.
+    pub unsorted: Vec<usize>,
+}
+
+/// Disjoint sets of `0..len`, merged as they turn out to belong together.
+///
+/// ```rust
+/// use advent::graph::UnionFind;
+/// let mut circuits = UnionFind::new(5);
+/// assert!(circuits.union(0, 3));
+/// assert!(circuits.union(3, 4));
+/// assert!(!circuits.union(4, 0));
+/// assert_eq!(3, circuits.sets());
+/// assert_eq!(3, circuits.size(4));
+/// assert!(circuits.same(0, 4));
+/// assert_eq!(vec![vec![0, 3, 4], vec![1], vec![2]], circuits.groups());
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// Every element in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// The element standing in for the set `element` is in.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything on the way straight at the root for next time.
+        let mut element = element;
+        while self.parents[element] != root {
+            element = std::mem::replace(&mut self.parents[element], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, `false` when they were the same set already.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[small] = big;
+        self.sizes[big] += self.sizes[small];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements share a set with `element`, itself included.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// How many sets there are.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The elements of every set, ordered by their smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups = HashMap::<usize, Vec<usize>>::new();
+        for element in 0..self.parents.len() {
+            let root = self.find(element);
+            groups.entry(root).or_default().push(element);
+        }
+        let mut groups = groups.into_values().collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|group| group[0]);
+        groups
+    }
+}
+
+/// Squashes the corridors of a maze into a graph of its junctions: cells where paths meet, dead
+/// ends, and whatever `keep` adds, like the start and the goal. Edges go both ways, weighed by
+/// how many steps the corridor between two junctions takes.
+///
+/// ```rust
+/// use advent::{graph::corridors, Grid, UPoint};
+/// let maze = Grid::bytes("#.###\n#...#\n#.#.#\n#...#\n###.#").unwrap();
+/// let (start, end) = (UPoint::new(1, 0), UPoint::new(3, 4));
+/// let graph = corridors(&maze, [start, end], |&cell| cell != b'#');
+/// // The start, the goal, and the two forks on the loop between them.
+/// assert_eq!(4, graph.len());
+/// let fork = graph.id(&UPoint::new(1, 1)).unwrap();
+/// let mut steps = graph.edges(fork).iter().map(|&(_, steps)| steps).collect::<Vec<_>>();
+/// steps.sort();
+/// assert_eq!(vec![1, 4, 4], steps);
+/// ```
+pub fn corridors<T, P>(
+    grid: &Grid<T>,
+    keep: impl IntoIterator<Item = P>,
+    open: impl Fn(&T) -> bool,
+) -> Graph<P, usize>
+where
+    P: GridPoint + Eq + Hash,
+{
+    let is_open = |point: P| grid.get(point).is_some_and(&open);
+    let mut graph = Graph::new();
+    for point in keep {
+        graph.node(point);
+    }
+    for (point, _) in grid.iter::<P>() {
+        if is_open(point)
+            && grid
+                .neighbours4(point)
+                .filter(|&next| is_open(next))
+                .count()
+                != 2
+        {
+            graph.node(point);
+        }
+    }
+
+    for from in 0..graph.len() {
+        let start = *graph.label(from);
+        for direction in Direction8::CARDINAL {
+            let Some(mut current) = grid.step(start, direction).filter(|&next| is_open(next))
+            else {
+                continue;
+            };
+            let mut previous = start;
+            let mut steps = 1;
+            // Junctions are the only places with a choice of way, so walk until the next one.
+            let to = loop {
+                if let Some(to) = graph.id(&current) {
+                    break Some(to);
+                }
+                let Some(next) = grid
+                    .neighbours4(current)
+                    .find(|&next| next != previous && is_open(next))
+                else {
+                    break None;
+                };
+                (previous, current) = (current, next);
+                steps += 1;
+            };
+            if let Some(to) = to {
+                graph.add_edge(from, to, steps);
+            }
+        }
+    }
+    graph
+}
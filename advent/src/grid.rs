@@ -0,0 +1,268 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction8, ParseError, Point};
+
+/// A position in a [`Grid`]. Any [`Point`] works, whatever its integer type; negative and
+/// overflowing coordinates are simply outside the grid.
+pub trait GridPoint: Copy {
+    fn to_xy(self) -> Option<(usize, usize)>;
+    fn from_xy(x: usize, y: usize) -> Self;
+}
+
+impl<T> GridPoint for Point<T>
+where
+    T: Copy + TryInto<usize> + TryFrom<usize>,
+{
+    fn to_xy(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    fn from_xy(x: usize, y: usize) -> Self {
+        let coordinate = |value| {
+            T::try_from(value)
+                .ok()
+                .expect("grid coordinate fits the point type")
+        };
+        Self {
+            x: coordinate(x),
+            y: coordinate(y),
+        }
+    }
+}
+
+/// A rectangle of cells stored row by row, `y` growing downwards like the puzzle text does.
+///
+/// ```rust
+/// use advent::{Grid, Direction, UPoint};
+/// let grid = Grid::bytes("#.#\n..^\n").unwrap();
+/// let start: UPoint = grid.find(&b'^').unwrap();
+/// assert_eq!(UPoint::new(2, 1), start);
+/// assert_eq!(Some(UPoint::new(2, 0)), grid.step(start, Direction::North));
+/// assert_eq!(None, grid.step(start, Direction::East));
+/// assert_eq!(b'#', grid[UPoint::new(2, 0)]);
+/// assert_eq!("#.#\n..^\n", grid.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Takes the cells row by row. Panics unless there are exactly `width * height` of them.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "a {width}x{height} grid");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Reads one cell per byte, one row per line. `cell` turns down bytes that aren't part of the
+    /// puzzle by returning `None`.
+    ///
+    /// ```rust
+    /// use advent::{Grid, IPoint};
+    /// let grid = Grid::parse("#.\n.#", |cell| match cell {
+    ///     b'#' => Some(true),
+    ///     b'.' => Some(false),
+    ///     _ => None,
+    /// }).unwrap();
+    /// assert_eq!(Some(&true), grid.get(IPoint::new(1, 1)));
+    /// assert_eq!(None, grid.get(IPoint::new(-1, 1)));
+    ///
+    /// let error = Grid::parse("#.\n.x", |cell| (cell != b'x').then_some(cell)).unwrap_err();
+    /// assert_eq!((2, 2), (error.line, error.column));
+    /// let error = Grid::parse("#.\n.→", |cell| cell.is_ascii().then_some(cell)).unwrap_err();
+    /// assert_eq!((2, 2, "→"), (error.line, error.column, error.found.as_str()));
+    /// assert!(Grid::parse("#.\n.", Some).is_err());
+    /// ```
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            for (x, byte) in line.bytes().enumerate() {
+                let value = cell(byte).ok_or_else(|| {
+                    // Point at the whole character the byte is part of.
+                    let (start, found) = line
+                        .char_indices()
+                        .take_while(|&(start, _)| start <= x)
+                        .last()
+                        .expect("a line with bytes has characters");
+                    ParseError::at(input, &line[start..start + found.len_utf8()], "a grid cell")
+                })?;
+                cells.push(value);
+            }
+            match width {
+                None => width = Some(line.len()),
+                Some(width) if width != line.len() => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row {width} cells wide"),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// One past the bottom-right corner, the way `Point::direction_checked` takes its bounds.
+    pub fn bounds<P: GridPoint>(&self) -> P {
+        P::from_xy(self.width, self.height)
+    }
+
+    fn offset<P: GridPoint>(&self, point: P) -> Option<usize> {
+        let (x, y) = point.to_xy()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains<P: GridPoint>(&self, point: P) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get<P: GridPoint>(&self, point: P) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut<P: GridPoint>(&mut self, point: P) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` would panic on a zero-width grid, which has no rows worth handing out anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter<P: GridPoint>(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (P::from_xy(offset % self.width, offset / self.width), cell))
+    }
+
+    /// The first position holding `value`, reading row by row.
+    pub fn find<P: GridPoint>(&self, value: &T) -> Option<P>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a, P: GridPoint + 'a>(&'a self, value: &'a T) -> impl Iterator<Item = P> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// The neighbour of `point` towards `direction`, a `Direction` or a `Direction8`, unless
+    /// that's off the grid.
+    pub fn step<P: GridPoint>(&self, point: P, direction: impl Into<Direction8>) -> Option<P> {
+        let (dx, dy) = direction.into().offset();
+        let (x, y) = point.to_xy()?;
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some(P::from_xy(x, y))
+    }
+
+    /// Orthogonal neighbours inside the grid, clockwise from north.
+    pub fn neighbours4<'a, P: GridPoint + 'a>(&'a self, point: P) -> impl Iterator<Item = P> + 'a {
+        Direction8::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, clockwise from north.
+    ///
+    /// ```rust
+    /// use advent::{Grid, UPoint};
+    /// let grid = Grid::bytes("abc\ndef\nghi").unwrap();
+    /// let around = |point: UPoint| {
+    ///     grid.neighbours8(point).map(|point| grid[point] as char).collect::<String>()
+    /// };
+    /// assert_eq!("bcfihgda", around(UPoint::new(1, 1)));
+    /// assert_eq!("bed", around(UPoint::new(0, 0)));
+    /// ```
+    pub fn neighbours8<'a, P: GridPoint + 'a>(&'a self, point: P) -> impl Iterator<Item = P> + 'a {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Renders a cell per character, a row per line.
+    pub fn to_text(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl Grid<u8> {
+    /// A grid of the input's bytes as they are.
+    pub fn bytes(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, Some)
+    }
+}
+
+impl<T, P: GridPoint> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: P) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(point)
+            .unwrap_or_else(|| panic!("point outside the {width}x{height} grid"))
+    }
+}
+
+impl<T, P: GridPoint> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, point: P) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point outside the {width}x{height} grid"))
+    }
+}
+
+/// Prints cells that turn into characters, like the `u8` and `char` grids puzzles come in.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text(|&cell| cell.into()))
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+};
+
+use crate::visualize::{Frame, Frames};
+
+/// Names a single input file to use instead of the input directory. `-` reads standard input.
+pub const INPUT_VAR: &str = "ADVENT_INPUT";
+/// Overrides the directory holding `<year>/dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+static SOURCE: OnceLock<InputSource> = OnceLock::new();
+/// Standard input can only be read once, so whatever came of that is kept for every later day. An
+/// error is kept as its kind and message, since `io::Error` can't be cloned.
+static STDIN: OnceLock<Result<String, (io::ErrorKind, String)>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// One input file, used for whichever day asks.
+    Path(PathBuf),
+    /// Everything on standard input, read once and replayed for both parts.
+    Stdin,
+    /// A directory laid out like the `AdventOfCodeInput` repository, `<dir>/<year>/dayNN.txt`.
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /// The source configured with [`set_source`], or failing that the `ADVENT_INPUT` and
+    /// `ADVENT_INPUT_DIR` environment variables, or failing that the `AdventOfCodeInput`
+    /// checkout next to this workspace.
+    pub fn current() -> Self {
+        SOURCE.get_or_init(Self::from_env).clone()
+    }
+
+    pub fn from_env() -> Self {
+        match (env::var_os(INPUT_VAR), env::var_os(INPUT_DIR_VAR)) {
+            (Some(path), _) if path == "-" => Self::Stdin,
+            (Some(path), _) => Self::Path(path.into()),
+            (None, Some(dir)) => Self::Directory(dir.into()),
+            (None, None) => Self::Directory(default_dir()),
+        }
+    }
+
+    /// ```rust
+    /// use std::path::Path;
+    /// use advent::input::InputSource;
+    /// let source = InputSource::Directory("inputs".into());
+    /// assert_eq!(Some(Path::new("inputs/2023/day05.txt").to_path_buf()), source.path(2023, 5));
+    /// assert_eq!(None, InputSource::Stdin.path(2023, 5));
+    /// ```
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Path(path) => Some(path.clone()),
+            Self::Stdin => None,
+            Self::Directory(dir) => {
+                Some(dir.join(year.to_string()).join(format!("day{day:02}.txt")))
+            }
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.path(year, day) {
+            Some(path) => read_file(&path),
+            // Days load in parallel, so the read happens inside the `OnceLock`, where only one
+            // of them gets to do it and the rest wait for the result.
+            None => STDIN
+                .get_or_init(|| {
+                    let mut input = String::new();
+                    io::stdin()
+                        .read_to_string(&mut input)
+                        .map(|_| input)
+                        .map_err(|error| (error.kind(), error.to_string()))
+                })
+                .clone()
+                .map_err(|(kind, message)| InputError::Io {
+                    path: None,
+                    source: io::Error::new(kind, message),
+                }),
+        }
+    }
+}
+
+/// Pins the input source for the rest of the process, which takes precedence over the
+/// environment. Only the first call wins; later calls hand their source back.
+pub fn set_source(source: InputSource) -> Result<(), InputSource> {
+    SOURCE.set(source)
+}
+
+/// Reads the input for a day from the [current](InputSource::current) source. A missing file
+/// falls back to `embedded`, which days fill in when built with the `embed-input` feature.
+pub fn read_input(
+    year: u16,
+    day: u8,
+    embedded: Option<&'static str>,
+) -> Result<String, InputError> {
+    match (InputSource::current().read(year, day), embedded) {
+        (Err(InputError::NotFound(_)), Some(embedded)) => Ok(embedded.to_owned()),
+        (result, _) => result,
+    }
+}
+
+pub fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("AdventOfCodeInput")
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_owned()),
+        _ => InputError::Io {
+            path: Some(path.to_owned()),
+            source,
+        },
+    })
+}
+
+/// What a parser made of an input, together with that input. Days are free to borrow from what
+/// they parse, so the input stays alive for as long as the parsed value does, and is freed with it
+/// rather than leaked.
+pub struct Parsed<T> {
+    // Fields drop in order, so `value` goes before the input it may borrow from.
+    value: T,
+    _input: Arc<str>,
+}
+
+impl<T> Parsed<T> {
+    /// Only for values that can't hand out what they borrow, like the boxed days, parts and frames
+    /// runners get, since nothing borrowed from `input` may outlive the `Parsed`.
+    pub(crate) fn new<E>(
+        input: &Arc<str>,
+        parse: impl FnOnce(&'static str) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        // SAFETY: an `Arc<str>` keeps its text at the same address until the last clone goes, and
+        // the clone kept here is dropped after `value`, the only thing the borrow is handed to.
+        // That holds as long as `value` never leaves the `Parsed`, so it's only ever lent out as
+        // `&T`, never by value or as `&mut T`, which could swap it out.
+        let borrowed = unsafe { &*Arc::as_ptr(input) };
+        Ok(Self {
+            value: parse(borrowed)?,
+            _input: Arc::clone(input),
+        })
+    }
+}
+
+impl<T> Deref for Parsed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Frames are owned, so handing them out doesn't let anything borrowed from the input escape.
+impl Iterator for Parsed<Frames> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        self.value.next()
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(path) => write!(
+                f,
+                "no input at {} (set {INPUT_VAR} or {INPUT_DIR_VAR}, or build with `embed-input`)",
+                path.display()
+            ),
+            Self::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "could not read {}: {source}", path.display()),
+            Self::Io { path: None, source } => write!(f, "could not read stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound(_) => None,
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash};
+
+/// Integers small enough to make handing out ids cheap, and that index a `Vec` when the time comes.
+pub trait Id: Copy + Eq + Hash + Debug {
+    /// Panics when `index` doesn't fit.
+    fn from_index(index: usize) -> Self;
+    fn index(self) -> usize;
+}
+
+macro_rules! id {
+    ($($int:ty),+) => {$(
+        impl Id for $int {
+            fn from_index(index: usize) -> Self {
+                Self::try_from(index).expect("more labels than the id type can count")
+            }
+
+            fn index(self) -> usize {
+                self as usize
+            }
+        }
+    )+};
+}
+
+id!(u8, u16, u32, usize);
+
+/// Hands out dense ids, `0`, `1`, `2`... in order of first sight, for the labels of an input, and
+/// turns them back into labels. The labels stay borrowed from the input.
+///
+/// ```rust
+/// use advent::intern::Interner;
+/// let mut wires = Interner::<u16>::new();
+/// assert_eq!(0, wires.intern("x00"));
+/// assert_eq!(1, wires.intern("y00"));
+/// assert_eq!(0, wires.intern("x00"));
+/// assert_eq!(Some(1), wires.get("y00"));
+/// assert_eq!(None, wires.get("z00"));
+/// assert_eq!("y00", wires.resolve(1));
+/// assert_eq!(2, wires.len());
+///
+/// let workflows = "in px qqz px".split(' ').collect::<Interner>();
+/// assert_eq!(&["in", "px", "qqz"], workflows.labels());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interner<'a, I = u32> {
+    ids: HashMap<&'a str, I>,
+    labels: Vec<&'a str>,
+}
+
+impl<I> Default for Interner<'_, I> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            labels: vec![],
+        }
+    }
+}
+
+impl<'a, I: Id> Interner<'a, I> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `label`, handing out the next one when it's new.
+    pub fn intern(&mut self, label: &'a str) -> I {
+        *self.ids.entry(label).or_insert_with(|| {
+            self.labels.push(label);
+            I::from_index(self.labels.len() - 1)
+        })
+    }
+
+    /// The id of `label`, if it's been interned.
+    pub fn get(&self, label: &str) -> Option<I> {
+        self.ids.get(label).copied()
+    }
+
+    /// The label behind `id`. Panics on ids this interner didn't hand out.
+    pub fn resolve(&self, id: I) -> &'a str {
+        self.labels[id.index()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Every label, indexed by its id.
+    pub fn labels(&self) -> &[&'a str] {
+        &self.labels
+    }
+}
+
+impl<'a, I: Id> FromIterator<&'a str> for Interner<'a, I> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(labels: T) -> Self {
+        let mut interner = Self::new();
+        for label in labels {
+            interner.intern(label);
+        }
+        interner
+    }
+}
+
+/// The base 36 value of an ASCII digit or letter, ignoring case: `0`-`9` then `a`-`z`.
+///
+/// ```rust
+/// use advent::intern::digit;
+/// assert_eq!(Some(7), digit(b'7'));
+/// assert_eq!(Some(35), digit(b'Z'));
+/// assert_eq!(digit(b'z'), digit(b'Z'));
+/// assert_eq!(None, digit(b'-'));
+/// ```
+pub fn digit(byte: u8) -> Option<usize> {
+    (byte as char).to_digit(36).map(|digit| digit as usize)
+}
+
+/// Labels of exactly `WIDTH` ASCII digits and letters, packed as base 36 numbers into
+/// `0..CAPACITY`: no hashing, no interning, and straight into a `Vec` the size of every possible
+/// label. Letters lose their case on the way.
+///
+/// ```rust
+/// use advent::intern::{digit, Alphanumeric};
+/// type Node = Alphanumeric<3>;
+/// assert_eq!(46_656, Node::CAPACITY);
+/// assert_eq!(Some(0), Node::pack("000"));
+/// assert_eq!(Some(Node::CAPACITY - 1), Node::pack("ZZZ"));
+/// assert_eq!(None, Node::pack("ZZ"));
+/// assert_eq!(None, Node::pack("a-b"));
+///
+/// let node = Node::pack("11A").unwrap();
+/// assert_eq!(digit(b'A'), Some(node % 36));
+/// assert_eq!("11a", Node::unpack(node));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alphanumeric<const WIDTH: usize>;
+
+impl<const WIDTH: usize> Alphanumeric<WIDTH> {
+    /// How many different labels there are, so the size of a `Vec` that can hold them all.
+    pub const CAPACITY: usize = 36usize.pow(WIDTH as u32);
+
+    pub fn pack(label: &str) -> Option<usize> {
+        if label.len() != WIDTH {
+            return None;
+        }
+        label
+            .bytes()
+            .try_fold(0, |packed, byte| Some(packed * 36 + digit(byte)?))
+    }
+
+    /// The label packed into `packed`, in lowercase.
+    pub fn unpack(packed: usize) -> String {
+        let mut label = (0..WIDTH)
+            .scan(packed, |rest, _| {
+                let digit = *rest % 36;
+                *rest /= 36;
+                char::from_digit(digit as u32, 36)
+            })
+            .collect::<Vec<_>>();
+        label.reverse();
+        label.into_iter().collect()
+    }
+}
@@ -0,0 +1,300 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+use num_traits::PrimInt;
+
+/// Turns any range into the half-open one everything here works with. `..=T::MAX` doesn't fit, so
+/// it panics, and an unbounded end stops just short of `T::MAX`.
+fn half_open<T: PrimInt>(range: impl RangeBounds<T>) -> Range<T> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + T::one(),
+        Bound::Unbounded => T::min_value(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(&T::one())
+            .expect("an inclusive range ending before the type's maximum"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => T::max_value(),
+    };
+    start..end
+}
+
+/// A set of integers kept as sorted, disjoint ranges, so huge spans cost no more than small ones.
+///
+/// ```rust
+/// use advent::interval::IntervalSet;
+/// let fresh = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect::<IntervalSet<u64>>();
+/// assert_eq!(&[3..6, 10..21], fresh.ranges());
+/// assert_eq!(14, fresh.coverage());
+/// assert!(fresh.contains(17));
+/// assert!(!fresh.contains(8));
+///
+/// let checked = IntervalSet::from_iter([0..12]);
+/// assert_eq!(&[3..6, 10..12], fresh.intersection(&checked).ranges());
+/// assert_eq!(&[12..21], fresh.difference(&checked).ranges());
+/// assert_eq!(&[0..21], fresh.union(&checked).ranges());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, non-empty, and never touching each other: touching ranges get merged.
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values the set holds.
+    pub fn coverage(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |total, range| total + (range.end - range.start))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// Adds every value in `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let Range { mut start, mut end } = half_open(range);
+        if start >= end {
+            return;
+        }
+        let first = self.ranges.partition_point(|range| range.end < start);
+        let last = self.ranges.partition_point(|range| range.start <= end);
+        if first < last {
+            start = start.min(self.ranges[first].start);
+            end = end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [start..end]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().cloned());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(left), Some(right)) = (a.peek(), b.peek()) {
+            let start = left.start.max(right.start);
+            let end = left.end.min(right.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever ends first can't overlap anything else on the other side.
+            if left.end < right.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut removed = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(hole) = removed.peek() {
+                if hole.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+                if hole.start > start {
+                    ranges.push(start..hole.start);
+                }
+                start = hole.end;
+                if hole.end > range.end {
+                    // The hole may still cut into the next range.
+                    break;
+                }
+                removed.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> Extend<R> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+/// A piecewise mapping that shifts ranges of values by their own offsets and leaves everything
+/// else as it is, like the almanac maps of 2023 day 5.
+///
+/// ```rust
+/// use advent::interval::{IntervalSet, OffsetMap};
+/// let mut seed_to_soil = OffsetMap::new();
+/// seed_to_soil.insert(98..100, 50u64);
+/// seed_to_soil.insert(50..98, 52);
+/// assert_eq!(81, seed_to_soil.get(79));
+/// assert_eq!(14, seed_to_soil.get(14));
+///
+/// // A range comes back split where the map's pieces split it.
+/// assert_eq!(vec![45..50, 52..100, 50..52, 100..105], seed_to_soil.map_range(45..105));
+/// let soil = seed_to_soil.map(&IntervalSet::from_iter([79..=92, 55..=67]));
+/// assert_eq!(&[57..70, 81..95], soil.ranges());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetMap<T> {
+    /// Sources with where they start mapping to, sorted by source.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for OffsetMap<T> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<T: PrimInt> OffsetMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the values in `source` to the same number of values starting at `destination`.
+    /// Panics when `source` overlaps a source already in the map.
+    pub fn insert(&mut self, source: impl RangeBounds<T>, destination: T) {
+        if self.try_insert(source, destination).is_err() {
+            panic!("overlapping sources in an offset map");
+        }
+    }
+
+    /// Like [`insert`](Self::insert), but hands back the source already in the map that `source`
+    /// overlaps, leaving the map as it was.
+    ///
+    /// ```rust
+    /// use advent::interval::OffsetMap;
+    /// let mut map = OffsetMap::new();
+    /// assert_eq!(Ok(()), map.try_insert(10..20, 50));
+    /// assert_eq!(Err(10..20), map.try_insert(15..=25, 0));
+    /// assert_eq!(Ok(()), map.try_insert(20..25, 0));
+    /// assert_eq!(55, map.get(15));
+    /// ```
+    pub fn try_insert(
+        &mut self,
+        source: impl RangeBounds<T>,
+        destination: T,
+    ) -> Result<(), Range<T>> {
+        let source = half_open(source);
+        if source.start >= source.end {
+            return Ok(());
+        }
+        let index = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= source.start);
+        match self.pieces.get(index) {
+            Some((piece, _)) if piece.start < source.end => Err(piece.clone()),
+            _ => {
+                self.pieces.insert(index, (source, destination));
+                Ok(())
+            }
+        }
+    }
+
+    fn translate(source: &Range<T>, destination: T, value: T) -> T {
+        destination + (value - source.start)
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let index = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        match self.pieces.get(index) {
+            Some((source, destination)) if source.start <= value => {
+                Self::translate(source, *destination, value)
+            }
+            _ => value,
+        }
+    }
+
+    /// Where every value in `range` ends up, as the pieces the map splits it into, in the order
+    /// of the values they came from.
+    pub fn map_range(&self, range: impl RangeBounds<T>) -> Vec<Range<T>> {
+        let Range { start, end } = half_open(range);
+        let mut mapped = vec![];
+        let mut cursor = start;
+        let first = self.pieces.partition_point(|(piece, _)| piece.end <= start);
+        for (source, destination) in &self.pieces[first..] {
+            if cursor >= end || source.start >= end {
+                break;
+            }
+            if source.start > cursor {
+                mapped.push(cursor..source.start);
+                cursor = source.start;
+            }
+            let piece_end = end.min(source.end);
+            mapped.push(
+                Self::translate(source, *destination, cursor)
+                    ..Self::translate(source, *destination, piece_end),
+            );
+            cursor = piece_end;
+        }
+        if cursor < end {
+            mapped.push(cursor..end);
+        }
+        mapped
+    }
+
+    /// Where every value in `set` ends up.
+    pub fn map(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.map_range(range.clone()))
+            .collect()
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> FromIterator<(R, T)> for OffsetMap<T> {
+    fn from_iter<I: IntoIterator<Item = (R, T)>>(pieces: I) -> Self {
+        let mut map = Self::new();
+        for (source, destination) in pieces {
+            map.insert(source, destination);
+        }
+        map
+    }
+}
@@ -1,4 +1,31 @@
-use std::{ops, str::FromStr};
+use std::{ops, str::FromStr, sync::Arc};
+
+pub mod answer;
+pub mod bench;
+pub mod cycle;
+pub mod example;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod intern;
+pub mod interval;
+pub mod math;
+pub mod params;
+pub mod parse;
+mod point;
+pub mod registry;
+pub mod search;
+pub mod trace;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use math::Decimal;
+// For the registration code `advent_day!` expands to.
+pub use ctor;
+pub use params::{Param, ParamError, Params};
+pub use parse::{DayError, ParseError};
+pub use point::{AbsDiff, Chebyshev, Manhattan, SquaredEuclidean};
 
 pub type UPoint = Point<u32>;
 pub type IPoint = Point<i32>;
@@ -9,39 +36,6 @@ pub struct Point<T> {
     pub y: T,
 }
 
-impl<T> ops::Add<Point<T>> for Point<T>
-where
-    T: num_traits::identities::Zero + Copy + num_traits::CheckedAdd,
-{
-    type Output = Point<T>;
-
-    fn add(self, rhs: Point<T>) -> Self::Output {
-        Point::new((self.x + rhs.x) as T, (self.y + rhs.y) as T)
-    }
-}
-
-impl<T> ops::Mul<T> for Point<T>
-where
-    T: num_traits::identities::Zero + Copy + num_traits::CheckedMul,
-{
-    type Output = Point<T>;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        Point::new((self.x * rhs) as T, (self.y * rhs) as T)
-    }
-}
-
-impl<T> ops::Sub<Point<T>> for Point<T>
-where
-    T: num_traits::identities::Zero + Copy + num_traits::CheckedSub,
-{
-    type Output = Point<T>;
-
-    fn sub(self, rhs: Point<T>) -> Self::Output {
-        Point::new((self.x - rhs.x) as T, (self.y - rhs.y) as T)
-    }
-}
-
 impl<T> Point<T>
 where
     T: num_traits::Unsigned
@@ -86,6 +80,40 @@ where
             Direction::West => self.west_checked(),
         }
     }
+
+    /// A step towards any of the eight directions, as long as it stays within `0..bounds`.
+    pub fn step_checked(&self, direction: Direction8, bounds: &Point<T>) -> Option<Self> {
+        let shift = |value: T, delta, bound| match delta {
+            -1 => value.checked_sub(&T::one()),
+            1 => Some(value + T::one()).filter(|&value| value < bound),
+            _ => Some(value),
+        };
+        let (dx, dy) = direction.offset();
+        Some(Self::new(
+            shift(self.x, dx, bounds.x)?,
+            shift(self.y, dy, bounds.y)?,
+        ))
+    }
+
+    /// The orthogonal neighbours within `0..bounds`, clockwise from north.
+    pub fn neighbours4_checked<'a>(
+        &'a self,
+        bounds: &'a Point<T>,
+    ) -> impl Iterator<Item = Self> + 'a {
+        Direction8::CARDINAL
+            .into_iter()
+            .filter_map(|direction| self.step_checked(direction, bounds))
+    }
+
+    /// The orthogonal and diagonal neighbours within `0..bounds`, clockwise from north.
+    pub fn neighbours8_checked<'a>(
+        &'a self,
+        bounds: &'a Point<T>,
+    ) -> impl Iterator<Item = Self> + 'a {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(|direction| self.step_checked(direction, bounds))
+    }
 }
 
 impl<T> Point<T>
@@ -139,6 +167,26 @@ where
     pub fn down(&self) -> Self {
         Self::new(self.x, self.y + T::one())
     }
+
+    pub fn step(&self, direction: Direction8) -> Self {
+        let shift = |value: T, delta| match delta {
+            -1 => value - T::one(),
+            1 => value + T::one(),
+            _ => value,
+        };
+        let (dx, dy) = direction.offset();
+        Self::new(shift(self.x, dx), shift(self.y, dy))
+    }
+
+    /// The orthogonal neighbours, clockwise from north.
+    pub fn neighbours4(&self) -> [Self; 4] {
+        Direction8::CARDINAL.map(|direction| self.step(direction))
+    }
+
+    /// The orthogonal and diagonal neighbours, clockwise from north.
+    pub fn neighbours8(&self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self.step(direction))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -178,30 +226,170 @@ impl Direction {
     }
 }
 
-pub trait Manhattan {
-    type Output;
-    fn manhattan(&self, other: &Self) -> Self::Output;
+/// All eight compass directions, clockwise from north. `y` grows southwards, as in the puzzles.
+///
+/// ```rust
+/// use advent::{Direction, Direction8, IPoint, UPoint};
+/// assert_eq!(Direction8::NorthEast, Direction8::North.right45());
+/// assert_eq!(Direction8::West, Direction8::North.left());
+/// assert_eq!(Direction8::SouthWest, Direction8::NorthEast.opposite());
+/// assert_eq!(Direction8::East, Direction::East.into());
+///
+/// assert_eq!(IPoint::new(-1, -1), IPoint::origin().step(Direction8::NorthWest));
+/// let bounds = UPoint::new(3, 3);
+/// assert_eq!(None, UPoint::new(2, 1).step_checked(Direction8::SouthEast, &bounds));
+/// assert_eq!(3, UPoint::origin().neighbours8_checked(&bounds).count());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
 }
 
-impl Manhattan for UPoint {
-    type Output = u32;
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    fn turn(&self, eighths: usize) -> Self {
+        Self::ALL[(*self as usize + eighths) % 8]
+    }
+
+    pub fn right45(&self) -> Self {
+        self.turn(1)
+    }
 
-    fn manhattan(&self, other: &Self) -> Self::Output {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    pub fn left45(&self) -> Self {
+        self.turn(7)
+    }
+
+    pub fn right(&self) -> Self {
+        self.turn(2)
+    }
+
+    pub fn left(&self) -> Self {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    /// How far a step moves along `x` and `y`.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+/// Diagonals have no four-way counterpart.
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::North => Ok(Self::North),
+            Direction8::East => Ok(Self::East),
+            Direction8::South => Ok(Self::South),
+            Direction8::West => Ok(Self::West),
+            diagonal => Err(diagonal),
+        }
     }
 }
 
 #[macro_export]
 macro_rules! run_day {
     ($id:literal, $day:path) => {
-        print!("Day {}#\t[{:<20}]", $id, <$day>::part1());
-        println!(" [{:<20}]", <$day>::part2());
+        let day = <$day>::default();
+        print!("Day {}#\t[{:<20}]", $id, day.part1());
+        println!(" [{:<20}]", day.part2());
     };
 }
 
 pub trait AdventDay: Send + Sync {
-    fn part1(&self) -> String;
-    fn part2(&self) -> String;
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+
+    /// The constants this day solves real inputs with, which examples can swap out.
+    fn params(&self) -> &'static [params::Param] {
+        &[]
+    }
+
+    /// Solves `part` with `params` in place of the real inputs' constants. Only days with
+    /// [`params`](Self::params) need to override this, since [`solve_with`](Self::solve_with)
+    /// turns away params a day doesn't have.
+    fn part_with(&self, part: u8, params: &params::Params) -> Result<Answer, params::ParamError> {
+        let _ = params;
+        Ok(match part {
+            1 => self.part1(),
+            _ => self.part2(),
+        })
+    }
+}
+
+impl dyn AdventDay {
+    /// Solves `part`, 1 or 2, in a `part` span for tracing.
+    pub fn solve(&self, part: u8) -> Answer {
+        let _span = tracing::info_span!("part", part).entered();
+        match part {
+            1 => self.part1(),
+            _ => self.part2(),
+        }
+    }
+
+    /// Like [`solve`](Self::solve), with `params` overriding the day's constants.
+    pub fn solve_with(
+        &self,
+        part: u8,
+        params: &params::Params,
+    ) -> Result<Answer, params::ParamError> {
+        params.check(self.params())?;
+        let _span = tracing::info_span!("part", part).entered();
+        self.part_with(part, params)
+    }
 }
 
 pub trait DayPrinter: AdventDay + DayId {
@@ -215,8 +403,88 @@ pub trait DayPrinter: AdventDay + DayId {
     }
 }
 
+/// Input is `'static` so days are free to borrow from it. Runners keep it alive for as long as the
+/// day by parsing through [`Solver::parse_owned`].
 pub trait DayParser<D: AdventDay> {
-    fn parse(input: &'_ str) -> D;
+    fn parse(input: &'static str) -> Result<D, ParseError>;
+}
+
+pub fn boxed_day<D: AdventDay + 'static>(day: D) -> Box<dyn AdventDay> {
+    Box::new(day)
+}
+
+/// A day along with the input it was parsed from.
+pub type LoadedDay = input::Parsed<Box<dyn AdventDay>>;
+
+/// A day broken into the steps a runner might want to drive, and time, separately.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub load_input: fn() -> Result<String, input::InputError>,
+    pub parse: fn(&'static str) -> Result<Box<dyn AdventDay>, ParseError>,
+}
+
+impl Solver {
+    /// The span tracing puts the day's `parse` and `part` spans under, when they're run inside it.
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!("day", year = self.year, day = self.day)
+    }
+
+    /// Reads and parses the input, in a `parse` span for tracing.
+    pub fn load(&self) -> Result<LoadedDay, DayError> {
+        let input = Arc::from((self.load_input)()?);
+        let _span = tracing::info_span!("parse").entered();
+        Ok(self.parse_owned(&input)?)
+    }
+
+    /// Parses `input`, which the day shares for as long as it lives. Parsing the same input again,
+    /// to time it, doesn't copy it.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use advent::{boxed_day, AdventDay, Answer, Solver};
+    /// struct Lines(Vec<&'static str>);
+    /// impl AdventDay for Lines {
+    ///     fn part1(&self) -> Answer {
+    ///         self.0.len().into()
+    ///     }
+    ///     fn part2(&self) -> Answer {
+    ///         self.0[0].into()
+    ///     }
+    /// }
+    /// let solver = Solver {
+    ///     year: 2015,
+    ///     day: 1,
+    ///     load_input: || Ok(String::new()),
+    ///     parse: |input| Ok(boxed_day(Lines(input.lines().collect()))),
+    /// };
+    ///
+    /// let input = Arc::from("first\nsecond");
+    /// let day = solver.parse_owned(&input).unwrap();
+    /// assert_eq!("first", day.solve(2).to_string());
+    /// assert_eq!(2, Arc::strong_count(&input));
+    /// drop(day);
+    /// assert_eq!(1, Arc::strong_count(&input));
+    /// ```
+    pub fn parse_owned(&self, input: &Arc<str>) -> Result<LoadedDay, ParseError> {
+        input::Parsed::new(input, self.parse)
+    }
+
+    /// Solves both parts in the `Day N#  [part 1]  [part 2]` form the year binaries print.
+    /// Panics if the day won't load, like `Default` does for days.
+    pub fn summary(&self) -> String {
+        let _span = self.span().entered();
+        let day = self
+            .load()
+            .unwrap_or_else(|error| panic!("Day {}: {}", self.day, error));
+        format!(
+            "Day {}#\t[{:<20}] [{:<20}]",
+            self.day,
+            day.solve(1),
+            day.solve(2)
+        )
+    }
 }
 
 pub trait DayId {
@@ -224,10 +492,11 @@ pub trait DayId {
 }
 
 pub trait AdventInput<TInput> {
-    const INPUT: &'static str;
     fn input(&self) -> &TInput;
 }
 
+// `crate::YEAR` deliberately names the year crate invoking the macro, not `advent`.
+#[allow(clippy::crate_in_macro_def)]
 #[macro_export]
 macro_rules! advent_day {
     ($day:ident, $day_id:literal, $input_type:ty) => {
@@ -235,8 +504,16 @@ macro_rules! advent_day {
         type InputType<'a> = $input_type;
         pub type Day = $day;
 
+        #[cfg(feature = "embed-input")]
+        const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+        #[cfg(not(feature = "embed-input"))]
+        const EMBEDDED_INPUT: Option<&'static str> = None;
+
+        pub fn load_input() -> Result<String, $crate::input::InputError> {
+            $crate::input::read_input(crate::YEAR, $day_id, EMBEDDED_INPUT)
+        }
+
         impl AdventInput<$input_type> for $day {
-            const INPUT: &'static str = include_str!("input.txt");
             fn input(&self) -> &$input_type {
                 &self.0
             }
@@ -248,101 +525,179 @@ macro_rules! advent_day {
             const DAY_ID: u8 = $day_id;
         }
 
+        impl $day {
+            /// Loads the day on its own, which leaks its input as there's nowhere to keep it.
+            /// Runners go through `SOLVER.load()` instead.
+            pub fn load() -> Result<Self, $crate::DayError> {
+                Ok(Self::parse(load_input()?.leak())?)
+            }
+        }
+
+        pub const SOLVER: $crate::Solver = $crate::Solver {
+            year: crate::YEAR,
+            day: <$day as DayId>::DAY_ID,
+            load_input,
+            parse: |input| Ok($crate::boxed_day(<$day as DayParser<$day>>::parse(input)?)),
+        };
+
+        #[$crate::ctor::ctor]
+        fn register() {
+            $crate::registry::register(SOLVER);
+        }
+
         impl Default for $day {
             fn default() -> Self {
-                Self::parse(Self::INPUT)
+                Self::load().unwrap_or_else(|error| panic!("Day {}: {}", $day_id, error))
             }
         }
 
-        #[cfg(test)]
-        mod bench {
-            use advent::{AdventDay, AdventInput};
-            extern crate test;
+    };
+    // Days written as free `parse`/`part1`/`part2` functions get a `Day` struct adapting them to
+    // the `AdventDay` traits. `str` can't be stored unsized, so the parsed `&str` is kept instead.
+    // Days with constants to swap out for examples list their `Param`s, and a
+    // `fn(&input, part, &Params) -> Result<Answer, ParamError>` solving with them.
+    ($day:ident, $day_id:literal, $parser:expr, str, $part1_func:ident, $part2_func:ident
+        $(, params = [$($param:expr),+ $(,)?], $part_with_func:ident)?) => {
+        type InputType<'a> = str;
+        $crate::advent_day!(@adapter $day, $day_id, $parser, &'static str, $part1_func, $part2_func
+            $(, params = [$($param),+], $part_with_func)?);
+    };
+    ($day:ident, $day_id:literal, $parser:expr, $input_type:ty, $part1_func:ident, $part2_func:ident
+        $(, params = [$($param:expr),+ $(,)?], $part_with_func:ident)?) => {
+        type InputType<'a> = $input_type;
+        $crate::advent_day!(@adapter $day, $day_id, $parser, InputType<'static>, $part1_func, $part2_func
+            $(, params = [$($param),+], $part_with_func)?);
+    };
+    (@adapter $day:ident, $day_id:literal, $parser:expr, $stored:ty, $part1_func:ident, $part2_func:ident
+        $(, params = [$($param:expr),+], $part_with_func:ident)?) => {
+        pub struct $day($stored);
+        pub type Day = $day;
 
-            #[bench]
-            fn part1_bench(b: &mut test::Bencher) {
-                b.bytes = super::$day::INPUT.len() as u64;
-                let day = super::$day::default();
-                b.iter(|| test::black_box(day.part1()));
+        #[cfg(feature = "embed-input")]
+        const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+        #[cfg(not(feature = "embed-input"))]
+        const EMBEDDED_INPUT: Option<&'static str> = None;
+
+        pub fn load_input() -> Result<String, $crate::input::InputError> {
+            $crate::input::read_input(crate::YEAR, $day_id, EMBEDDED_INPUT)
+        }
+
+        impl DayParser<$day> for $day {
+            fn parse(input: &'static str) -> Result<Self, $crate::ParseError> {
+                Ok(Self($parser(input)?))
             }
+        }
 
-            #[bench]
-            fn part2_bench(b: &mut test::Bencher) {
-                b.bytes = super::$day::INPUT.len() as u64;
-                let day = super::$day::default();
-                b.iter(|| test::black_box(day.part2()));
+        impl AdventInput<$stored> for $day {
+            fn input(&self) -> &$stored {
+                &self.0
             }
         }
-    };
-    ($day:ident, $parser:expr, $input_type:ty, $part1_func:ident, $part2_func:ident) => {
-        pub const INPUT: &'static str = include_str!("input.txt");
-        type InputType<'a> = $input_type;
 
-        pub struct $day;
+        impl AdventDay for $day {
+            fn part1(&self) -> $crate::Answer {
+                $crate::Answer::from($part1_func(&self.0))
+            }
 
-        impl $day {
-            pub fn part1() -> String {
-                $part1_func(&$parser(INPUT)).to_string()
+            fn part2(&self) -> $crate::Answer {
+                $crate::Answer::from($part2_func(&self.0))
+            }
+            $(
+            fn params(&self) -> &'static [$crate::params::Param] {
+                &[$($param),+]
             }
 
-            pub fn part2() -> String {
-                $part2_func(&$parser(INPUT)).to_string()
+            fn part_with(
+                &self,
+                part: u8,
+                params: &$crate::params::Params,
+            ) -> Result<$crate::Answer, $crate::params::ParamError> {
+                $part_with_func(&self.0, part, params)
             }
+            )?
         }
 
-        #[cfg(test)]
-        mod bench {
-            extern crate test;
+        impl DayPrinter for $day {}
 
-            #[bench]
-            fn part1_bench(b: &mut test::Bencher) {
-                b.bytes = super::INPUT.len() as u64;
-                let input = super::parse(super::INPUT);
-                b.iter(|| test::black_box(super::$part1_func(&input)));
+        impl DayId for $day {
+            const DAY_ID: u8 = $day_id;
+        }
+
+        impl $day {
+            /// Loads the day on its own, which leaks its input as there's nowhere to keep it.
+            /// Runners go through `SOLVER.load()` instead.
+            pub fn load() -> Result<Self, $crate::DayError> {
+                Ok(<Self as DayParser<Self>>::parse(load_input()?.leak())?)
             }
+        }
+
+        pub const SOLVER: $crate::Solver = $crate::Solver {
+            year: crate::YEAR,
+            day: <$day as DayId>::DAY_ID,
+            load_input,
+            parse: |input| Ok($crate::boxed_day(<$day as DayParser<$day>>::parse(input)?)),
+        };
+
+        #[$crate::ctor::ctor]
+        fn register() {
+            $crate::registry::register(SOLVER);
+        }
 
-            #[bench]
-            fn part2_bench(b: &mut test::Bencher) {
-                b.bytes = super::INPUT.len() as u64;
-                let input = super::parse(super::INPUT);
-                b.iter(|| test::black_box(super::$part2_func(&input)));
+        impl Default for $day {
+            fn default() -> Self {
+                Self::load().unwrap_or_else(|error| panic!("Day {}: {}", $day_id, error))
             }
         }
+
     };
 }
 
+/// Registers alternative implementations of a day's parts for the bench harness to measure next
+/// to the day's own, with the `cursed` feature on. `$parser` feeds every one of them.
+#[allow(clippy::crate_in_macro_def)]
 #[macro_export]
 macro_rules! advent_bench {
-    ($parser:ident, $module:ident, $part1_func:ident) => {
-        #[cfg(test)]
+    ($parser:ident, $module:ident, $($part_func:ident),+) => {
         mod $module {
-            extern crate test;
-
             #[cfg(feature = "cursed")]
-            #[bench]
-            fn bench(b: &mut test::Bencher) {
-                let input = super::$parser(include_str!("input.txt"));
-                b.iter(|| test::black_box(super::$part1_func(&input)));
+            #[$crate::ctor::ctor]
+            fn register() {
+                $($crate::registry::register_variant($crate::bench::Variant {
+                    year: crate::YEAR,
+                    day: <super::Day as $crate::DayId>::DAY_ID,
+                    name: concat!(stringify!($module), "/", stringify!($part_func)),
+                    load_input: super::load_input,
+                    prepare: |input| {
+                        let input = super::$parser(input)?;
+                        Ok(Box::new(move || {
+                            std::hint::black_box(super::$part_func(&input));
+                        }))
+                    },
+                });)+
             }
         }
     };
-    ($parser:ident, $module:ident, $part1_func:ident, $part2_func:ident) => {
-        #[cfg(test)]
-        mod $module {
-            extern crate test;
-
-            #[cfg(feature = "cursed")]
-            #[bench]
-            fn part1_bench(b: &mut test::Bencher) {
-                let input = super::$parser(include_str!("input.txt"));
-                b.iter(|| test::black_box(super::$part1_func(&input)));
-            }
+}
 
-            #[cfg(feature = "cursed")]
-            #[bench]
-            fn part2_bench(b: &mut test::Bencher) {
-                let input = super::$parser(include_str!("input.txt"));
-                b.iter(|| test::black_box(super::$part1_func(&input)));
+/// Registers functions turning a day's input into frames of its simulation, for `everything
+/// visualize` to play, with the `visualize` feature on. `$parser` feeds every one of them, handing
+/// over what it parsed so the frames can outlive it.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! advent_visualize {
+    ($parser:expr, $($frames_func:ident),+) => {
+        mod visualize_registration {
+            #[cfg(feature = "visualize")]
+            #[$crate::ctor::ctor]
+            fn register() {
+                use super::*;
+                $($crate::registry::register_animation($crate::visualize::Animation {
+                    year: crate::YEAR,
+                    day: <Day as $crate::DayId>::DAY_ID,
+                    name: stringify!($frames_func),
+                    load_input,
+                    frames: |input| Ok(Box::new($frames_func($parser(input)?))),
+                });)+
             }
         }
     };
@@ -374,71 +729,132 @@ where
     }
 }
 
-#[derive(Debug)]
-pub enum PointParseError<Err> {
-    Terminator,
-    InnerValueError(Err),
-}
-
-impl<T> FromStr for Point3D<T>
+impl<T> Point3D<T>
 where
-    T: FromStr,
+    T: num_traits::Signed + Copy,
 {
-    type Err = PointParseError<T::Err>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn parse_value<T, TErr>(
-            value: Option<&str>,
-        ) -> Result<T, PointParseError<<T as FromStr>::Err>>
-        where
-            T: FromStr,
-        {
-            value
-                .ok_or(PointParseError::Terminator)?
-                .trim()
-                .parse::<T>()
-                .map_err(|inner| PointParseError::InnerValueError(inner))
-        }
-
-        let mut values = s.split_terminator(", ");
-        Ok(Point3D::new(
-            parse_value::<_, Self::Err>(values.next())?,
-            parse_value::<_, Self::Err>(values.next())?,
-            parse_value::<_, Self::Err>(values.next())?,
-        ))
+    /// The six points sharing a face with this one, along x, then y, then z.
+    pub fn neighbours6(&self) -> [Self; 6] {
+        let one = T::one();
+        let Self { x, y, z } = *self;
+        [
+            Self::new(x - one, y, z),
+            Self::new(x + one, y, z),
+            Self::new(x, y - one, z),
+            Self::new(x, y + one, z),
+            Self::new(x, y, z - one),
+            Self::new(x, y, z + one),
+        ]
     }
 }
 
-impl<T> ops::Mul<T> for Point3D<T>
+impl<T> Point3D<T>
 where
-    T: num_traits::int::PrimInt,
+    T: num_traits::Unsigned + num_traits::CheckedSub + PartialOrd + Copy,
 {
-    type Output = Point3D<T>;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        Point3D::new(self.x.mul(rhs), self.y.mul(rhs), self.z.mul(rhs))
+    /// The face neighbours within `0..bounds`, along x, then y, then z.
+    ///
+    /// ```rust
+    /// use advent::Point3D;
+    /// let bounds = Point3D::new(2u32, 2, 2);
+    /// assert_eq!(3, Point3D::new(0, 0, 0).neighbours6_checked(&bounds).count());
+    /// assert_eq!(
+    ///     vec![Point3D::new(0, 1, 1), Point3D::new(1, 0, 1), Point3D::new(1, 1, 0)],
+    ///     Point3D::new(1, 1, 1).neighbours6_checked(&bounds).collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn neighbours6_checked(&self, bounds: &Self) -> impl Iterator<Item = Self> {
+        let one = T::one();
+        let below = |value: T| value.checked_sub(&one);
+        let above = |value: T, bound| Some(value + one).filter(|&value| value < bound);
+        let Self { x, y, z } = *self;
+        [
+            below(x).map(|x| Self::new(x, y, z)),
+            above(x, bounds.x).map(|x| Self::new(x, y, z)),
+            below(y).map(|y| Self::new(x, y, z)),
+            above(y, bounds.y).map(|y| Self::new(x, y, z)),
+            below(z).map(|z| Self::new(x, y, z)),
+            above(z, bounds.z).map(|z| Self::new(x, y, z)),
+        ]
+        .into_iter()
+        .flatten()
     }
 }
 
-impl<T> ops::Add<Point3D<T>> for Point3D<T>
+impl<T> Point3D<T>
 where
-    T: num_traits::int::PrimInt,
+    T: ops::Add<Output = T> + ops::Sub<Output = T> + ops::Mul<Output = T> + Copy,
 {
-    type Output = Point3D<T>;
-
-    fn add(self, rhs: Point3D<T>) -> Self::Output {
-        Point3D::new(self.x.add(rhs.x), self.y.add(rhs.y), self.z.add(rhs.z))
+    /// ```rust
+    /// use advent::Point3D;
+    /// assert_eq!(32, Point3D::new(1, 2, 3).dot(&Point3D::new(4, 5, 6)));
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The vector at right angles to both, following the right-hand rule.
+    ///
+    /// ```rust
+    /// use advent::Point3D;
+    /// let x = Point3D::new(1, 0, 0);
+    /// let y = Point3D::new(0, 1, 0);
+    /// assert_eq!(Point3D::new(0, 0, 1), x.cross(&y));
+    /// assert_eq!(Point3D::new(0, 0, -1), y.cross(&x));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
     }
 }
 
-impl<T> ops::Sub<Point3D<T>> for Point3D<T>
+#[derive(Debug, PartialEq, Eq)]
+pub enum PointParseError<Err> {
+    /// Fewer than three values.
+    Terminator,
+    /// More than three values.
+    Trailing,
+    InnerValueError(Err),
+}
+
+/// Reads `x,y,z`, with or without spaces around the commas.
+///
+/// ```rust
+/// use advent::{Point3D, PointParseError};
+/// assert_eq!(Ok(Point3D::new(19, 13, -30)), "19, 13, -30".parse());
+/// assert_eq!(Ok(Point3D::new(1, 0, 1)), "1,0,1".parse());
+/// assert_eq!(Ok(Point3D::new(-2, 1, -2)), " -2,  1, -2".parse());
+/// assert_eq!(Err(PointParseError::Terminator), "1,2".parse::<Point3D<i32>>());
+/// assert_eq!(Err(PointParseError::Trailing), "1,2,3,4".parse::<Point3D<i32>>());
+/// assert!(matches!(
+///     "1,two,3".parse::<Point3D<i32>>(),
+///     Err(PointParseError::InnerValueError(_))
+/// ));
+/// ```
+impl<T> FromStr for Point3D<T>
 where
-    T: num_traits::int::PrimInt,
+    T: FromStr,
 {
-    type Output = Point3D<T>;
+    type Err = PointParseError<T::Err>;
 
-    fn sub(self, rhs: Point3D<T>) -> Self::Output {
-        Point3D::new(self.x.sub(rhs.x), self.y.sub(rhs.y), self.z.sub(rhs.z))
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = s.split(',');
+        let mut next = || {
+            values
+                .next()
+                .ok_or(PointParseError::Terminator)?
+                .trim()
+                .parse::<T>()
+                .map_err(PointParseError::InnerValueError)
+        };
+        let point = Point3D::new(next()?, next()?, next()?);
+        match values.next() {
+            Some(_) => Err(PointParseError::Trailing),
+            None => Ok(point),
+        }
     }
 }
 
@@ -464,37 +880,3 @@ impl<T> PartialEq for MinScore<T> {
         self.0 == other.0
     }
 }
-
-pub trait Decimal {
-    fn digits(self) -> u32;
-    fn shl10(self, digits: u32) -> Self;
-    fn shr10(self, digits: u32) -> Self;
-}
-
-impl Decimal for u32 {
-    fn digits(self) -> u32 {
-        self.ilog10() + 1
-    }
-
-    fn shl10(self, digits: u32) -> Self {
-        self * 10u32.pow(digits)
-    }
-
-    fn shr10(self, digits: u32) -> Self {
-        self / 10u32.pow(digits)
-    }
-}
-
-impl Decimal for u64 {
-    fn digits(self) -> u32 {
-        self.ilog10() + 1
-    }
-
-    fn shl10(self, digits: u32) -> Self {
-        self * 10u64.pow(digits)
-    }
-
-    fn shr10(self, digits: u32) -> Self {
-        self / 10u64.pow(digits)
-    }
-}
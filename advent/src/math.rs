@@ -0,0 +1,205 @@
+use num_traits::{PrimInt, Signed};
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is `0`.
+///
+/// ```rust
+/// use advent::math::gcd;
+/// assert_eq!(6, gcd(12u32, 18));
+/// assert_eq!(6, gcd(-12i64, 18));
+/// assert_eq!(5, gcd(0, 5));
+/// ```
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+    if a < T::zero() {
+        T::zero() - a
+    } else {
+        a
+    }
+}
+
+/// Least common multiple, `0` when either is `0`.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+    let lcm = a / gcd(a, b) * b;
+    if lcm < T::zero() {
+        T::zero() - lcm
+    } else {
+        lcm
+    }
+}
+
+/// The least common multiple of all the values, `1` when there are none: the first time a bunch
+/// of cycles all line up again.
+///
+/// ```rust
+/// use advent::math::lcm_all;
+/// assert_eq!(60, lcm_all([4u64, 6, 10, 15]));
+/// assert_eq!(1, lcm_all(Vec::<u32>::new()));
+/// ```
+pub fn lcm_all<T: PrimInt>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), lcm)
+}
+
+/// Bézout's identity: `(g, x, y)` with `a * x + b * y == g`, `g` being `gcd(a, b)`.
+///
+/// ```rust
+/// use advent::math::extended_gcd;
+/// let (g, x, y) = extended_gcd(240i64, 46);
+/// assert_eq!(2, g);
+/// assert_eq!(g, 240 * x + 46 * y);
+/// ```
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `value` brought into `0..modulus`, whatever its sign.
+fn modulo<T: PrimInt>(value: T, modulus: T) -> T {
+    let remainder = value % modulus;
+    if remainder < T::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if there is one.
+///
+/// ```rust
+/// use advent::math::mod_inverse;
+/// assert_eq!(Some(4), mod_inverse(3i32, 11));
+/// assert_eq!(Some(10), mod_inverse(-1i32, 11));
+/// assert_eq!(None, mod_inverse(6i32, 9));
+/// ```
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a, modulus);
+    g.is_one().then(|| modulo(x, modulus))
+}
+
+/// Chinese remainder theorem: the `x` with `x ≡ residue (mod modulus)` for every pair, as
+/// `(x, modulus)` where `x` is the smallest non-negative answer and every `x + k * modulus` works
+/// too. The moduli don't need to be coprime, but then there may be no answer at all.
+///
+/// ```rust
+/// use advent::math::crt;
+/// assert_eq!(Some((23, 105)), crt([(2i64, 3), (3, 5), (2, 7)]));
+/// // Not coprime, but consistent.
+/// assert_eq!(Some((10, 12)), crt([(2i64, 4), (4, 6)]));
+/// assert_eq!(None, crt([(1i64, 4), (2, 6)]));
+/// ```
+pub fn crt<T: PrimInt + Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::zero(), T::one()), |(x, modulus), (residue, other)| {
+            let (g, p, _) = extended_gcd(modulus, other);
+            let difference = residue - x;
+            if !(difference % g).is_zero() {
+                return None;
+            }
+            let combined = modulus / g * other;
+            // Step `x` by multiples of `modulus` until it matches `residue` too.
+            let steps = modulo(difference / g * p, other / g);
+            Some((modulo(x + modulus * steps, combined), combined))
+        })
+}
+
+/// The largest `r` with `r * r <= n`. Panics on negative numbers.
+///
+/// ```rust
+/// use advent::math::isqrt;
+/// assert_eq!(4, isqrt(24u32));
+/// assert_eq!(5, isqrt(25i64));
+/// assert_eq!(4_294_967_295, isqrt(u64::MAX));
+/// ```
+pub fn isqrt<T: PrimInt>(n: T) -> T {
+    assert!(n >= T::zero(), "square root of a negative number");
+    if n <= T::one() {
+        return n;
+    }
+    // Newton's method from a guess that's certainly too big, which then only ever goes down.
+    let mut root = T::one() << ((T::zero().count_zeros() - n.leading_zeros()).div_ceil(2) as usize);
+    loop {
+        let next = (root + n / root) >> 1;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Working with the decimal digits of an integer.
+///
+/// ```rust
+/// use advent::Decimal;
+/// assert_eq!(4, 2024u32.digits());
+/// assert_eq!(1, 0u8.digits());
+/// assert_eq!(3, (-512i16).digits());
+/// assert_eq!(20_240_000, 2024u64.shl10(4));
+/// assert_eq!(20, 2024u64.shr10(2));
+/// assert_eq!((20, 24), 2024u128.split_at_digit(2));
+/// assert_eq!((10, 0), 1000u64.split_at_digit(2));
+/// assert_eq!(156, 15u32.concat(6));
+/// assert_eq!(1000, 10u32.concat(0).concat(0));
+/// ```
+pub trait Decimal: Sized {
+    /// How many digits it takes to write, ignoring any sign.
+    fn digits(self) -> u32;
+    /// Appends `digits` zeroes.
+    fn shl10(self, digits: u32) -> Self;
+    /// Drops the last `digits` digits.
+    fn shr10(self, digits: u32) -> Self;
+    /// Splits off the last `digits` digits, giving `(everything before, those digits)`.
+    fn split_at_digit(self, digits: u32) -> (Self, Self);
+    /// Writes `other` after this number, like `15 || 6` is `156`.
+    fn concat(self, other: Self) -> Self;
+}
+
+macro_rules! decimal {
+    ($($int:ty),+; $($signed:ty),+) => {
+        decimal!(@impl $($int => |value: $int| value),+);
+        decimal!(@impl $($signed => |value: $signed| value.unsigned_abs()),+);
+    };
+    (@impl $($int:ty => $magnitude:expr),+) => {$(
+        impl Decimal for $int {
+            fn digits(self) -> u32 {
+                ($magnitude)(self).checked_ilog10().map_or(1, |log| log + 1)
+            }
+
+            fn shl10(self, digits: u32) -> Self {
+                self * (10 as $int).pow(digits)
+            }
+
+            fn shr10(self, digits: u32) -> Self {
+                self / (10 as $int).pow(digits)
+            }
+
+            fn split_at_digit(self, digits: u32) -> (Self, Self) {
+                let power = (10 as $int).pow(digits);
+                (self / power, self % power)
+            }
+
+            fn concat(self, other: Self) -> Self {
+                self.shl10(other.digits()) + other
+            }
+        }
+    )+};
+}
+
+decimal!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,186 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::Answer;
+
+/// A constant a day solves real inputs with, like how many steps to walk, that can be swapped out
+/// for the one a puzzle's example uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// What real inputs use.
+    pub default: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: &'static str) -> Self {
+        Self { name, default }
+    }
+}
+
+/// Values for some of a day's [`Param`]s, by name. The rest keep their defaults.
+///
+/// ```rust
+/// use advent::params::{Param, ParamError, Params};
+/// const STEPS: Param = Param::new("steps", "64");
+/// const WIDTH: Param = Param::new("width", "71");
+///
+/// let params = "steps=6".parse::<Params>().unwrap();
+/// assert_eq!(Ok(6), params.get::<u32>(&STEPS));
+/// assert_eq!(Ok(71), params.get::<u32>(&WIDTH));
+/// assert!(params.check(&[STEPS, WIDTH]).is_ok());
+/// assert!(params.check(&[WIDTH]).is_err());
+///
+/// let params = "steps=six".parse::<Params>().unwrap();
+/// assert_eq!(
+///     Err(ParamError::Invalid { name: "steps", value: "six".into() }),
+///     params.get::<u32>(&STEPS)
+/// );
+/// assert!("steps".parse::<Params>().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Sets a param from `name=value`.
+    pub fn set_assignment(&mut self, assignment: &str) -> Result<(), ParamError> {
+        match assignment.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                self.set(name, value);
+                Ok(())
+            }
+            _ => Err(ParamError::Malformed(assignment.to_owned())),
+        }
+    }
+
+    /// The value given for `param`, or failing that its default.
+    pub fn get<T: FromStr>(&self, param: &Param) -> Result<T, ParamError> {
+        let value = self.0.get(param.name).map_or(param.default, String::as_str);
+        value.parse().map_err(|_| ParamError::Invalid {
+            name: param.name,
+            value: value.to_owned(),
+        })
+    }
+
+    /// Turns away values for anything that isn't one of `params`, which would otherwise go
+    /// unnoticed.
+    pub fn check(&self, params: &[Param]) -> Result<(), ParamError> {
+        match self
+            .0
+            .keys()
+            .find(|name| params.iter().all(|param| param.name != name.as_str()))
+        {
+            Some(name) => Err(ParamError::Unknown {
+                name: name.clone(),
+                known: params.iter().map(|param| param.name).collect(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Solves `part` with every param at its default, the way real inputs are solved. Days with params
+/// write their `part1` and `part2` with this, so each default is only written down in its
+/// [`Param`]. Like any part given an input it can't solve, it panics if the defaults don't suit.
+///
+/// ```rust
+/// use advent::{params::{self, Param, ParamError, Params}, Answer};
+/// const STEPS: Param = Param::new("steps", "64");
+/// fn part_with(start: &u32, _part: u8, params: &Params) -> Result<Answer, ParamError> {
+///     Ok((start + params.get::<u32>(&STEPS)?).into())
+/// }
+/// assert_eq!(Answer::from(65), params::with_defaults(part_with, &1, 1));
+/// ```
+pub fn with_defaults<I: ?Sized>(
+    part_with: impl Fn(&I, u8, &Params) -> Result<Answer, ParamError>,
+    input: &I,
+    part: u8,
+) -> Answer {
+    part_with(input, part, &Params::default()).unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// `name=value`, as `--param` takes it.
+impl FromStr for Params {
+    type Err = ParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Self::default();
+        params.set_assignment(s)?;
+        Ok(params)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// Not `name=value`.
+    Malformed(String),
+    Unknown {
+        name: String,
+        known: Vec<&'static str>,
+    },
+    Invalid {
+        name: &'static str,
+        value: String,
+    },
+    /// Parses, but doesn't work with the input or the puzzle.
+    Unsuitable {
+        name: &'static str,
+        value: String,
+        reason: String,
+    },
+    /// Each param works on its own, but together with the input they leave the part without an
+    /// answer.
+    NoAnswer(String),
+}
+
+impl ParamError {
+    /// `param` can't be `value` for `reason`.
+    ///
+    /// ```rust
+    /// use advent::params::{Param, ParamError};
+    /// const WIDTH: Param = Param::new("width", "101");
+    /// assert_eq!(
+    ///     "`width` can't be `11`: the tree is 31 robots wide",
+    ///     ParamError::unsuitable(&WIDTH, 11, "the tree is 31 robots wide").to_string()
+    /// );
+    /// ```
+    pub fn unsuitable(param: &Param, value: impl ToString, reason: impl Into<String>) -> Self {
+        Self::Unsuitable {
+            name: param.name,
+            value: value.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(assignment) => write!(f, "`{assignment}` is not `<name>=<value>`"),
+            Self::Unknown { name, known } if known.is_empty() => {
+                write!(f, "`{name}` is not a parameter, this day takes none")
+            }
+            Self::Unknown { name, known } => write!(
+                f,
+                "`{name}` is not a parameter, this day takes `{}`",
+                known.join("`, `")
+            ),
+            Self::Invalid { name, value } => write!(f, "`{value}` is not a valid `{name}`"),
+            Self::Unsuitable {
+                name,
+                value,
+                reason,
+            } => write!(f, "`{name}` can't be `{value}`: {reason}"),
+            Self::NoAnswer(reason) => write!(f, "no answer with these parameters: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
@@ -0,0 +1,362 @@
+use std::{any, borrow::Cow, fmt, iter, str::FromStr};
+
+use crate::input::InputError;
+
+/// Where and why a puzzle input stopped making sense. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: Cow<'static, str>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        found: impl Into<String>,
+        expected: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            found: found.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Blames `found`, which has to be a slice of `input`, working out its position from where
+    /// it sits in memory. Debug builds panic on anything else, rather than blame the first line.
+    ///
+    /// ```rust
+    /// use advent::parse::ParseError;
+    /// let input = "1 2\n3 x 5";
+    /// let error = ParseError::at(input, &input[6..7], "a number");
+    /// assert_eq!((2, 3), (error.line, error.column));
+    /// assert_eq!("line 2, column 3: expected a number, found `x`", error.to_string());
+    /// ```
+    pub fn at(input: &str, found: &str, expected: impl Into<Cow<'static, str>>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + found.len() <= input.len());
+        debug_assert!(offset.is_some(), "`{found}` is not a slice of the input");
+        let (line, column) = position(input, offset.unwrap_or(0));
+        Self::new(line, column, found, expected)
+    }
+
+    /// The input ran out before `expected` turned up.
+    ///
+    /// ```rust
+    /// use advent::parse::ParseError;
+    /// let error = ParseError::end_of_input("1 2\n3", "a blank line");
+    /// assert_eq!((2, 2), (error.line, error.column));
+    /// assert_eq!("line 2, column 2: expected a blank line, found end of input", error.to_string());
+    /// ```
+    pub fn end_of_input(input: &str, expected: impl Into<Cow<'static, str>>) -> Self {
+        let (line, column) = position(input, input.len());
+        Self::new(line, column, "", expected)
+    }
+}
+
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        match self.found.lines().next() {
+            None | Some("") => write!(f, "end of input"),
+            Some(found) if found.chars().count() > 40 => {
+                write!(f, "`{}...`", found.chars().take(40).collect::<String>())
+            }
+            Some(found) => write!(f, "`{found}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, naming the target type in the error if it doesn't fit.
+///
+/// ```rust
+/// use advent::parse::token;
+/// let input = "12,-4";
+/// assert_eq!(Ok(12u8), token(input, &input[..2]));
+/// assert_eq!(
+///     "line 1, column 4: expected u8, found `-4`",
+///     token::<u8>(input, &input[3..]).unwrap_err().to_string()
+/// );
+/// ```
+pub fn token<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token.parse::<T>().map_err(|_| {
+        let name = any::type_name::<T>();
+        ParseError::at(input, token, name.rsplit("::").next().unwrap_or(name))
+    })
+}
+
+/// `str::split_once` that reports the text it couldn't split.
+///
+/// ```rust
+/// use advent::parse::split_once;
+/// let input = "a-b\ncd";
+/// assert_eq!(Ok(("a", "b")), split_once(input, &input[..3], "-"));
+/// assert_eq!(
+///     "line 2, column 1: expected `-`, found `cd`",
+///     split_once(input, &input[4..], "-").unwrap_err().to_string()
+/// );
+/// ```
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, format!("`{delimiter}`")))
+}
+
+/// Runs `parse` over every line of `text` that isn't blank.
+///
+/// ```rust
+/// use advent::parse::{lines, token};
+/// let input = "3\n\n4\r\n5\n";
+/// assert_eq!(Ok(vec![3, 4, 5]), lines(input, |line| token::<u8>(input, line)));
+/// let error = lines(input, |line| token::<i8>(input, line.trim_end_matches('5'))).unwrap_err();
+/// assert_eq!((4, 1), (error.line, error.column));
+/// ```
+pub fn lines<'a, T>(
+    text: &'a str,
+    parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse)
+        .collect()
+}
+
+/// The runs of lines between blank lines, each as one slice without its surrounding newlines.
+///
+/// ```rust
+/// use advent::parse::blocks;
+/// let input = "1000\n2000\n\n4000\r\n\r\n\n5000\n6000\n";
+/// assert_eq!(vec!["1000\n2000", "4000", "5000\n6000"], blocks(input).collect::<Vec<_>>());
+/// ```
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        let block = &rest[start?..end];
+        rest = &rest[offset..];
+        Some(block)
+    })
+}
+
+/// Slices of `text` that look like integers. A `-` counts as a sign when `signed` and it's not
+/// stuck to the end of another word, so `1-3` is two numbers either way.
+fn integer_tokens(text: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+    std::iter::from_fn(move || {
+        while index < bytes.len() {
+            let start = index;
+            let sign = signed
+                && bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+            if sign {
+                index += 1;
+            }
+            if bytes[index].is_ascii_digit() {
+                while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+                    index += 1;
+                }
+                return Some(&text[start..index]);
+            }
+            index += 1;
+        }
+        None
+    })
+}
+
+/// Every integer in `text`, ignoring whatever is between them, `-` signs included.
+///
+/// ```rust
+/// use advent::parse::integers;
+/// let input = "p=0,4 v=3,-3";
+/// assert_eq!(Ok(vec![0, 4, 3, -3]), integers::<i32>(input, input));
+/// assert_eq!(Ok(vec![1, 3]), integers::<i32>("1-3 a", "1-3 a"));
+/// assert_eq!(
+///     "line 1, column 11: expected u8, found `-3`",
+///     integers::<u8>(input, input).unwrap_err().to_string()
+/// );
+/// ```
+pub fn integers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    integer_tokens(text, true)
+        .map(|integer| token(input, integer))
+        .collect()
+}
+
+/// Every run of digits in `text`, with anything else, `-` included, as a separator.
+///
+/// ```rust
+/// use advent::parse::unsigned_integers;
+/// let input = "Game 12: 3-4";
+/// assert_eq!(Ok(vec![12u32, 3, 4]), unsigned_integers(input, input));
+/// ```
+pub fn unsigned_integers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    integer_tokens(text, false)
+        .map(|integer| token(input, integer))
+        .collect()
+}
+
+/// Exactly `N` integers out of `text`, `-` signs included, for lines with a fixed shape.
+///
+/// ```rust
+/// use advent::parse::integer_array;
+/// let input = "p=6,3 v=-1,-3\nx=1";
+/// assert_eq!(Ok([6, 3, -1, -3]), integer_array::<i32, 4>(input, &input[..13]));
+/// assert_eq!(
+///     "line 2, column 1: expected 4 integers, found `x=1`",
+///     integer_array::<i32, 4>(input, &input[14..]).unwrap_err().to_string()
+/// );
+/// ```
+pub fn integer_array<T: FromStr, const N: usize>(
+    input: &str,
+    text: &str,
+) -> Result<[T; N], ParseError> {
+    integers(input, text)?
+        .try_into()
+        .map_err(|_| ParseError::at(input, text, format!("{N} integers")))
+}
+
+/// The lines of `text` that aren't blank, split around `delimiter` with the spaces trimmed off
+/// both sides.
+///
+/// ```rust
+/// use advent::parse::key_values;
+/// let input = "x00: 1\ny00 0";
+/// assert_eq!(Ok(vec![("x00", "1")]), key_values(input, &input[..6], ":"));
+/// assert_eq!(
+///     "line 2, column 1: expected `:`, found `y00 0`",
+///     key_values(input, input, ":").unwrap_err().to_string()
+/// );
+/// ```
+pub fn key_values<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &'static str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    lines(text, |line| {
+        let (key, value) = split_once(input, line, delimiter)?;
+        Ok((key.trim(), value.trim()))
+    })
+}
+
+/// Blocks that start with a heading ending in `:`, as the heading and everything after its
+/// colon, the rest of the block included.
+///
+/// ```rust
+/// use advent::parse::sections;
+/// let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nnope";
+/// let sections = sections(input, &input[..input.len() - 6]).unwrap();
+/// assert_eq!(vec![("seeds", "79 14"), ("seed-to-soil map", "50 98 2\n52 50 48")], sections);
+/// assert_eq!(
+///     "line 7, column 1: expected `:`, found `nope`",
+///     advent::parse::sections(input, input).unwrap_err().to_string()
+/// );
+/// ```
+pub fn sections<'a>(input: &str, text: &'a str) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    blocks(text)
+        .map(|block| {
+            let (heading, body) = split_once(input, block, ":")?;
+            Ok((heading.trim(), body.trim()))
+        })
+        .collect()
+}
+
+/// Cuts a line into columns `width` characters wide, the last one maybe narrower, for inputs that
+/// line things up with spaces rather than separate them.
+///
+/// ```rust
+/// use advent::parse::columns;
+/// assert_eq!(vec!["[Z] ", "[M] ", "[P]"], columns("[Z] [M] [P]", 4).collect::<Vec<_>>());
+/// assert_eq!(vec!["123", " 45"], columns("123 45", 3).collect::<Vec<_>>());
+/// assert_eq!(vec!["→1 ", "←2"], columns("→1 ←2", 3).collect::<Vec<_>>());
+/// ```
+pub fn columns(line: &str, width: usize) -> impl Iterator<Item = &str> {
+    assert!(width > 0, "columns need a width");
+    let mut rest = line;
+    iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(index, _)| index);
+        let (column, after) = rest.split_at(end);
+        rest = after;
+        Some(column)
+    })
+}
+
+/// Everything that can go wrong before a day gets to solve anything.
+#[derive(Debug)]
+pub enum DayError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(error) => error.fmt(f),
+            Self::Parse(error) => write!(f, "invalid input at {error}"),
+        }
+    }
+}
+
+impl std::error::Error for DayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Input(error) => Some(error),
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<InputError> for DayError {
+    fn from(error: InputError) -> Self {
+        Self::Input(error)
+    }
+}
+
+impl From<ParseError> for DayError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
@@ -0,0 +1,402 @@
+//! Vector algebra shared by [`Point`] and [`Point3D`].
+//!
+//! The operators behave like they do on the coordinates: they panic on overflow in debug builds
+//! and wrap in release ones. Reach for the `checked_*` and `wrapping_*` methods when overflow is
+//! a real possibility, like stepping off the top-left corner of a `UPoint` grid.
+//!
+//! ```rust
+//! use advent::{IPoint, Point, UPoint};
+//! let robot = IPoint::new(2, 4);
+//! let velocity = IPoint::new(2, -3);
+//! assert_eq!(IPoint::new(1, 3), (robot + velocity * 5).rem_euclid(&IPoint::new(11, 7)));
+//! assert_eq!(IPoint::new(-2, 3), -velocity + IPoint::new(0, 0));
+//! assert_eq!(None, UPoint::new(0, 3).checked_sub(&UPoint::new(1, 0)));
+//!
+//! // `UPoint`, `IPoint` and `Point<usize>` convert between each other when the values fit.
+//! let point = UPoint::try_from(IPoint::new(3, 4)).unwrap();
+//! assert_eq!(Point::new(3usize, 4), point.try_into().unwrap());
+//! assert!(UPoint::try_from(IPoint::new(-1, 4)).is_err());
+//! assert!(IPoint::try_from(UPoint::new(u32::MAX, 0)).is_err());
+//! ```
+
+use std::ops;
+
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, Euclid, WrappingAdd, WrappingMul, WrappingSub,
+};
+
+use crate::{Point, Point3D};
+
+/// How far apart two numbers are, as the unsigned type that always fits the answer.
+pub trait AbsDiff: Copy {
+    type Output: Copy
+        + PartialOrd
+        + ops::Add<Output = Self::Output>
+        + ops::Mul<Output = Self::Output>;
+    fn abs_diff(self, other: Self) -> Self::Output;
+}
+
+macro_rules! abs_diff_int {
+    ($($int:ty => $unsigned:ty),+) => {$(
+        impl AbsDiff for $int {
+            type Output = $unsigned;
+
+            fn abs_diff(self, other: Self) -> Self::Output {
+                <$int>::abs_diff(self, other)
+            }
+        }
+    )+};
+}
+
+abs_diff_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+macro_rules! abs_diff_float {
+    ($($float:ty),+) => {$(
+        impl AbsDiff for $float {
+            type Output = $float;
+
+            fn abs_diff(self, other: Self) -> Self::Output {
+                (self - other).abs()
+            }
+        }
+    )+};
+}
+
+abs_diff_float!(f32, f64);
+
+/// Taxicab distance: the number of orthogonal steps between two points.
+///
+/// ```rust
+/// use advent::{IPoint, Manhattan, Point3D, UPoint};
+/// assert_eq!(7, UPoint::new(1, 5).manhattan(&UPoint::new(4, 1)));
+/// assert_eq!(7u32, IPoint::new(-1, 2).manhattan(&IPoint::new(2, -2)));
+/// assert_eq!(6, Point3D::new(1i64, 1, 1).manhattan(&Point3D::new(-1, 2, 4)));
+/// ```
+pub trait Manhattan {
+    type Output;
+    fn manhattan(&self, other: &Self) -> Self::Output;
+}
+
+/// Chessboard distance: the number of steps between two points when diagonal steps are allowed.
+///
+/// ```rust
+/// use advent::{Chebyshev, IPoint, Point};
+/// assert_eq!(4u32, IPoint::new(-1, 2).chebyshev(&IPoint::new(2, -2)));
+/// assert_eq!(0, Point::new(3usize, 3).chebyshev(&Point::new(3, 3)));
+/// ```
+pub trait Chebyshev {
+    type Output;
+    fn chebyshev(&self, other: &Self) -> Self::Output;
+}
+
+/// The square of the straight-line distance, which stays exact for integer points and orders
+/// them the same way the distance would.
+///
+/// ```rust
+/// use advent::{Point3D, SquaredEuclidean, UPoint};
+/// assert_eq!(25, UPoint::new(0, 4).squared_euclidean(&UPoint::new(3, 0)));
+/// assert_eq!(9u64, Point3D::new(1i64, -1, 0).squared_euclidean(&Point3D::new(-1, 1, 1)));
+/// ```
+pub trait SquaredEuclidean {
+    type Output;
+    fn squared_euclidean(&self, other: &Self) -> Self::Output;
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+macro_rules! vector {
+    ($point:ident { $first:ident $(, $field:ident)* }, $len:literal) => {
+        impl<T: ops::Add<Output = T>> ops::Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $point {
+                    $first: self.$first + rhs.$first,
+                    $($field: self.$field + rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: ops::Sub<Output = T>> ops::Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $point {
+                    $first: self.$first - rhs.$first,
+                    $($field: self.$field - rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: ops::Neg<Output = T>> ops::Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $point {
+                    $first: -self.$first,
+                    $($field: -self.$field,)*
+                }
+            }
+        }
+
+        impl<T: ops::Mul<Output = T> + Copy> ops::Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $point {
+                    $first: self.$first * rhs,
+                    $($field: self.$field * rhs,)*
+                }
+            }
+        }
+
+        impl<T: ops::Div<Output = T> + Copy> ops::Div<T> for $point<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $point {
+                    $first: self.$first / rhs,
+                    $($field: self.$field / rhs,)*
+                }
+            }
+        }
+
+        /// Truncated remainder of every coordinate, negative for negative coordinates like `%`
+        /// is. Wrap-around worlds want [`rem_euclid`](Self::rem_euclid) instead.
+        impl<T: ops::Rem<Output = T> + Copy> ops::Rem<T> for $point<T> {
+            type Output = Self;
+
+            fn rem(self, rhs: T) -> Self::Output {
+                $point {
+                    $first: self.$first % rhs,
+                    $($field: self.$field % rhs,)*
+                }
+            }
+        }
+
+        impl<T: ops::AddAssign> ops::AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                self.$first += rhs.$first;
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: ops::SubAssign> ops::SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.$first -= rhs.$first;
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: ops::MulAssign + Copy> ops::MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                self.$first *= rhs;
+                $(self.$field *= rhs;)*
+            }
+        }
+
+        impl<T> $point<T> {
+            /// `None` when any coordinate overflows.
+            pub fn checked_add(&self, rhs: &Self) -> Option<Self>
+            where
+                T: CheckedAdd,
+            {
+                Some($point {
+                    $first: self.$first.checked_add(&rhs.$first)?,
+                    $($field: self.$field.checked_add(&rhs.$field)?,)*
+                })
+            }
+
+            /// `None` when any coordinate overflows, like a `UPoint` going below zero.
+            pub fn checked_sub(&self, rhs: &Self) -> Option<Self>
+            where
+                T: CheckedSub,
+            {
+                Some($point {
+                    $first: self.$first.checked_sub(&rhs.$first)?,
+                    $($field: self.$field.checked_sub(&rhs.$field)?,)*
+                })
+            }
+
+            /// Scales every coordinate by `rhs`, `None` when any of them overflows.
+            pub fn checked_mul(&self, rhs: T) -> Option<Self>
+            where
+                T: CheckedMul,
+            {
+                Some($point {
+                    $first: self.$first.checked_mul(&rhs)?,
+                    $($field: self.$field.checked_mul(&rhs)?,)*
+                })
+            }
+
+            pub fn wrapping_add(&self, rhs: &Self) -> Self
+            where
+                T: WrappingAdd,
+            {
+                $point {
+                    $first: self.$first.wrapping_add(&rhs.$first),
+                    $($field: self.$field.wrapping_add(&rhs.$field),)*
+                }
+            }
+
+            pub fn wrapping_sub(&self, rhs: &Self) -> Self
+            where
+                T: WrappingSub,
+            {
+                $point {
+                    $first: self.$first.wrapping_sub(&rhs.$first),
+                    $($field: self.$field.wrapping_sub(&rhs.$field),)*
+                }
+            }
+
+            pub fn wrapping_mul(&self, rhs: T) -> Self
+            where
+                T: WrappingMul,
+            {
+                $point {
+                    $first: self.$first.wrapping_mul(&rhs),
+                    $($field: self.$field.wrapping_mul(&rhs),)*
+                }
+            }
+
+            /// Folds every coordinate into `0..bounds`, for worlds that wrap around at their
+            /// edges.
+            pub fn rem_euclid(&self, bounds: &Self) -> Self
+            where
+                T: Euclid,
+            {
+                $point {
+                    $first: self.$first.rem_euclid(&bounds.$first),
+                    $($field: self.$field.rem_euclid(&bounds.$field),)*
+                }
+            }
+
+            /// Converts every coordinate losslessly, like `UPoint` into `Point<u64>`.
+            pub fn cast<U: From<T>>(self) -> $point<U> {
+                $point {
+                    $first: self.$first.into(),
+                    $($field: self.$field.into(),)*
+                }
+            }
+
+            /// Converts every coordinate, `None` when any of them doesn't fit `U`.
+            pub fn try_cast<U: TryFrom<T>>(self) -> Option<$point<U>> {
+                Some($point {
+                    $first: U::try_from(self.$first).ok()?,
+                    $($field: U::try_from(self.$field).ok()?,)*
+                })
+            }
+        }
+
+        impl<T: AbsDiff> Manhattan for $point<T> {
+            type Output = T::Output;
+
+            fn manhattan(&self, other: &Self) -> Self::Output {
+                self.$first.abs_diff(other.$first) $(+ self.$field.abs_diff(other.$field))*
+            }
+        }
+
+        impl<T: AbsDiff> SquaredEuclidean for $point<T> {
+            type Output = T::Output;
+
+            fn squared_euclidean(&self, other: &Self) -> Self::Output {
+                let square = |distance: T::Output| distance * distance;
+                square(self.$first.abs_diff(other.$first))
+                    $(+ square(self.$field.abs_diff(other.$field)))*
+            }
+        }
+
+        impl<T> From<[T; $len]> for $point<T> {
+            fn from([$first $(, $field)*]: [T; $len]) -> Self {
+                $point { $first $(, $field)* }
+            }
+        }
+
+        impl<T> From<$point<T>> for [T; $len] {
+            fn from(point: $point<T>) -> Self {
+                [point.$first $(, point.$field)*]
+            }
+        }
+
+        impl<T: AbsDiff> Chebyshev for $point<T> {
+            type Output = T::Output;
+
+            fn chebyshev(&self, other: &Self) -> Self::Output {
+                let distance = self.$first.abs_diff(other.$first);
+                $(let distance = max(distance, self.$field.abs_diff(other.$field));)*
+                distance
+            }
+        }
+    };
+}
+
+vector!(Point { x, y }, 2);
+vector!(Point3D { x, y, z }, 3);
+
+/// Conversions to and from nalgebra's vectors and points, with the `nalgebra` feature on.
+///
+/// ```rust
+/// use advent::Point3D;
+/// use nalgebra::Vector3;
+/// let velocity = Point3D::new(1.0, -2.0, 0.5);
+/// assert_eq!(Vector3::new(1.0, -2.0, 0.5), velocity.into());
+/// assert_eq!(velocity, Point3D::from(Vector3::new(1.0, -2.0, 0.5)));
+/// ```
+#[cfg(feature = "nalgebra")]
+mod linear_algebra {
+    use nalgebra::{Point2, Point3, Scalar, Vector2, Vector3};
+
+    use crate::{Point, Point3D};
+
+    macro_rules! nalgebra_conversions {
+        ($($other:ident => $point:ident { $($field:ident),+ });+) => {$(
+            impl<T: Scalar> From<$point<T>> for $other<T> {
+                fn from(point: $point<T>) -> Self {
+                    $other::new($(point.$field),+)
+                }
+            }
+
+            impl<T: Scalar> From<$other<T>> for $point<T> {
+                fn from(other: $other<T>) -> Self {
+                    $point {
+                        $($field: other.$field.clone()),+
+                    }
+                }
+            }
+        )+};
+    }
+
+    nalgebra_conversions!(
+        Vector2 => Point { x, y };
+        Point2 => Point { x, y };
+        Vector3 => Point3D { x, y, z };
+        Point3 => Point3D { x, y, z }
+    );
+}
+
+// `TryFrom` between the point types days tend to mix: `UPoint`, `IPoint` and `Point<usize>`.
+macro_rules! point_conversions {
+    ($($from:ty => $($to:ty),+);+) => {$($(
+        impl TryFrom<Point<$from>> for Point<$to> {
+            type Error = std::num::TryFromIntError;
+
+            fn try_from(point: Point<$from>) -> Result<Self, Self::Error> {
+                Ok(Point {
+                    x: point.x.try_into()?,
+                    y: point.y.try_into()?,
+                })
+            }
+        }
+    )+)+};
+}
+
+point_conversions!(u32 => i32, usize; i32 => u32, usize; usize => u32, i32);
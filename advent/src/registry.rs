@@ -0,0 +1,70 @@
+use std::sync::Mutex;
+
+use crate::{bench::Variant, visualize::Animation, Solver};
+
+static SOLVERS: Mutex<Vec<Solver>> = Mutex::new(Vec::new());
+static VARIANTS: Mutex<Vec<Variant>> = Mutex::new(Vec::new());
+static ANIMATIONS: Mutex<Vec<Animation>> = Mutex::new(Vec::new());
+
+/// `advent_day!` registers every day it declares before `main` runs, so runners can look days up
+/// here instead of keeping their own lists.
+pub fn register(solver: Solver) {
+    SOLVERS.lock().unwrap().push(solver);
+}
+
+/// `advent_bench!` registers its variants, with the `cursed` feature on.
+pub fn register_variant(variant: Variant) {
+    VARIANTS.lock().unwrap().push(variant);
+}
+
+/// `advent_visualize!` registers its animations, with the `visualize` feature on.
+pub fn register_animation(animation: Animation) {
+    ANIMATIONS.lock().unwrap().push(animation);
+}
+
+/// All registered days, ordered by year and day.
+pub fn solvers() -> Vec<Solver> {
+    let mut solvers = SOLVERS.lock().unwrap().clone();
+    solvers.sort_by_key(|solver| (solver.year, solver.day));
+    solvers
+}
+
+pub fn solver(year: u16, day: u8) -> Option<Solver> {
+    SOLVERS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|solver| solver.year == year && solver.day == day)
+        .copied()
+}
+
+/// The registered days of `year`, in order.
+pub fn year(year: u16) -> Vec<Solver> {
+    let mut solvers = solvers();
+    solvers.retain(|solver| solver.year == year);
+    solvers
+}
+
+/// Years with at least one registered day, in order.
+pub fn years() -> Vec<u16> {
+    let mut years = solvers()
+        .iter()
+        .map(|solver| solver.year)
+        .collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// Registered variants, ordered by year and day.
+pub fn variants() -> Vec<Variant> {
+    let mut variants = VARIANTS.lock().unwrap().clone();
+    variants.sort_by_key(|variant| (variant.year, variant.day));
+    variants
+}
+
+/// Registered animations, ordered by year and day.
+pub fn animations() -> Vec<Animation> {
+    let mut animations = ANIMATIONS.lock().unwrap().clone();
+    animations.sort_by_key(|animation| (animation.year, animation.day));
+    animations
+}
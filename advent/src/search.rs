@@ -0,0 +1,336 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use num_traits::Zero;
+
+use crate::trace;
+
+/// The cheapest way to a goal, from the start to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Every way to reach a goal as cheaply as possible.
+#[derive(Debug, Clone)]
+pub struct AllPaths<S, C> {
+    pub cost: C,
+    /// The goals reached at `cost`.
+    pub goals: Vec<S>,
+    /// Every state reached on the way to a goal, with each state it's reached from at best cost.
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    /// Every state on at least one of the cheapest paths.
+    pub fn states(&self) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut pending = self.goals.clone();
+        while let Some(state) = pending.pop() {
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                if states.insert(state) {
+                    pending.extend(predecessors.iter().cloned());
+                }
+            }
+        }
+        states
+    }
+
+    /// Spells out each of the cheapest paths, from the start to a goal. There can be a lot of
+    /// them; [`states`](Self::states) is cheaper when only the states matter.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = vec![];
+        let mut pending = self
+            .goals
+            .iter()
+            .map(|goal| vec![goal.clone()])
+            .collect::<Vec<_>>();
+        while let Some(path) = pending.pop() {
+            let last = path.last().expect("paths are never empty");
+            match self.predecessors.get(last).map(Vec::as_slice) {
+                Some([]) | None => {
+                    paths.push(path.into_iter().rev().collect());
+                }
+                Some(predecessors) => {
+                    for predecessor in predecessors {
+                        let mut path = path.clone();
+                        path.push(predecessor.clone());
+                        pending.push(path);
+                    }
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// A queued state, ordered so the `BinaryHeap` pops the lowest estimate first.
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+fn reconstruct<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(Some(parent)) = parents.get(states.last().expect("starts with the goal")) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+/// Dijkstra's algorithm: the cheapest path from `start` to any state `is_goal` accepts, where
+/// `successors` lists the states one step away along with what the step costs.
+///
+/// ```rust
+/// use advent::search::dijkstra;
+/// // Getting from 1 to 10 by adding one (cost 1) or tripling (cost 5).
+/// let path = dijkstra(1, |&n| [(n + 1, 1), (n * 3, 5)], |&n| n == 10).unwrap();
+/// assert_eq!(8, path.cost);
+/// assert_eq!(vec![1, 2, 3, 9, 10], path.states);
+/// ```
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A*: like [`dijkstra`], guided towards the goal by `heuristic`. The path is only guaranteed to
+/// be the cheapest when `heuristic` never overestimates the remaining cost.
+///
+/// ```rust
+/// use advent::{search::astar, IPoint};
+/// let goal = IPoint::new(3, 2);
+/// let path = astar(
+///     IPoint::origin(),
+///     |point| [point.right(), point.down()].map(|next| (next, 1)),
+///     |point| point.x.abs_diff(goal.x) + point.y.abs_diff(goal.y),
+///     |&point| point == goal,
+/// )
+/// .unwrap();
+/// assert_eq!(5, path.cost);
+/// assert_eq!(6, path.states.len());
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best = HashMap::from([(start.clone(), (C::zero(), None))]);
+    let mut pending = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: C::zero(),
+        state: start,
+    }]);
+    let mut expanded = 0;
+
+    while let Some(Queued { cost, state, .. }) = pending.pop() {
+        if best.get(&state).is_some_and(|&(best, _)| cost > best) {
+            // Reached more cheaply since it was queued.
+            continue;
+        }
+        if is_goal(&state) {
+            trace::count("states expanded", expanded);
+            let parents = best
+                .into_iter()
+                .map(|(state, (_, parent))| (state, parent))
+                .collect();
+            return Some(Path {
+                cost,
+                states: reconstruct(&parents, state),
+            });
+        }
+        expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match best.entry(next.clone()) {
+                Entry::Occupied(entry) if entry.get().0 <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((next_cost, Some(state.clone())));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, Some(state.clone())));
+                }
+            }
+            pending.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    trace::count("states expanded", expanded);
+    None
+}
+
+/// Like [`dijkstra`], but keeps track of every cheapest path instead of settling for one.
+///
+/// ```rust
+/// use advent::{search::dijkstra_all, UPoint};
+/// // Both ways around a square, from one corner to the opposite one.
+/// let paths = dijkstra_all(
+///     UPoint::new(0, 0),
+///     |point| {
+///         [point.east_checked(&UPoint::new(2, 2)), point.south_checked(&UPoint::new(2, 2))]
+///             .into_iter()
+///             .flatten()
+///             .map(|next| (next, 1))
+///     },
+///     |&point| point == UPoint::new(1, 1),
+/// )
+/// .unwrap();
+/// assert_eq!(2, paths.cost);
+/// assert_eq!(4, paths.states().len());
+/// assert_eq!(2, paths.paths().len());
+/// ```
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best = HashMap::from([(start.clone(), (C::zero(), vec![]))]);
+    let mut pending = BinaryHeap::from([Queued {
+        estimate: C::zero(),
+        cost: C::zero(),
+        state: start,
+    }]);
+    let mut found: Option<(C, Vec<S>)> = None;
+    let mut expanded = 0;
+
+    while let Some(Queued { cost, state, .. }) = pending.pop() {
+        if found
+            .as_ref()
+            .is_some_and(|&(goal_cost, _)| cost > goal_cost)
+        {
+            break;
+        }
+        if best.get(&state).is_some_and(|(best, _)| cost > *best) {
+            continue;
+        }
+        if is_goal(&state) {
+            found.get_or_insert_with(|| (cost, vec![])).1.push(state);
+            continue;
+        }
+        expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match best.entry(next.clone()) {
+                Entry::Occupied(entry) if entry.get().0 < next_cost => continue,
+                Entry::Occupied(mut entry) if entry.get().0 == next_cost => {
+                    // Another way in at the same cost; it's already queued.
+                    entry.get_mut().1.push(state.clone());
+                    continue;
+                }
+                Entry::Occupied(mut entry) => {
+                    entry.insert((next_cost, vec![state.clone()]));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, vec![state.clone()]));
+                }
+            }
+            pending.push(Queued {
+                estimate: next_cost,
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    trace::count("states expanded", expanded);
+    let (cost, goals) = found?;
+    Some(AllPaths {
+        cost,
+        goals,
+        predecessors: best
+            .into_iter()
+            .map(|(state, (_, predecessors))| (state, predecessors))
+            .collect(),
+    })
+}
+
+/// Breadth-first search, for when every step costs the same. The cost is the number of steps.
+///
+/// ```rust
+/// use advent::search::bfs;
+/// let path = bfs(1u32, |&n| [n + 1, n * 3], |&n| n == 10).unwrap();
+/// assert_eq!(3, path.cost);
+/// assert_eq!(vec![1, 3, 9, 10], path.states);
+/// assert!(bfs(1u32, |&n| (n < 5).then_some(n + 1), |&n| n == 10).is_none());
+/// ```
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut pending = VecDeque::from([start]);
+    let mut expanded = 0;
+
+    while let Some(state) = pending.pop_front() {
+        if is_goal(&state) {
+            trace::count("states expanded", expanded);
+            let states = reconstruct(&parents, state);
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+        expanded += 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                pending.push_back(next);
+            }
+        }
+    }
+    trace::count("states expanded", expanded);
+    None
+}
@@ -0,0 +1,406 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Write as _},
+    io::{self, Write as _},
+    str::FromStr,
+    time::Instant,
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Level, Subscriber,
+};
+use tracing_subscriber::{
+    filter::Targets,
+    layer::{Context, SubscriberExt},
+    registry::{LookupSpan, SpanRef},
+    util::SubscriberInitExt,
+    Layer,
+};
+
+/// The target `count` events go to, which the trace layer adds up instead of printing.
+const COUNT_TARGET: &str = "advent::count";
+
+/// Adds `amount` to the counter called `counter` on the span it's counted in, which adds it to
+/// every span around that when it closes. Work done on other threads only counts towards the
+/// spans entered there, so count it before it goes, or after it's back. Counting once with a
+/// total beats counting every state one at a time.
+///
+/// ```rust
+/// advent::trace::count("states expanded", 42);
+/// ```
+pub fn count(counter: &'static str, amount: u64) {
+    tracing::info!(target: COUNT_TARGET, counter, amount);
+}
+
+/// How traces get written to stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Spans indented under the span they ran in, written once the outermost one closes.
+    Tree,
+    /// A JSON object a line for every event and closed span, with the fields of the spans around
+    /// them folded in.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "tree" => Ok(Self::Tree),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown trace format `{format}`, expected tree or json"
+            )),
+        }
+    }
+}
+
+/// Sets up tracing the way the environment asks: `ADVENT_TRACE` picks the format, `tree` or
+/// `json`, and `RUST_LOG` what gets through, `env_logger` style. Without `ADVENT_TRACE`, only
+/// warnings and errors come out unless `RUST_LOG` says otherwise; with it, `info` is the default,
+/// which gets every day, parse and part span and what they counted. `log` records come through as
+/// events too.
+pub fn init_from_env() {
+    let format = env::var("ADVENT_TRACE").ok();
+    let (format, default) = match format.as_deref().map(str::parse) {
+        None => (Format::Tree, "warn"),
+        Some(Ok(format)) => (format, "info"),
+        Some(Err(error)) => {
+            eprintln!("warning: {error}, tracing as a tree");
+            (Format::Tree, "info")
+        }
+    };
+    let filter = env::var("RUST_LOG").unwrap_or_else(|_| default.to_owned());
+    let targets = filter.parse::<Targets>().unwrap_or_else(|error| {
+        eprintln!("warning: ignoring `RUST_LOG={filter}`: {error}");
+        default.parse().expect("default filters parse")
+    });
+    init(format, targets);
+}
+
+/// Sets up tracing in `format`, letting through what `targets` does. Does nothing when something
+/// else already set tracing up.
+pub fn init(format: Format, targets: Targets) {
+    let _ = tracing_subscriber::registry()
+        .with(TraceLayer { format }.with_filter(targets))
+        .try_init();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Integer(i128),
+    Float(f64),
+    Bool(bool),
+    Text(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value}"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+impl Value {
+    fn json(&self, out: &mut String) {
+        match self {
+            Value::Float(value) if !value.is_finite() => out.push_str("null"),
+            Value::Text(value) => json_string(value, out),
+            value => write!(out, "{value}").expect("strings take writes"),
+        }
+    }
+}
+
+fn json_string(text: &str, out: &mut String) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).expect("strings take writes"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// The fields of a span or event, in the order they were recorded.
+#[derive(Debug, Default)]
+struct Fields {
+    message: Option<String>,
+    /// Where `log` records really came from; tracing files them all under `log`.
+    log_target: Option<String>,
+    values: Vec<(&'static str, Value)>,
+}
+
+impl Fields {
+    fn set(&mut self, field: &Field, value: Value) {
+        match (field.name(), value) {
+            ("message", value) => self.message = Some(value.to_string()),
+            ("log.target", value) => self.log_target = Some(value.to_string()),
+            (name, _) if name.starts_with("log.") => {}
+            (name, value) => match self.values.iter_mut().find(|(known, _)| *known == name) {
+                Some((_, known)) => *known = value,
+                None => self.values.push((name, value)),
+            },
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(known, _)| *known == name)
+            .map(|(_, value)| value)
+    }
+
+    /// ` name=value` for every field.
+    fn text(&self) -> String {
+        self.values
+            .iter()
+            .map(|(name, value)| format!(" {name}={value}"))
+            .collect()
+    }
+}
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.set(field, Value::Text(format!("{value:?}")));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.set(field, Value::Text(value.to_owned()));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.set(field, Value::Integer(value.into()));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.set(field, Value::Integer(value.into()));
+    }
+
+    fn record_i128(&mut self, field: &Field, value: i128) {
+        self.set(field, Value::Integer(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.set(field, Value::Float(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.set(field, Value::Bool(value));
+    }
+}
+
+/// What the trace layer keeps on every open span.
+struct Node {
+    fields: Fields,
+    opened: Instant,
+    counts: BTreeMap<String, u64>,
+    /// The lines of everything traced inside, one entry a child, for `Format::Tree`.
+    children: Vec<Vec<String>>,
+}
+
+struct TraceLayer {
+    format: Format,
+}
+
+impl TraceLayer {
+    fn write(&self, line: &str) {
+        let _ = writeln!(io::stderr().lock(), "{line}");
+    }
+
+    /// Adds `lines` under `span` for `Format::Tree`, or writes them straight out when they're not
+    /// inside one.
+    fn add_lines<S>(&self, span: Option<SpanRef<'_, S>>, lines: Vec<String>)
+    where
+        S: for<'a> LookupSpan<'a>,
+    {
+        if let Some(span) = span {
+            if let Some(node) = span.extensions_mut().get_mut::<Node>() {
+                node.children.push(lines);
+                return;
+            }
+        }
+        self.write(&lines.join("\n"));
+    }
+}
+
+/// The fields of every span from `span` out, outermost first, as JSON members.
+fn scope_json<S>(span: Option<SpanRef<'_, S>>, out: &mut String)
+where
+    S: for<'a> LookupSpan<'a>,
+{
+    let Some(span) = span else {
+        return;
+    };
+    for span in span.scope().from_root() {
+        if let Some(node) = span.extensions().get::<Node>() {
+            for (name, value) in &node.fields.values {
+                json_member(name, value, out);
+            }
+        }
+    }
+}
+
+/// `"name":value`, after a comma unless it's the first member of its object.
+fn json_member(name: &str, value: &Value, out: &mut String) {
+    if !out.ends_with('{') {
+        out.push(',');
+    }
+    json_string(name, out);
+    out.push(':');
+    value.json(out);
+}
+
+impl<S> Layer<S> for TraceLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        attrs.record(&mut fields);
+        let span = ctx.span(id).expect("new spans are registered");
+        span.extensions_mut().insert(Node {
+            fields,
+            opened: Instant::now(),
+            counts: BTreeMap::new(),
+            children: vec![],
+        });
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("recorded spans are registered");
+        let mut extensions = span.extensions_mut();
+        if let Some(node) = extensions.get_mut::<Node>() {
+            values.record(&mut node.fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let span = ctx.event_span(event);
+        let metadata = event.metadata();
+
+        if metadata.target() == COUNT_TARGET {
+            let counter = match fields.get("counter") {
+                Some(Value::Text(counter)) => counter.clone(),
+                _ => return,
+            };
+            let Some(&Value::Integer(amount)) = fields.get("amount") else {
+                return;
+            };
+            if let Some(span) = span {
+                if let Some(node) = span.extensions_mut().get_mut::<Node>() {
+                    *node.counts.entry(counter).or_default() += amount as u64;
+                }
+            }
+            return;
+        }
+
+        let target = fields.log_target.as_deref().unwrap_or(metadata.target());
+        let message = fields.message.as_deref().unwrap_or_default();
+        match self.format {
+            Format::Tree => {
+                let line = format!(
+                    "{} {target}: {message}{}",
+                    level(metadata.level()),
+                    fields.text()
+                );
+                self.add_lines(span, vec![line]);
+            }
+            Format::Json => {
+                let mut line = String::from("{\"kind\":\"event\",\"level\":");
+                json_string(level(metadata.level()), &mut line);
+                line.push_str(",\"target\":");
+                json_string(target, &mut line);
+                line.push_str(",\"message\":");
+                json_string(message, &mut line);
+                line.push_str(",\"fields\":{");
+                scope_json(span, &mut line);
+                for (name, value) in &fields.values {
+                    json_member(name, value, &mut line);
+                }
+                line.push_str("}}");
+                self.write(&line);
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).expect("closing spans are registered");
+        let Some(node) = span.extensions_mut().remove::<Node>() else {
+            return;
+        };
+        let elapsed = node.opened.elapsed();
+        let parent = span.parent();
+        if let Some(parent) = &parent {
+            if let Some(parent) = parent.extensions_mut().get_mut::<Node>() {
+                for (counter, &amount) in &node.counts {
+                    *parent.counts.entry(counter.clone()).or_default() += amount;
+                }
+            }
+        }
+
+        match self.format {
+            Format::Tree => {
+                let mut header = format!("{}{}  {elapsed:.1?}", span.name(), node.fields.text());
+                for (counter, amount) in &node.counts {
+                    write!(header, "  {counter}={amount}").expect("strings take writes");
+                }
+                let mut lines = vec![header];
+                let last = node.children.len().saturating_sub(1);
+                for (index, child) in node.children.into_iter().enumerate() {
+                    let (first, rest) = if index == last {
+                        ("└─ ", "   ")
+                    } else {
+                        ("├─ ", "│  ")
+                    };
+                    for (line_index, line) in child.into_iter().enumerate() {
+                        let prefix = if line_index == 0 { first } else { rest };
+                        lines.push(format!("{prefix}{line}"));
+                    }
+                }
+                self.add_lines(parent, lines);
+            }
+            Format::Json => {
+                let mut line = String::from("{\"kind\":\"span\",\"name\":");
+                json_string(span.name(), &mut line);
+                write!(line, ",\"elapsed_us\":{}", elapsed.as_micros())
+                    .expect("strings take writes");
+                line.push_str(",\"fields\":{");
+                scope_json(parent, &mut line);
+                for (name, value) in &node.fields.values {
+                    json_member(name, value, &mut line);
+                }
+                line.push_str("},\"counts\":{");
+                for (counter, &amount) in &node.counts {
+                    json_member(counter, &Value::Integer(amount.into()), &mut line);
+                }
+                line.push_str("}}");
+                self.write(&line);
+            }
+        }
+    }
+}
+
+fn level(level: &Level) -> &'static str {
+    match *level {
+        Level::ERROR => "ERROR",
+        Level::WARN => "WARN",
+        Level::INFO => "INFO",
+        Level::DEBUG => "DEBUG",
+        Level::TRACE => "TRACE",
+    }
+}
@@ -0,0 +1,363 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use crate::{
+    grid::{Grid, GridPoint},
+    input::{InputError, Parsed},
+    ParseError,
+};
+
+pub mod export;
+
+/// A 24-bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const GREY: Self = Self(128, 128, 128);
+    pub const RED: Self = Self(230, 60, 50);
+    pub const GREEN: Self = Self(60, 200, 80);
+    pub const YELLOW: Self = Self(250, 210, 60);
+    pub const BLUE: Self = Self(70, 130, 240);
+}
+
+/// Tiles that know what colour they are, for pictures of the grids they make up.
+pub trait Palette {
+    fn colour(&self) -> Rgb;
+}
+
+/// One character of a frame, in the terminal's own colour unless it has one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Option<Rgb>,
+}
+
+impl Cell {
+    pub const BLANK: Self = Self::new(' ');
+
+    pub const fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            colour: None,
+        }
+    }
+
+    pub const fn coloured(glyph: char, colour: Rgb) -> Self {
+        Self {
+            glyph,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(glyph: char) -> Self {
+        Self::new(glyph)
+    }
+}
+
+/// A picture of a simulation at one step, with a line of text to go under it.
+///
+/// ```rust
+/// use advent::{visualize::{Cell, Frame, Rgb}, Grid, UPoint};
+/// let map = Grid::bytes("#..\n.#.").unwrap();
+/// let mut frame = Frame::from_grid(&map, |&tile| Cell::from(tile as char)).with_caption("step 1");
+/// frame.set(UPoint::new(2, 0), Cell::coloured('^', Rgb::RED));
+/// // Drawing off the edge just doesn't.
+/// frame.set(UPoint::new(5, 5), '@');
+/// assert_eq!("#.^\n.#.\nstep 1\n", frame.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: Grid::filled(width, height, Cell::BLANK),
+            caption: String::new(),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, cell: impl FnMut(&T) -> Cell) -> Self {
+        Self {
+            cells: grid.map(cell),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Draws `cell` at `point`, if that's inside the frame.
+    pub fn set<P: GridPoint>(&mut self, point: P, cell: impl Into<Cell>) {
+        if let Some(target) = self.cells.get_mut(point) {
+            *target = cell.into();
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+}
+
+/// The glyphs without their colours, then the caption, when there is one.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.cells.to_text(|cell| cell.glyph))?;
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+        Ok(())
+    }
+}
+
+/// Simulation states that can draw themselves.
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// The grids puzzles come in draw as they are.
+impl<T: Copy + Into<char>> Render for Grid<T> {
+    fn render(&self) -> Frame {
+        Frame::from_grid(self, |&cell| Cell::new(cell.into()))
+    }
+}
+
+/// Frames one after the other, worked out as they're shown.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// A day's simulation, animated. `advent_visualize!` builds these.
+#[derive(Clone, Copy)]
+pub struct Animation {
+    pub year: u16,
+    pub day: u8,
+    /// The function making the frames, e.g. `guard_walk`.
+    pub name: &'static str,
+    pub load_input: fn() -> Result<String, InputError>,
+    pub frames: fn(&'static str) -> Result<Frames, ParseError>,
+}
+
+impl Animation {
+    /// Starts the frames off from `input`, which they share until the last one has gone by.
+    pub fn frames_owned(&self, input: &Arc<str>) -> Result<Parsed<Frames>, ParseError> {
+        Parsed::new(input, self.frames)
+    }
+}
+
+/// How fast frames go by, and whether they go by at all. Speeds double and halve, between one
+/// frame a second and a thousand.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use advent::visualize::Playback;
+/// let mut playback = Playback::new(10);
+/// assert_eq!(Duration::from_millis(100), playback.delay());
+/// playback.faster();
+/// assert_eq!(Duration::from_millis(50), playback.delay());
+/// for _ in 0..20 {
+///     playback.slower();
+/// }
+/// assert_eq!(1, playback.fps());
+/// playback.toggle_pause();
+/// assert!(playback.paused());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Playback {
+    fps: u32,
+    paused: bool,
+}
+
+impl Playback {
+    const MAX_FPS: u32 = 1000;
+
+    pub fn new(fps: u32) -> Self {
+        Self {
+            fps: fps.clamp(1, Self::MAX_FPS),
+            paused: false,
+        }
+    }
+
+    pub fn fps(&self) -> u32 {
+        self.fps
+    }
+
+    /// How long each frame stays up.
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn faster(&mut self) {
+        self.fps = (self.fps * 2).min(Self::MAX_FPS);
+    }
+
+    pub fn slower(&mut self) {
+        self.fps = (self.fps / 2).max(1);
+    }
+}
+
+/// Plays frames in the terminal: space pauses, `→` or `.` steps a frame at a time, `+` and `-`
+/// change the speed, `q` or `Esc` stops.
+#[cfg(feature = "visualize")]
+pub mod terminal {
+    use std::io::{self, Write};
+
+    use crossterm::{
+        cursor,
+        event::{self, Event, KeyCode, KeyEventKind},
+        execute, queue,
+        style::{Color, Print, ResetColor, SetForegroundColor},
+        terminal::{self, ClearType},
+    };
+
+    use super::{Frame, Playback, Rgb};
+
+    enum Control {
+        Next,
+        Redraw,
+        Quit,
+    }
+
+    /// Takes over the terminal until the frames run out and `q` gets pressed, or `q` gets pressed
+    /// before that. Frames bigger than the terminal lose their right and bottom edges.
+    pub fn play(frames: impl IntoIterator<Item = Frame>, fps: u32) -> io::Result<()> {
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        let played = run(&mut out, frames.into_iter(), Playback::new(fps));
+        // Hand the terminal back even when drawing failed.
+        let restored = execute!(out, cursor::Show, terminal::LeaveAlternateScreen)
+            .and_then(|_| terminal::disable_raw_mode());
+        played.and(restored)
+    }
+
+    fn run(
+        out: &mut impl Write,
+        mut frames: impl Iterator<Item = Frame>,
+        mut playback: Playback,
+    ) -> io::Result<()> {
+        let Some(mut frame) = frames.next() else {
+            return Ok(());
+        };
+        let mut shown = 1;
+        let mut finished = false;
+        loop {
+            draw(out, &frame, shown, &playback, finished)?;
+            match wait(&mut playback, finished)? {
+                Control::Quit => return Ok(()),
+                Control::Redraw => {}
+                Control::Next if finished => {}
+                Control::Next => match frames.next() {
+                    Some(next) => {
+                        frame = next;
+                        shown += 1;
+                    }
+                    None => finished = true,
+                },
+            }
+        }
+    }
+
+    /// Waits out the frame's delay, or for a key while paused.
+    fn wait(playback: &mut Playback, finished: bool) -> io::Result<Control> {
+        if !playback.paused() && !finished && !event::poll(playback.delay())? {
+            return Ok(Control::Next);
+        }
+        let Event::Key(key) = event::read()? else {
+            // Resized, most likely.
+            return Ok(Control::Redraw);
+        };
+        if key.kind == KeyEventKind::Release {
+            return Ok(Control::Redraw);
+        }
+        Ok(match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Control::Quit,
+            KeyCode::Right | KeyCode::Char('.') => {
+                playback.pause();
+                Control::Next
+            }
+            KeyCode::Char(' ') => {
+                playback.toggle_pause();
+                Control::Redraw
+            }
+            KeyCode::Char('+' | '=') => {
+                playback.faster();
+                Control::Redraw
+            }
+            KeyCode::Char('-') => {
+                playback.slower();
+                Control::Redraw
+            }
+            _ => Control::Redraw,
+        })
+    }
+
+    fn draw(
+        out: &mut impl Write,
+        frame: &Frame,
+        shown: usize,
+        playback: &Playback,
+        finished: bool,
+    ) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (columns, rows) = (columns as usize, (rows as usize).saturating_sub(1));
+        queue!(out, terminal::Clear(ClearType::All))?;
+        let mut colour = None;
+        for (y, row) in frame.cells.rows().take(rows).enumerate() {
+            queue!(out, cursor::MoveTo(0, y as u16))?;
+            for cell in row.iter().take(columns) {
+                if cell.colour != colour {
+                    colour = cell.colour;
+                    match colour {
+                        Some(Rgb(r, g, b)) => {
+                            queue!(out, SetForegroundColor(Color::Rgb { r, g, b }))?
+                        }
+                        None => queue!(out, ResetColor)?,
+                    }
+                }
+                queue!(out, Print(cell.glyph))?;
+            }
+        }
+        let state = if finished {
+            "done"
+        } else if playback.paused() {
+            "paused"
+        } else {
+            "playing"
+        };
+        let status = format!(
+            "{} | frame {shown} | {} fps | {state} | space pause, → step, +/- speed, q quit",
+            frame.caption,
+            playback.fps()
+        );
+        queue!(
+            out,
+            ResetColor,
+            cursor::MoveTo(0, frame.height().min(rows) as u16),
+            Print(status.chars().take(columns).collect::<String>())
+        )?;
+        out.flush()
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::grid::Grid;
+
+use super::{Frame, Palette, Rgb};
+
+/// A picture to save, every cell of what it was made from drawn as a `scale` by `scale` square of
+/// pixels.
+///
+/// ```rust
+/// use advent::{visualize::{export::Image, Palette, Rgb}, Grid};
+/// #[derive(Clone, Copy)]
+/// enum Tile { Wall, Floor }
+/// impl Palette for Tile {
+///     fn colour(&self) -> Rgb {
+///         match self {
+///             Tile::Wall => Rgb::GREY,
+///             Tile::Floor => Rgb::BLACK,
+///         }
+///     }
+/// }
+/// let map = Grid::parse("#.\n.#", |tile| Some(if tile == b'#' { Tile::Wall } else { Tile::Floor })).unwrap();
+/// let image = Image::from_palette(&map, 2);
+/// assert_eq!((4, 4), (image.width(), image.height()));
+/// assert_eq!(Rgb::GREY, image.pixel(1, 1));
+/// assert_eq!(Rgb::BLACK, image.pixel(2, 1));
+///
+/// let mut ppm = vec![];
+/// image.write_ppm(&mut ppm).unwrap();
+/// assert_eq!(b"P6\n4 4\n255\n", &ppm[..11]);
+/// assert_eq!(11 + 4 * 4 * 3, ppm.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut colour: impl FnMut(&T) -> Rgb) -> Self {
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let row = row
+                .iter()
+                .flat_map(|cell| std::iter::repeat_n(colour(cell), scale))
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Tiles in their palette's colours.
+    pub fn from_palette<T: Palette>(grid: &Grid<T>, scale: usize) -> Self {
+        Self::from_grid(grid, scale, Palette::colour)
+    }
+
+    /// A frame's cells in their colours. Cells left in the terminal's colour come out white,
+    /// unless they're blanks or `.`s, which are background and come out black. The caption
+    /// doesn't make it in.
+    pub fn from_frame(frame: &Frame, scale: usize) -> Self {
+        Self::from_grid(&frame.cells, scale, |cell| {
+            match (cell.colour, cell.glyph) {
+                (Some(colour), _) => colour,
+                (None, ' ' | '.') => Rgb::BLACK,
+                (None, _) => Rgb::WHITE,
+            }
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Panics outside the image.
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        assert!(
+            x < self.width,
+            "{x} is outside an image {} wide",
+            self.width
+        );
+        self.pixels[y * self.width + x]
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+
+    /// A binary PPM, which anything can open and nothing needs compressing.
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.bytes())
+            .map_err(io::Error::other)
+    }
+
+    /// Saves as whatever `path`'s extension asks for: `ppm`, `png` or a still `gif`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match Format::of(path)? {
+            Format::Ppm => self.write_ppm(&mut create(path)?),
+            Format::Png => self.write_png(create(path)?),
+            Format::Gif => write_gif([self.clone()], 1, create(path)?),
+        }
+    }
+}
+
+enum Format {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl Format {
+    fn of(path: &Path) -> io::Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("ppm") => Ok(Self::Ppm),
+            Some("png") => Ok(Self::Png),
+            Some("gif") => Ok(Self::Gif),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "can't save `{}`: images are .ppm, .png or .gif",
+                    path.display()
+                ),
+            )),
+        }
+    }
+}
+
+fn create(path: &Path) -> io::Result<BufWriter<File>> {
+    File::create(path).map(BufWriter::new)
+}
+
+/// An animated GIF looping forever, `fps` images a second, sized by the first image. Images with
+/// at most 256 colours, which is all of them drawn from a palette, keep their colours exactly.
+///
+/// ```rust
+/// use advent::{visualize::{export::{write_gif, Image}, Rgb}, Grid};
+/// let blink = [Rgb::RED, Rgb::BLUE].map(|colour| Image::from_grid(&Grid::filled(3, 2, ()), 4, |_| colour));
+/// let mut gif = vec![];
+/// write_gif(blink.clone(), 2, &mut gif).unwrap();
+/// assert!(gif.starts_with(b"GIF89a"));
+///
+/// let smaller = Image::from_grid(&Grid::filled(1, 1, ()), 1, |_| Rgb::RED);
+/// assert!(write_gif([blink[0].clone(), smaller], 2, vec![]).is_err());
+/// ```
+pub fn write_gif(
+    images: impl IntoIterator<Item = Image>,
+    fps: u32,
+    out: impl Write,
+) -> io::Result<()> {
+    let mut images = images.into_iter().peekable();
+    let Some(first) = images.peek() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a GIF needs at least one image",
+        ));
+    };
+    let size = |length: usize| {
+        u16::try_from(length).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("GIFs are at most {} pixels a side", u16::MAX),
+            )
+        })
+    };
+    let first = (first.width, first.height);
+    let (width, height) = (size(first.0)?, size(first.1)?);
+    let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    // GIF delays count hundredths of a second.
+    let delay = (100 / fps.max(1)).max(1) as u16;
+    for image in images {
+        if (image.width, image.height) != first {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "every image in a GIF needs to be the same size",
+            ));
+        }
+        let mut frame = gif_frame(&image, width, height);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+fn gif_frame(image: &Image, width: u16, height: u16) -> gif::Frame<'static> {
+    let mut palette = HashMap::new();
+    let indices = image
+        .pixels
+        .iter()
+        .map(|&pixel| {
+            let next = palette.len();
+            *palette.entry(pixel).or_insert(next)
+        })
+        .collect::<Vec<_>>();
+    if palette.len() > 256 {
+        // Too many to index, so GIF's quantizer picks 256 of them.
+        return gif::Frame::from_rgb_speed(width, height, &image.bytes(), 10);
+    }
+    let mut colours = vec![0; palette.len() * 3];
+    for (Rgb(r, g, b), index) in palette {
+        colours[index * 3..index * 3 + 3].copy_from_slice(&[r, g, b]);
+    }
+    let indices = indices
+        .into_iter()
+        .map(|index| index as u8)
+        .collect::<Vec<_>>();
+    gif::Frame::from_palette_pixels(width, height, indices, colours, None)
+}
+
+/// Saves frames as an animated GIF, `fps` frames a second, when `path` ends in `.gif`. Anything
+/// else saves just the last frame, as `Image::save` would.
+pub fn save_frames(
+    frames: impl IntoIterator<Item = Frame>,
+    scale: usize,
+    fps: u32,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let path = path.as_ref();
+    let images = frames
+        .into_iter()
+        .map(|frame| Image::from_frame(&frame, scale));
+    match Format::of(path)? {
+        Format::Gif => write_gif(images, fps, create(path)?),
+        Format::Ppm | Format::Png => images
+            .last()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "there are no frames"))?
+            .save(path),
+    }
+}
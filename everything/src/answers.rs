@@ -0,0 +1,167 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::PathBuf};
+
+use advent::{
+    input::{default_dir, InputSource},
+    Answer,
+};
+
+/// Known-correct answers for one year, read from `answers.toml` next to that year's inputs:
+///
+/// ```toml
+/// [day01]
+/// part1 = 54630
+/// part2 = 54770
+///
+/// [day10]
+/// part2 = """
+/// #..#
+/// ####"""
+/// ```
+///
+/// Numbers too big for TOML integers can be written as strings.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, u8), String>,
+}
+
+/// How an answer measures up against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Nothing recorded for this part yet.
+    Unknown,
+}
+
+impl Answers {
+    /// Where the answers for `year` live: alongside the inputs when they come from a directory,
+    /// otherwise in the default input directory.
+    pub fn path(source: &InputSource, year: u16) -> PathBuf {
+        let dir = match source {
+            InputSource::Directory(dir) => dir.clone(),
+            InputSource::Path(_) | InputSource::Stdin => default_dir(),
+        };
+        dir.join(year.to_string()).join("answers.toml")
+    }
+
+    /// Loads the answers for `year` from the [current](InputSource::current) source. A year
+    /// without an answers file has no answers, rather than being an error.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = Self::path(&InputSource::current(), year);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|reason| AnswersError::Invalid { path, reason }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AnswersError::Io { path, source }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let mut expected = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .filter(|digits| digits.len() == 2)
+                .and_then(|digits| digits.parse::<u8>().ok())
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("`{day_key}` is not a day, expected `day01` to `day25`"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{day_key}` should be a table of parts"))?;
+            for (part_key, value) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(format!("`{day_key}.{part_key}` is not `part1` or `part2`")),
+                };
+                let answer = match value {
+                    toml::Value::Integer(value) => value.to_string(),
+                    toml::Value::String(text) => text.trim_end_matches('\n').to_owned(),
+                    _ => {
+                        return Err(format!(
+                            "`{day_key}.{part_key}` should be an integer or a string"
+                        ))
+                    }
+                };
+                expected.insert((day, part), answer);
+            }
+        }
+        Ok(Self { expected })
+    }
+
+    pub fn expected(&self, day: u8, part: u8) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn verify(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        let Some(expected) = self.expected(day, part) else {
+            return Verdict::Unknown;
+        };
+        if answer.matches(expected) {
+            Verdict::Pass
+        } else {
+            Verdict::Fail {
+                expected: expected.to_owned(),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "could not read {}: {source}", path.display()),
+            Self::Invalid { path, reason } => write!(f, "{}: {reason}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_against_recorded_answers() {
+        let answers = Answers::parse("[day03]\npart1 = 4361\npart2 = \"467835\"").unwrap();
+        assert_eq!(Verdict::Pass, answers.verify(3, 1, &Answer::from(4361)));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "467835".into()
+            },
+            answers.verify(3, 2, &Answer::from(467834))
+        );
+        assert_eq!(Verdict::Unknown, answers.verify(4, 1, &Answer::from(13)));
+        assert_eq!(
+            Verdict::Pass,
+            Answers::parse("[day10]\npart2 = \"\"\"\n#.\n.#\n\"\"\"")
+                .unwrap()
+                .verify(10, 2, &Answer::from("#.\n.#\n"))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Answers::parse("[day3]\npart1 = 4361").is_err());
+        assert!(Answers::parse("[day03]\npart3 = 4361").is_err());
+        assert!(Answers::parse("[day03]\npart1 = 1.5").is_err());
+    }
+}
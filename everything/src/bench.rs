@@ -0,0 +1,408 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use advent::{
+    bench::{measure, Measurement, Variant},
+    DayError, Solver,
+};
+
+use crate::{
+    cli::{Bench, Selection},
+    timing::format_duration,
+};
+
+/// A phase of a day (`parse`, `part1` or `part2`) or one of its variants.
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub result: Result<Measurement, String>,
+}
+
+/// Medians of earlier benchmarks, kept under `target/advent-bench/<name>.toml`:
+///
+/// ```toml
+/// [2023.day01]
+/// parse = 41250
+/// part1 = 23750
+/// "cursed_regex/part2_regex" = 3204583
+/// ```
+///
+/// Times are in nanoseconds.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u8, String), u64>,
+}
+
+impl Baseline {
+    pub fn path(name: &str) -> PathBuf {
+        let target = env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent();
+                workspace
+                    .expect("`everything` sits in the workspace")
+                    .join("target")
+            });
+        target.join("advent-bench").join(format!("{name}.toml"))
+    }
+
+    /// A baseline that was never saved is empty, so everything compares as new.
+    pub fn load(name: &str) -> Result<Self, BaselineError> {
+        let path = Self::path(name);
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|reason| BaselineError::Invalid { path, reason })
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(BaselineError::Io { path, source }),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let mut medians = BTreeMap::new();
+        for (year_key, days) in table {
+            let year = year_key
+                .parse::<u16>()
+                .map_err(|_| format!("`{year_key}` is not a year"))?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| format!("`{year_key}` should be a table of days"))?;
+            for (day_key, benchmarks) in days {
+                let day = day_key
+                    .strip_prefix("day")
+                    .and_then(|digits| digits.parse::<u8>().ok())
+                    .ok_or_else(|| format!("`{year_key}.{day_key}` is not a day"))?;
+                let benchmarks = benchmarks.as_table().ok_or_else(|| {
+                    format!("`{year_key}.{day_key}` should be a table of benchmarks")
+                })?;
+                for (name, nanos) in benchmarks {
+                    let nanos = nanos
+                        .as_integer()
+                        .and_then(|nanos| u64::try_from(nanos).ok())
+                        .ok_or_else(|| {
+                            format!("`{year_key}.{day_key}.{name}` should be nanoseconds")
+                        })?;
+                    medians.insert((year, day, name.clone()), nanos);
+                }
+            }
+        }
+        Ok(Self { medians })
+    }
+
+    pub fn median(&self, year: u16, day: u8, name: &str) -> Option<Duration> {
+        self.medians
+            .get(&(year, day, name.to_owned()))
+            .map(|&nanos| Duration::from_nanos(nanos))
+    }
+
+    /// Replaces what was recorded for the benchmarks that ran, keeping the rest.
+    pub fn update(&mut self, benchmarks: &[Benchmark]) {
+        for benchmark in benchmarks {
+            if let Ok(measurement) = &benchmark.result {
+                let nanos = u64::try_from(measurement.median.as_nanos()).unwrap_or(u64::MAX);
+                self.medians.insert(
+                    (benchmark.year, benchmark.day, benchmark.name.clone()),
+                    nanos,
+                );
+            }
+        }
+    }
+
+    /// The text `parse` reads back. Times too long to store as a TOML integer are capped.
+    pub fn to_toml(&self) -> String {
+        let mut table = toml::Table::new();
+        for ((year, day, name), &nanos) in &self.medians {
+            let days = table
+                .entry(year.to_string())
+                .or_insert_with(|| toml::Table::new().into());
+            let benchmarks = days
+                .as_table_mut()
+                .expect("years only hold days")
+                .entry(format!("day{day:02}"))
+                .or_insert_with(|| toml::Table::new().into());
+            benchmarks
+                .as_table_mut()
+                .expect("days only hold benchmarks")
+                .insert(name.clone(), i64::try_from(nanos).unwrap_or(i64::MAX).into());
+        }
+        table.to_string()
+    }
+
+    pub fn save(&self, name: &str) -> Result<PathBuf, BaselineError> {
+        let path = Self::path(name);
+        let write = || {
+            fs::create_dir_all(path.parent().expect("baselines live in a directory"))?;
+            fs::write(&path, self.to_toml())
+        };
+        match write() {
+            Ok(()) => Ok(path),
+            Err(source) => Err(BaselineError::Io { path, source }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io { path: PathBuf, source: io::Error },
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "could not access {}: {source}", path.display()),
+            Self::Invalid { path, reason } => write!(f, "{}: {reason}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+fn bench_day(
+    solver: &Solver,
+    selection: &Selection,
+    benchmarks: &mut Vec<Benchmark>,
+) -> Result<(), DayError> {
+    let input = Arc::from((solver.load_input)()?);
+    let day = solver.parse_owned(&input)?;
+
+    let mut push = |name: &str, measurement| {
+        benchmarks.push(Benchmark {
+            year: solver.year,
+            day: solver.day,
+            name: name.to_owned(),
+            result: Ok(measurement),
+        })
+    };
+    push("parse", measure(|| solver.parse_owned(&input)));
+    if selection.matches(solver.year, solver.day, 1) {
+        push("part1", measure(|| day.part1()));
+    }
+    if selection.matches(solver.year, solver.day, 2) {
+        push("part2", measure(|| day.part2()));
+    }
+    Ok(())
+}
+
+fn bench_variant(variant: &Variant) -> Result<Measurement, DayError> {
+    let input = Arc::from((variant.load_input)()?);
+    let part = variant.prepare_owned(&input)?;
+    Ok(measure(|| (*part)()))
+}
+
+/// Benchmarks the selected days, then their variants, one at a time.
+pub fn run(solvers: &[Solver], variants: &[Variant], selection: &Selection) -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for solver in solvers {
+        eprintln!("Benchmarking {} day {}", solver.year, solver.day);
+        if let Err(error) = bench_day(solver, selection, &mut benchmarks) {
+            benchmarks.push(Benchmark {
+                year: solver.year,
+                day: solver.day,
+                name: "parse".to_owned(),
+                result: Err(format!("error: {error}")),
+            });
+        }
+    }
+    for variant in variants {
+        // Variants can't be told apart by part, so only the year and day select them.
+        if selection.year.is_some_and(|year| year != variant.year)
+            || selection.day.is_some_and(|day| day != variant.day)
+        {
+            continue;
+        }
+        eprintln!(
+            "Benchmarking {} day {} {}",
+            variant.year, variant.day, variant.name
+        );
+        benchmarks.push(Benchmark {
+            year: variant.year,
+            day: variant.day,
+            name: variant.name.to_owned(),
+            result: bench_variant(variant).map_err(|error| format!("error: {error}")),
+        });
+    }
+    // Stable, so variants stay after the day's own benchmarks.
+    benchmarks.sort_by_key(|benchmark| (benchmark.year, benchmark.day));
+    benchmarks
+}
+
+/// Prints every benchmark next to its baseline, handing back how many got slower than
+/// `bench.threshold` allows.
+pub fn print_table(benchmarks: &[Benchmark], baseline: &Baseline, bench: &Bench) -> usize {
+    let mut regressions = 0;
+    let cells = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let measurement = match &benchmark.result {
+                Ok(measurement) => measurement,
+                Err(error) => {
+                    return [
+                        error.clone(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                    ]
+                }
+            };
+            let previous = baseline.median(benchmark.year, benchmark.day, &benchmark.name);
+            let (change, flag) = match previous {
+                Some(previous) if !previous.is_zero() => {
+                    let change =
+                        (measurement.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                    let slower = change > f64::from(bench.threshold);
+                    regressions += usize::from(slower);
+                    (
+                        format!("{change:+.1}%"),
+                        if slower { "SLOWER" } else { "" }.to_owned(),
+                    )
+                }
+                _ => ("new".to_owned(), String::new()),
+            };
+            [
+                format_duration(measurement.median),
+                format_duration(measurement.min),
+                previous
+                    .map(format_duration)
+                    .unwrap_or_else(|| "-".to_owned()),
+                change,
+                flag,
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let name_width = benchmarks
+        .iter()
+        .map(|benchmark| benchmark.name.len())
+        .chain(["Benchmark".len()])
+        .max()
+        .unwrap_or_default();
+    let headers = ["Median", "Fastest", "Baseline", "Change"];
+    let widths: [usize; 4] = std::array::from_fn(|column| {
+        benchmarks
+            .iter()
+            .zip(&cells)
+            .filter(|(benchmark, _)| benchmark.result.is_ok())
+            .map(|(_, row)| row[column].chars().count())
+            .chain([headers[column].len()])
+            .max()
+            .unwrap_or_default()
+    });
+
+    println!(
+        "Baseline `{}`, flagging anything over {}% slower",
+        bench.baseline, bench.threshold
+    );
+    print!("{:<4}  {:>3}  {:<name_width$}", "Year", "Day", "Benchmark");
+    for (header, width) in headers.iter().zip(widths) {
+        print!("  {header:>width$}");
+    }
+    println!();
+    print!("{:-<4}  {:->3}  {:-<name_width$}", "", "", "");
+    for width in widths {
+        print!("  {:->width$}", "");
+    }
+    println!();
+    for (benchmark, row) in benchmarks.iter().zip(&cells) {
+        print!(
+            "{:<4}  {:>3}  {:<name_width$}",
+            benchmark.year, benchmark.day, benchmark.name
+        );
+        if benchmark.result.is_err() {
+            println!("  {}", row[0]);
+            continue;
+        }
+        for (cell, width) in row.iter().zip(widths) {
+            print!("  {cell:>width$}");
+        }
+        match row[4].as_str() {
+            "" => println!(),
+            flag => println!("  {flag}"),
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn updates_only_what_ran() {
+        let mut baseline =
+            Baseline::parse("[2023.day01]\nparse = 1000\npart1 = 2000\n\n[2024.day05]\npart2 = 5")
+                .unwrap();
+        baseline.update(&[Benchmark {
+            year: 2023,
+            day: 1,
+            name: "part1".into(),
+            result: Ok(Measurement {
+                median: Duration::from_nanos(1500),
+                min: Duration::from_nanos(1400),
+                iterations: 100,
+            }),
+        }]);
+        assert_eq!(
+            Some(Duration::from_nanos(1000)),
+            baseline.median(2023, 1, "parse")
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(1500)),
+            baseline.median(2023, 1, "part1")
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(5)),
+            baseline.median(2024, 5, "part2")
+        );
+        assert_eq!(None, baseline.median(2024, 5, "part1"));
+    }
+
+    #[test]
+    fn reads_back_what_it_saves() {
+        let mut baseline = Baseline::parse("[2023.day01]\nparse = 1000").unwrap();
+        baseline.update(&[Benchmark {
+            year: 2023,
+            day: 1,
+            name: "part1".into(),
+            result: Ok(Measurement {
+                median: Duration::MAX,
+                min: Duration::MAX,
+                iterations: 1,
+            }),
+        }]);
+        let saved = Baseline::parse(&baseline.to_toml()).unwrap();
+        assert_eq!(
+            Some(Duration::from_nanos(1000)),
+            saved.median(2023, 1, "parse")
+        );
+        assert_eq!(
+            Some(Duration::from_nanos(i64::MAX as u64)),
+            saved.median(2023, 1, "part1")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_baselines() {
+        assert!(Baseline::parse("[twenty.day01]\nparse = 1").is_err());
+        assert!(Baseline::parse("[2023.one]\nparse = 1").is_err());
+        assert!(Baseline::parse("[2023.day01]\nparse = \"fast\"").is_err());
+        assert!(Baseline::parse("[2023.day01]\nparse = -1").is_err());
+    }
+}
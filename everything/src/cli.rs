@@ -0,0 +1,375 @@
+use std::{fmt, path::PathBuf, str::FromStr};
+
+use advent::{input::InputSource, Params};
+
+pub const USAGE: &str = "Usage:
+    everything run --all [--day <day>] [--part <part>] [--input-dir <dir>]
+    everything run --year <year> [--day <day>] [--part <part>] [--input-dir <dir>]
+    everything run --year <year> --day <day> [--part <part>] --input <file|->
+    everything run --year <year> --day <day> [--part <part>] [--input <file|->] --param <name>=<value>...
+    everything verify (--all | --year <year>) [--day <day>] [--part <part>] [--input-dir <dir>]
+    everything bench (--all | --year <year>) [--day <day>] [--part <part>] [--input-dir <dir>]
+                     [--baseline <name>] [--save] [--threshold <percent>]
+    everything visualize --year <year> --day <day> [--input <file|-> | --input-dir <dir>] [--fps <n>]
+                         [--output <file>]
+
+`run` and `verify` also take `--time [--runs <n>] [--sort <column>]` to time each day one at a
+time, where `<column>` is one of day, parse, part1, part2, total or peak.
+`run` solves with `<value>` in place of the constant `<name>` real inputs use, for days that
+have one, like 2023 day 21's `steps`; `--param` can be given more than once.
+`verify` checks answers against `<input dir>/<year>/answers.toml`.
+`bench` compares against the baseline called `<name>` (`main` unless given), flagging anything
+more than `<percent>` (10 unless given) slower, and with `--save` records the results as the
+new baseline.
+`visualize` plays the day's simulation in the terminal, `<n>` frames a second (10 unless given),
+in builds with the `visualize` feature. With `--output` it saves the frames instead: all of them
+as an animation when `<file>` ends in .gif, otherwise the last one as a .png or .ppm picture.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(Options),
+    /// Like `Run`, but checks every answer against the recorded ones.
+    Verify(Options),
+    /// Benchmarks instead of printing answers; `Options::bench` is always set.
+    Bench(Options),
+    /// Animates a single day instead; `Options::fps` is always set.
+    Visualize(Options),
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub selection: Selection,
+    pub input: Option<InputSource>,
+    pub timing: Option<Timing>,
+    /// Only allowed for `bench`.
+    pub bench: Option<Bench>,
+    /// Frames a second, only allowed for `visualize`.
+    pub fps: Option<u32>,
+    /// Where to save frames instead of playing them, only allowed for `visualize`.
+    pub output: Option<PathBuf>,
+    /// Constants to solve with instead of the real inputs' ones, only allowed for `run` of a
+    /// single day.
+    pub params: Params,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// How many times each phase runs; the report gives the median and the fastest.
+    pub runs: usize,
+    pub sort: SortBy,
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Self {
+            runs: 1,
+            sort: SortBy::Day,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub baseline: String,
+    /// Write the results to the baseline once compared against it.
+    pub save: bool,
+    /// How many percent slower than its baseline a benchmark may get before it's flagged.
+    pub threshold: u32,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            baseline: "main".to_owned(),
+            save: false,
+            threshold: 10,
+        }
+    }
+}
+
+/// Timing table column to sort on. Everything but `Day` puts the most expensive days first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    Peak,
+}
+
+impl FromStr for SortBy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Self::Day),
+            "parse" => Ok(Self::Parse),
+            "part1" => Ok(Self::Part1),
+            "part2" => Ok(Self::Part2),
+            "total" => Ok(Self::Total),
+            "peak" => Ok(Self::Peak),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Which puzzles to run. `None` means "every one of them".
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+}
+
+impl Selection {
+    pub fn matches(&self, year: u16, day: u8, part: u8) -> bool {
+        self.year.is_none_or(|y| y == year)
+            && self.day.is_none_or(|d| d == day)
+            && self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownFlag(String),
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+    NoYearSelected,
+    InputNeedsSingleDay,
+    InputWithVerify,
+    TimeWithBench,
+    BenchFlagsWithoutBench,
+    VisualizeNeedsSingleDay,
+    VisualizeFlagsWithoutVisualize,
+    ParamNeedsSingleDay,
+    ParamWithoutRun,
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "no command given"),
+            Self::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            Self::UnknownFlag(flag) => write!(f, "unknown flag `{flag}`"),
+            Self::MissingValue(flag) => write!(f, "`{flag}` needs a value"),
+            Self::InvalidValue(flag, value) => write!(f, "`{value}` is not a valid {flag}"),
+            Self::NoYearSelected => write!(f, "`run` needs either `--year <year>` or `--all`"),
+            Self::InputNeedsSingleDay => {
+                write!(f, "`--input` needs exactly one `--year` and `--day`")
+            }
+            Self::InputWithVerify => {
+                write!(
+                    f,
+                    "`verify` takes `--input-dir`, answers live next to the inputs"
+                )
+            }
+            Self::TimeWithBench => write!(f, "`bench` does its own timing, drop `--time`"),
+            Self::BenchFlagsWithoutBench => {
+                write!(
+                    f,
+                    "`--baseline`, `--save` and `--threshold` only go with `bench`"
+                )
+            }
+            Self::VisualizeNeedsSingleDay => {
+                write!(
+                    f,
+                    "`visualize` needs exactly one `--year` and `--day`, and no `--part` or `--time`"
+                )
+            }
+            Self::VisualizeFlagsWithoutVisualize => {
+                write!(f, "`--fps` and `--output` only go with `visualize`")
+            }
+            Self::ParamNeedsSingleDay => {
+                write!(f, "`--param` needs exactly one `--year` and `--day`")
+            }
+            Self::ParamWithoutRun => {
+                write!(
+                    f,
+                    "`--param` only goes with `run`, the other commands stick to real inputs"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, ArgsError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => match parse_options(args)? {
+            Options { bench: Some(_), .. } => Err(ArgsError::BenchFlagsWithoutBench),
+            Options { fps: Some(_), .. }
+            | Options {
+                output: Some(_), ..
+            } => Err(ArgsError::VisualizeFlagsWithoutVisualize),
+            options => Ok(Command::Run(options)),
+        },
+        Some("verify") => match parse_options(args)? {
+            Options {
+                input: Some(InputSource::Path(_) | InputSource::Stdin),
+                ..
+            } => Err(ArgsError::InputWithVerify),
+            Options { params, .. } if !params.is_empty() => Err(ArgsError::ParamWithoutRun),
+            Options { bench: Some(_), .. } => Err(ArgsError::BenchFlagsWithoutBench),
+            Options { fps: Some(_), .. }
+            | Options {
+                output: Some(_), ..
+            } => Err(ArgsError::VisualizeFlagsWithoutVisualize),
+            options => Ok(Command::Verify(options)),
+        },
+        Some("bench") => match parse_options(args)? {
+            Options {
+                timing: Some(_), ..
+            } => Err(ArgsError::TimeWithBench),
+            Options { params, .. } if !params.is_empty() => Err(ArgsError::ParamWithoutRun),
+            Options { fps: Some(_), .. }
+            | Options {
+                output: Some(_), ..
+            } => Err(ArgsError::VisualizeFlagsWithoutVisualize),
+            mut options => {
+                options.bench.get_or_insert_default();
+                Ok(Command::Bench(options))
+            }
+        },
+        Some("visualize") => match parse_options(args)? {
+            Options { bench: Some(_), .. } => Err(ArgsError::BenchFlagsWithoutBench),
+            Options { params, .. } if !params.is_empty() => Err(ArgsError::ParamWithoutRun),
+            mut options @ Options {
+                selection:
+                    Selection {
+                        year: Some(_),
+                        day: Some(_),
+                        part: None,
+                    },
+                timing: None,
+                ..
+            } => {
+                options.fps.get_or_insert(10);
+                Ok(Command::Visualize(options))
+            }
+            _ => Err(ArgsError::VisualizeNeedsSingleDay),
+        },
+        Some(command) => Err(ArgsError::UnknownCommand(command.to_owned())),
+        None => Err(ArgsError::MissingCommand),
+    }
+}
+
+fn parse_options<I: Iterator<Item = String>>(mut args: I) -> Result<Options, ArgsError> {
+    let mut selection = Selection::default();
+    let mut all = false;
+    let mut input = None;
+    let mut timing: Option<Timing> = None;
+    let mut bench: Option<Bench> = None;
+    let mut fps = None;
+    let mut output = None;
+    let mut params = Params::default();
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--all" => all = true,
+            "--year" => {
+                selection.year = Some(flag_value(&mut args, "--year", |year| year >= 2015)?)
+            }
+            "--day" => {
+                selection.day = Some(flag_value(&mut args, "--day", |day| {
+                    (1..=25).contains(&day)
+                })?)
+            }
+            "--part" => {
+                selection.part = Some(flag_value(&mut args, "--part", |part| {
+                    (1..=2).contains(&part)
+                })?)
+            }
+            "--input" => {
+                input = match args.next().ok_or(ArgsError::MissingValue("--input"))? {
+                    path if path == "-" => Some(InputSource::Stdin),
+                    path => Some(InputSource::Path(path.into())),
+                }
+            }
+            "--input-dir" => {
+                let dir = args.next().ok_or(ArgsError::MissingValue("--input-dir"))?;
+                input = Some(InputSource::Directory(dir.into()));
+            }
+            "--time" => {
+                timing.get_or_insert_default();
+            }
+            "--runs" => {
+                timing.get_or_insert_default().runs =
+                    flag_value(&mut args, "--runs", |runs| runs > 0)?
+            }
+            "--sort" => {
+                timing.get_or_insert_default().sort = flag_value(&mut args, "--sort", |_| true)?
+            }
+            "--baseline" => {
+                let name = args.next().ok_or(ArgsError::MissingValue("--baseline"))?;
+                // It ends up as a file name.
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                {
+                    return Err(ArgsError::InvalidValue("--baseline", name));
+                }
+                bench.get_or_insert_default().baseline = name;
+            }
+            "--save" => bench.get_or_insert_default().save = true,
+            "--fps" => fps = Some(flag_value(&mut args, "--fps", |fps| fps > 0)?),
+            "--output" => {
+                let path = args.next().ok_or(ArgsError::MissingValue("--output"))?;
+                output = Some(path.into());
+            }
+            "--param" => {
+                let assignment = args.next().ok_or(ArgsError::MissingValue("--param"))?;
+                params
+                    .set_assignment(&assignment)
+                    .map_err(|_| ArgsError::InvalidValue("--param", assignment))?;
+            }
+            "--threshold" => {
+                bench.get_or_insert_default().threshold =
+                    flag_value(&mut args, "--threshold", |_| true)?
+            }
+            _ => return Err(ArgsError::UnknownFlag(flag)),
+        }
+    }
+
+    if all {
+        selection.year = None;
+    } else if selection.year.is_none() {
+        return Err(ArgsError::NoYearSelected);
+    }
+
+    let single_day = selection.year.is_some() && selection.day.is_some();
+    if matches!(input, Some(InputSource::Path(_) | InputSource::Stdin)) && !single_day {
+        return Err(ArgsError::InputNeedsSingleDay);
+    }
+    if !params.is_empty() && !single_day {
+        return Err(ArgsError::ParamNeedsSingleDay);
+    }
+
+    Ok(Options {
+        selection,
+        input,
+        timing,
+        bench,
+        fps,
+        output,
+        params,
+    })
+}
+
+fn flag_value<T, I, F>(args: &mut I, flag: &'static str, valid: F) -> Result<T, ArgsError>
+where
+    T: std::str::FromStr + Copy,
+    I: Iterator<Item = String>,
+    F: Fn(T) -> bool,
+{
+    let value = args.next().ok_or(ArgsError::MissingValue(flag))?;
+    match value.parse::<T>() {
+        Ok(parsed) if valid(parsed) => Ok(parsed),
+        _ => Err(ArgsError::InvalidValue(flag, value)),
+    }
+}
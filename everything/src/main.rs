@@ -1,85 +1,358 @@
-// use advent_of_code_2015::* as a2015;
-// use advent_of_code_2023::* as a2023;
-// use advent_of_code_2024::* as a2024;
-// use advent_of_code_2025::* as a2025;
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+};
 
-use std::collections::HashMap;
-
-use advent::AdventDay;
-// use tokio::{join, spawn};
+use advent::{
+    registry,
+    visualize::{export, Animation, Frame},
+    Answer, DayError, Params, Solver,
+};
+use answers::{Answers, AnswersError, Verdict};
+use bench::Baseline;
+use cli::{Command, Options, Selection};
 use rayon::prelude::*;
 
-// fn runner<D: Day>() -> (String, String) {
-//     let mut part1_answer: Option<String> = None;
-//     let mut part2_answer: Option<String> = None;
-//     rayon::scope(|s| {
-//         s.spawn( |_| part1_answer = Some(D::part1()));
-//         s.spawn( |_| part2_answer = Some(D::part2()));
-//     });
+// Days register themselves from their own crates, which only get linked in once they're named.
+use advent_of_code_2015 as _;
+use advent_of_code_2023 as _;
+use advent_of_code_2024 as _;
+use advent_of_code_2025 as _;
 
-//     (part1_answer.unwrap(), part2_answer.unwrap())
-// }
+mod alloc;
+mod answers;
+mod bench;
+mod cli;
+mod timing;
 
-// macro_rules! advent_day {
-//     ($year:ty, $results:ident) => {
-//         $tasks.insert("1982-01-11", (spawn(run())));
-//     };
-// }
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
 
-// #[tokio::main]
+struct Row {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Result<Answer, String>,
+    /// Only filled in by `verify`.
+    verdict: Option<Verdict>,
+}
 
-fn main() {
-    println!("AdventOfCode");
+impl Row {
+    fn text(&self) -> String {
+        match (&self.answer, &self.verdict) {
+            (Ok(answer), Some(Verdict::Fail { expected })) if expected.contains('\n') => {
+                format!("{answer}\nexpected:\n{expected}")
+            }
+            (Ok(answer), Some(Verdict::Fail { expected })) => {
+                format!("{answer} (expected {expected})")
+            }
+            (Ok(answer), _) => answer.to_string(),
+            (Err(error), _) => error.clone(),
+        }
+    }
 
-    let years = (2015..=2025).collect::<Vec<_>>();
-    // let days = (1..=31).collect::<Vec<_>>();
-    let all_days = years
-        .iter()
-        .flat_map(|year| (1..=31).map(|day| (*year, day)))
-        .map(|(year, day)| match year {
-            2025 => advent_of_code_2025::new_day(day),
-            _ => None,
+    fn check(&self) -> Check {
+        match (&self.answer, &self.verdict) {
+            (Err(_), _) => Check::Error,
+            (Ok(_), Some(Verdict::Pass)) => Check::Pass,
+            (Ok(_), Some(Verdict::Fail { .. })) => Check::Fail,
+            (Ok(_), Some(Verdict::Unknown) | None) => Check::Unknown,
+        }
+    }
+}
+
+/// What the check column says about a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Check {
+    Pass,
+    Fail,
+    Unknown,
+    Error,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::Unknown => "unknown",
+            Self::Error => "error",
+        })
+    }
+}
+
+fn solvers(selection: &Selection) -> Vec<Solver> {
+    let mut solvers = registry::solvers();
+    solvers.retain(|solver| {
+        selection.year.is_none_or(|year| year == solver.year)
+            && selection.day.is_none_or(|day| day == solver.day)
+    });
+    solvers
+}
+
+fn run(solvers: &[Solver], selection: &Selection, params: &Params) -> Vec<Row> {
+    solvers
+        .par_iter()
+        .map(|solver| {
+            let span = solver.span();
+            let day = span.in_scope(|| solver.load());
+            (solver, span, day)
+        })
+        .flat_map(|(solver, span, day)| {
+            [1, 2]
+                .into_par_iter()
+                .filter(|&part| selection.matches(solver.year, solver.day, part))
+                .map(|part| Row {
+                    year: solver.year,
+                    day: solver.day,
+                    part,
+                    answer: match &day {
+                        Ok(day) => span
+                            .in_scope(|| day.solve_with(part, params))
+                            .map_err(|error| format!("error: {error}")),
+                        Err(error) => Err(format!("error: {error}")),
+                    },
+                    verdict: None,
+                })
+                .collect::<Vec<_>>()
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+fn verify(rows: &mut [Row]) -> Result<(), AnswersError> {
+    let mut answers: Option<(u16, Answers)> = None;
+    for row in rows {
+        if answers.as_ref().is_none_or(|(year, _)| *year != row.year) {
+            answers = Some((row.year, Answers::load(row.year)?));
+        }
+        if let (Ok(answer), Some((_, answers))) = (&row.answer, &answers) {
+            row.verdict = Some(answers.verify(row.day, row.part, answer));
+        }
+    }
+    Ok(())
+}
 
-    let all_parts = all_days
+fn print_table(rows: &[Row], verifying: bool) {
+    let texts = rows.iter().map(Row::text).collect::<Vec<_>>();
+    let answer_width = texts
         .iter()
-        .filter_map(|day| day.as_ref().map(|day| (1, day)))
-        .chain(
-            all_days
-                .iter()
-                .filter_map(|day| day.as_ref().map(|day| (2, day))),
-        )
-        .collect::<Vec<_>>();
-
-    rayon::scope(|s| {
-        all_parts.iter().for_each(|&(part, day)| {
-            s.spawn(|_| {
-                let _ = day.part1();
-            })
-        });
-    })
-    // days.into_par_iter().for_each(|day| {});
-
-    // let advent = [
-    //     || (advent_of_code_2025::day01::part1,advent_of_code_2025::day01::part2),
-    //     // || (advent_of_code_2025::day02::part1,advent_of_code_2025::day02::part2),
-    //     // || (advent_of_code_2025::day03::part1,advent_of_code_2025::day03::part2),
-    // ];
-
-    // let mut results:HashMap<String, (String, String)> = HashMap::new();
-    // // let mut tasks = vec![];
-    // rayon::scope(|s| {
-    //     s.spawn(|s|  runner::<advent_of_code_2025::day01::Day01>());
-    //     advent_day!(advent_of_code_2025::day01::Day01, results);
-    // });
-
-    // // advent_day!(tasks);
-    // for (day, result) in results {
-    //     println!("Day {}: {}", day, result.await.unwrap());
-    // }
-    // // tasks.push(spawn(async || advent_of_code_2025::day03::Day03::part2()));
-    // // let threadpool = rayon::ThreadPoolBuilder::new().build().unwrap();
-    // // threadpool.spawn(|| advent_of_code_2025::day03::Day03::part1());
-    // // threadpool.spawn(|| advent_of_code_2025::day03::Day03::part2());
+        .flat_map(|text| text.lines())
+        .map(|line| line.chars().count())
+        .chain([6])
+        .max()
+        .unwrap_or_default();
+
+    let check_width = if verifying { 7 } else { 0 };
+    let gap = if verifying { "  " } else { "" };
+
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<check_width$}{gap}{:<answer_width$}",
+        "Year",
+        "Day",
+        "Part",
+        if verifying { "Check" } else { "" },
+        "Answer"
+    );
+    println!(
+        "{:-<4}  {:->3}  {:->4}  {:-<check_width$}{gap}{:-<answer_width$}",
+        "", "", "", "", ""
+    );
+    for (row, text) in rows.iter().zip(&texts) {
+        let check = if verifying {
+            row.check().to_string()
+        } else {
+            String::new()
+        };
+        let mut lines = text.lines();
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<check_width$}{gap}{}",
+            row.year,
+            row.day,
+            row.part,
+            check,
+            lines.next().unwrap_or_default()
+        );
+        // Multi-line answers (ASCII art) continue underneath the answer column.
+        for line in lines {
+            println!(
+                "{:<4}  {:>3}  {:>4}  {:<check_width$}{gap}{}",
+                "", "", "", "", line
+            );
+        }
+    }
+}
+
+fn execute(options: Options, verifying: bool) -> ExitCode {
+    if let Some(input) = options.input {
+        advent::input::set_source(input).expect("input source is only set once");
+    }
+    let solvers = solvers(&options.selection);
+    let (mut rows, mut timings) = match options.timing {
+        Some(timing) => timing::run(&solvers, &options.selection, &options.params, timing.runs),
+        None => (run(&solvers, &options.selection, &options.params), vec![]),
+    };
+    if rows.is_empty() {
+        eprintln!("No solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+    if verifying {
+        if let Err(error) = verify(&mut rows) {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    print_table(&rows, verifying);
+    if let Some(timing) = options.timing {
+        println!();
+        timing::print_table(&mut timings, timing);
+    }
+
+    let (mut passed, mut failed, mut errors, mut unknown) = (0, 0, 0, 0);
+    for row in &rows {
+        match row.check() {
+            Check::Pass => passed += 1,
+            Check::Fail => failed += 1,
+            Check::Error => errors += 1,
+            Check::Unknown => unknown += 1,
+        }
+    }
+    if verifying {
+        println!("\n{passed} passed, {failed} failed, {errors} errors, {unknown} unknown");
+    }
+    if failed + errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn execute_bench(options: Options) -> ExitCode {
+    let bench = options.bench.expect("`bench` always has bench options");
+    if let Some(input) = options.input {
+        advent::input::set_source(input).expect("input source is only set once");
+    }
+    let baseline = match Baseline::load(&bench.baseline) {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let variants = registry::variants();
+    let benchmarks = bench::run(&solvers(&options.selection), &variants, &options.selection);
+    if benchmarks.is_empty() {
+        eprintln!("No solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+    let regressions = bench::print_table(&benchmarks, &baseline, &bench);
+    let errors = benchmarks
+        .iter()
+        .filter(|benchmark| benchmark.result.is_err())
+        .count();
+    println!("\n{regressions} slower, {errors} errors");
+
+    if bench.save {
+        let mut baseline = baseline;
+        baseline.update(&benchmarks);
+        match baseline.save(&bench.baseline) {
+            Ok(path) => println!("Saved baseline to {}", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if regressions + errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(feature = "visualize")]
+fn play(frames: impl Iterator<Item = Frame>, fps: u32) -> std::io::Result<()> {
+    advent::visualize::terminal::play(frames, fps)
+}
+
+#[cfg(not(feature = "visualize"))]
+fn play(_frames: impl Iterator<Item = Frame>, _fps: u32) -> std::io::Result<()> {
+    unreachable!("animations only register with the `visualize` feature")
+}
+
+/// How big a square each cell of a frame gets in saved pictures.
+const PIXELS_PER_CELL: usize = 4;
+
+/// Where an animation gets saved. A day with several animations adds each one's name to the file
+/// name, so they don't overwrite each other.
+fn output_path(output: &Path, animation: &Animation, several: bool) -> PathBuf {
+    if !several {
+        return output.to_owned();
+    }
+    let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{stem}-{}", animation.name);
+    if let Some(extension) = output.extension() {
+        name = format!("{name}.{}", extension.to_string_lossy());
+    }
+    output.with_file_name(name)
+}
+
+fn execute_visualize(options: Options) -> ExitCode {
+    let fps = options.fps.expect("`visualize` always has a frame rate");
+    if let Some(input) = options.input {
+        advent::input::set_source(input).expect("input source is only set once");
+    }
+    let mut animations = registry::animations();
+    animations.retain(|animation| {
+        options.selection.year == Some(animation.year)
+            && options.selection.day == Some(animation.day)
+    });
+    if animations.is_empty() {
+        eprintln!("No animations match the selection");
+        if cfg!(not(feature = "visualize")) {
+            eprintln!("They only get built in with the `visualize` feature");
+        }
+        return ExitCode::FAILURE;
+    }
+
+    let several = animations.len() > 1;
+    for animation in animations {
+        let frames = (animation.load_input)()
+            .map_err(DayError::from)
+            .and_then(|input| Ok(animation.frames_owned(&Arc::from(input))?));
+        let played = match (frames, &options.output) {
+            (Ok(frames), Some(output)) => {
+                let path = output_path(output, &animation, several);
+                export::save_frames(frames, PIXELS_PER_CELL, fps, path)
+                    .map_err(|error| error.to_string())
+            }
+            (Ok(frames), None) => play(frames, fps).map_err(|error| error.to_string()),
+            (Err(error), _) => Err(error.to_string()),
+        };
+        if let Err(error) = played {
+            eprintln!(
+                "error: {} day {} {}: {error}",
+                animation.year, animation.day, animation.name
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    advent::trace::init_from_env();
+
+    match cli::parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => execute(options, false),
+        Ok(Command::Verify(options)) => execute(options, true),
+        Ok(Command::Bench(options)) => execute_bench(options),
+        Ok(Command::Visualize(options)) => execute_visualize(options),
+        Err(error) => {
+            eprintln!("error: {error}\n\n{}", cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
 }
@@ -0,0 +1,229 @@
+use std::{
+    cmp::Reverse,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use advent::{DayError, Params, Solver};
+
+use crate::{
+    alloc,
+    cli::{Selection, SortBy, Timing},
+    Row,
+};
+
+/// How long a phase took over every run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+        }
+    }
+}
+
+impl std::ops::Add for Stats {
+    type Output = Stats;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            median: self.median + rhs.median,
+            min: self.min + rhs.min,
+        }
+    }
+}
+
+pub struct DayTiming {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    /// Most bytes allocated at once on top of the input, from parsing until both parts finish.
+    pub peak: usize,
+}
+
+impl DayTiming {
+    pub fn total(&self) -> Stats {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+/// Runs `f` `runs` times, handing back the last result. Earlier results are dropped before the
+/// next run starts so they don't count towards the allocation peak.
+fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Stats) {
+    let mut last = None;
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        drop(last.take());
+        let start = Instant::now();
+        last = Some(f());
+        samples.push(start.elapsed());
+    }
+    (last.expect("at least one run"), Stats::new(samples))
+}
+
+fn time_day(
+    solver: &Solver,
+    selection: &Selection,
+    params: &Params,
+    runs: usize,
+    rows: &mut Vec<Row>,
+) -> Result<DayTiming, DayError> {
+    let input = Arc::from((solver.load_input)()?);
+    let baseline = alloc::reset_peak();
+
+    let (day, parse) = measure(runs, || solver.parse_owned(&input));
+    let day = day?;
+
+    let mut time_part = |part: u8| {
+        if !selection.matches(solver.year, solver.day, part) {
+            return None;
+        }
+        let (answer, stats) = measure(runs, || day.solve_with(part, params));
+        rows.push(Row {
+            year: solver.year,
+            day: solver.day,
+            part,
+            answer: answer.map_err(|error| format!("error: {error}")),
+            verdict: None,
+        });
+        Some(stats)
+    };
+    let part1 = time_part(1);
+    let part2 = time_part(2);
+
+    Ok(DayTiming {
+        year: solver.year,
+        day: solver.day,
+        parse,
+        part1,
+        part2,
+        peak: alloc::peak().saturating_sub(baseline),
+    })
+}
+
+/// Solves the selected days one at a time, so that neither their timings nor their allocation
+/// peaks get mixed up with each other.
+pub fn run(
+    solvers: &[Solver],
+    selection: &Selection,
+    params: &Params,
+    runs: usize,
+) -> (Vec<Row>, Vec<DayTiming>) {
+    let mut rows = vec![];
+    let mut timings = vec![];
+    for solver in solvers {
+        match time_day(solver, selection, params, runs, &mut rows) {
+            Ok(timing) => timings.push(timing),
+            Err(error) => rows.extend(
+                [1, 2]
+                    .into_iter()
+                    .filter(|&part| selection.matches(solver.year, solver.day, part))
+                    .map(|part| Row {
+                        year: solver.year,
+                        day: solver.day,
+                        part,
+                        answer: Err(format!("error: {error}")),
+                        verdict: None,
+                    }),
+            ),
+        }
+    }
+    (rows, timings)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+pub fn print_table(timings: &mut [DayTiming], timing: Timing) {
+    match timing.sort {
+        SortBy::Day => timings.sort_by_key(|day| (day.year, day.day)),
+        SortBy::Parse => timings.sort_by_key(|day| Reverse(day.parse.median)),
+        SortBy::Part1 => timings.sort_by_key(|day| Reverse(day.part1.map(|stats| stats.median))),
+        SortBy::Part2 => timings.sort_by_key(|day| Reverse(day.part2.map(|stats| stats.median))),
+        SortBy::Total => timings.sort_by_key(|day| Reverse(day.total().median)),
+        SortBy::Peak => timings.sort_by_key(|day| Reverse(day.peak)),
+    }
+
+    let cell = |stats: Option<Stats>| match stats {
+        None => "-".to_owned(),
+        Some(stats) if timing.runs > 1 => format!(
+            "{} ({})",
+            format_duration(stats.median),
+            format_duration(stats.min)
+        ),
+        Some(stats) => format_duration(stats.median),
+    };
+    let cells = timings
+        .iter()
+        .map(|day| {
+            [
+                cell(Some(day.parse)),
+                cell(day.part1),
+                cell(day.part2),
+                cell(Some(day.total())),
+                format_bytes(day.peak),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let headers = ["Parse", "Part 1", "Part 2", "Total", "Peak"];
+    let widths: [usize; 5] = std::array::from_fn(|column| {
+        cells
+            .iter()
+            .map(|row| row[column].chars().count())
+            .chain([headers[column].len()])
+            .max()
+            .unwrap_or_default()
+    });
+
+    if timing.runs > 1 {
+        println!("Median (fastest) of {} runs", timing.runs);
+    }
+    print!("{:<4}  {:>3}", "Year", "Day");
+    for (header, width) in headers.iter().zip(widths) {
+        print!("  {header:>width$}");
+    }
+    println!();
+    print!("{:-<4}  {:->3}", "", "");
+    for width in widths {
+        print!("  {:->width$}", "");
+    }
+    println!();
+    for (day, row) in timings.iter().zip(&cells) {
+        print!("{:<4}  {:>3}", day.year, day.day);
+        for (cell, width) in row.iter().zip(widths) {
+            print!("  {cell:>width$}");
+        }
+        println!();
+    }
+}